@@ -0,0 +1,12 @@
+fn main() {
+    println!("cargo:rerun-if-changed=model.toml");
+
+    let mut generator = spec_parser::Generator::vendored("2.3").unwrap();
+    generator.set_apis(&["ApiVersions", "Metadata", "Fetch"]);
+    generator.set_crate_path("kafka_protocol");
+    generator.set_derives(&["Eq"]);
+    generator.set_config(spec_parser::Config::from_file("model.toml").unwrap());
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    generator.write_to(format!("{}/model.rs", out_dir)).unwrap();
+}
@@ -0,0 +1,57 @@
+//! A subset of the Kafka model, generated by `build.rs` outside of
+//! `kafka-protocol` and used with its codec.
+
+pub mod model {
+    include!(concat!(env!("OUT_DIR"), "/model.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::model::*;
+    use kafka_protocol::codec::{
+        decode_req_header, decode_response, encode_request, encode_resp, Deserializer,
+    };
+    use kafka_protocol::request::Request;
+    use kafka_protocol::types::{AnyApiKey, NullableString};
+    use kafka_protocol::versions::ApiVersions;
+
+    #[test]
+    fn api_versions_round_trip() {
+        let req = ApiVersionsRequest::V1 {};
+        assert_eq!(ApiKey::ApiVersions, ApiVersionsRequest::API_KEY);
+        assert_eq!(1, req.version());
+
+        let frame = encode_request(&req, 42, NullableString::from("me")).unwrap();
+        let (header, body) = decode_req_header(&frame[4..]).unwrap();
+        assert_eq!(AnyApiKey::Known(ApiKey::ApiVersions), header.api_key());
+        assert_eq!(42, header.correlation_id());
+        assert!(body.is_empty());
+
+        let resp = ApiVersionsResponse::V1 {
+            error_code: 0,
+            api_versions: vec![api_versions_response::v1::ApiVersions {
+                api_key: ApiKey::Fetch.into(),
+                min_version: 0,
+                max_version: 11,
+            }],
+            throttle_time_ms: 0,
+        };
+        let header = ResponseHeader::new(ApiKey::ApiVersions, 1, 42);
+        let frame = encode_resp(&header, &resp).unwrap();
+        let (header, decoded) = decode_response(&frame[4..], &req).unwrap();
+        assert_eq!(42, header.correlation_id());
+        assert_eq!(resp, decoded);
+        assert_eq!(
+            vec![(AnyApiKey::Known(ApiKey::Fetch), 0, 11)],
+            decoded.api_key_versions()
+        );
+    }
+
+    #[test]
+    fn subset_only() {
+        assert!(FetchRequest::has_field("isolation_level", 4));
+
+        let mut deserializer = Deserializer::from_bytes(&[], 0);
+        assert!(Response::deserialize_for(ApiKey::Produce, &mut deserializer).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde::{Deserialize, Serialize};
+
+use kafka_protocol::codec::{direct, Deserializer, Serializer};
+use kafka_protocol::model::*;
+use kafka_protocol::types::*;
+
+const NB_TOPICS: i32 = 10;
+const NB_PARTITIONS: i32 = 20;
+
+fn fetch_response() -> FetchResponse {
+    FetchResponse::V11 {
+        throttle_time_ms: 0,
+        error_code: 0,
+        session_id: 1,
+        responses: (0..NB_TOPICS)
+            .map(|topic| fetch_response::v11::Responses {
+                topic: format!("topic-{}", topic),
+                partition_responses: (0..NB_PARTITIONS)
+                    .map(|partition| fetch_response::v11::PartitionResponses {
+                        partition_header: fetch_response::v11::PartitionHeader {
+                            partition,
+                            error_code: 0,
+                            high_watermark: 1000,
+                            last_stable_offset: 1000,
+                            log_start_offset: 0,
+                            aborted_transactions: vec![],
+                            preferred_read_replica: -1,
+                        },
+                        record_set: NullableBytes::from(vec![0; 1024]),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+fn metadata_response() -> MetadataResponse {
+    MetadataResponse::V8 {
+        throttle_time_ms: 0,
+        brokers: (0..3)
+            .map(|node_id| metadata_response::v8::Brokers {
+                node_id,
+                host: format!("broker-{}", node_id),
+                port: 9092,
+                rack: NullableString(None),
+            })
+            .collect(),
+        cluster_id: NullableString::from("cluster"),
+        controller_id: 0,
+        topics: (0..NB_TOPICS)
+            .map(|topic| metadata_response::v8::Topics {
+                error_code: 0,
+                name: format!("topic-{}", topic),
+                is_internal: false,
+                partitions: (0..NB_PARTITIONS)
+                    .map(|partition| metadata_response::v8::Partitions {
+                        error_code: 0,
+                        partition_index: partition,
+                        leader_id: partition % 3,
+                        leader_epoch: 0,
+                        replica_nodes: vec![0, 1, 2],
+                        isr_nodes: vec![0, 1, 2],
+                        offline_replicas: vec![],
+                    })
+                    .collect(),
+                topic_authorized_operations: 0,
+            })
+            .collect(),
+        cluster_authorized_operations: 0,
+    }
+}
+
+fn serde_encode<T: Serialize>(val: &T) -> Vec<u8> {
+    let mut serializer = Serializer::new();
+    val.serialize(&mut serializer).unwrap();
+    serializer.bytes()
+}
+
+fn serde_decode<'de, T: Deserialize<'de>>(input: &'de [u8], version: usize) -> T {
+    T::deserialize(&mut Deserializer::from_bytes(input, version)).unwrap()
+}
+
+fn bench_response<T>(c: &mut Criterion, name: &str, val: T, version: i16)
+where
+    T: Serialize + for<'de> Deserialize<'de> + direct::Encode + direct::Decode,
+{
+    let bytes = direct::encode(&val);
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("encode/serde", |b| b.iter(|| serde_encode(black_box(&val))));
+    group.bench_function("encode/direct", |b| {
+        b.iter(|| direct::encode(black_box(&val)))
+    });
+    group.bench_function("decode/serde", |b| {
+        b.iter(|| serde_decode::<T>(black_box(&bytes), version as usize))
+    });
+    group.bench_function("decode/direct", |b| {
+        b.iter(|| direct::decode::<T>(black_box(&bytes), version).unwrap())
+    });
+    group.finish();
+}
+
+fn fetch(c: &mut Criterion) {
+    bench_response(c, "fetch_response", fetch_response(), 11);
+}
+
+fn metadata(c: &mut Criterion) {
+    bench_response(c, "metadata_response", metadata_response(), 8);
+}
+
+criterion_group!(benches, fetch, metadata);
+criterion_main!(benches);
@@ -4,10 +4,23 @@ use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
 
-pub fn decompress(src: &[u8]) -> std::io::Result<Vec<u8>> {
-    let mut buffer = vec![];
-    GzDecoder::new(src).read_to_end(&mut buffer)?;
-    Ok(buffer)
+use crate::codec::compression::CompressionCodec;
+
+/// Gzip codec backed by `flate2`.
+pub struct GzipCodec;
+
+impl CompressionCodec for GzipCodec {
+    fn compress(&self, src: &[u8]) -> std::io::Result<Vec<u8>> {
+        compress(src)
+    }
+
+    fn decoder<'a>(&self, src: &'a [u8], _max_size: usize) -> std::io::Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(decoder(src)?))
+    }
+}
+
+pub fn decoder<'a>(src: &'a [u8]) -> std::io::Result<impl Read + 'a> {
+    Ok(GzDecoder::new(src))
 }
 
 pub fn compress(src: &[u8]) -> std::io::Result<Vec<u8>> {
@@ -1,20 +1,191 @@
-use std::io::{Cursor, Error};
+use std::borrow::Cow;
+use std::io::{Cursor, Error, ErrorKind, Read};
 
-use lz4::{Decoder, EncoderBuilder};
+use lz4::{BlockMode, ContentChecksum, Decoder, EncoderBuilder};
 
-pub fn decompress(src: &[u8]) -> std::io::Result<Vec<u8>> {
-    let mut buf = vec![];
-    let mut dec = Decoder::new(src)?;
-    std::io::copy(&mut dec, &mut buf)?;
-    Ok(buf)
+use crate::codec::compression::CompressionCodec;
+
+const MAGIC: &'static [u8] = &[0x04, 0x22, 0x4d, 0x18];
+
+/// LZ4 codec backed by the `lz4` C library.
+pub struct Lz4Codec;
+
+impl CompressionCodec for Lz4Codec {
+    fn compress(&self, src: &[u8]) -> std::io::Result<Vec<u8>> {
+        compress(src)
+    }
+
+    fn decoder<'a>(&self, src: &'a [u8], _max_size: usize) -> std::io::Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(decoder(src)?))
+    }
+}
+
+/// The LZ4 frame flavour to use, which depends on the message format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lz4Framing {
+    /// Standard LZ4 frame, used by message format v1 and v2 (see KIP-57).
+    Standard,
+    /// Framing written by `KafkaLZ4BlockOutputStream` for message format v0,
+    /// whose header checksum wrongly also covers the frame magic number.
+    Legacy,
+}
+
+impl Lz4Framing {
+    /// Returns the framing used along with a given message format version.
+    pub fn for_magic(magic: i8) -> Self {
+        match magic {
+            0 => Lz4Framing::Legacy,
+            _ => Lz4Framing::Standard,
+        }
+    }
+}
+
+pub fn decoder<'a>(src: &'a [u8]) -> std::io::Result<impl Read + 'a> {
+    decoder_with(src, Lz4Framing::Standard)
 }
 
 pub fn compress(src: &[u8]) -> std::io::Result<Vec<u8>> {
+    compress_with(src, Lz4Framing::Standard)
+}
+
+/// Streams the decompressed content of `src`, framed as per `framing`.
+pub fn decoder_with<'a>(
+    src: &'a [u8],
+    framing: Lz4Framing,
+) -> std::io::Result<Decoder<Cursor<Cow<'a, [u8]>>>> {
+    let src = match framing {
+        Lz4Framing::Standard => Cow::Borrowed(src),
+        Lz4Framing::Legacy => {
+            // Like the Java consumer, ignore the legacy header checksum by
+            // replacing it with the one expected by the lz4 library.
+            let hc_pos = header_checksum_pos(src)?;
+            let mut fixed = src.to_vec();
+            fixed[hc_pos] = header_checksum(&src[MAGIC.len()..hc_pos]);
+            Cow::Owned(fixed)
+        }
+    };
+    Decoder::new(Cursor::new(src))
+}
+
+/// Compresses `src` into a single LZ4 frame, framed as per `framing`.
+/// `Lz4Codec`, which the registry uses, always writes `Standard` frames.
+pub fn compress_with(src: &[u8], framing: Lz4Framing) -> std::io::Result<Vec<u8>> {
+    // Same frame settings as `KafkaLZ4BlockOutputStream`
     let buf = vec![];
-    let mut enc = EncoderBuilder::new().level(4).build(buf)?;
+    let mut enc = EncoderBuilder::new()
+        .level(4)
+        .block_mode(BlockMode::Independent)
+        .checksum(ContentChecksum::NoChecksum)
+        .build(buf)?;
     let mut cur = Cursor::new(src);
     std::io::copy(&mut cur, &mut enc)?;
     let res = enc.finish();
     res.1.map_err(Error::from)?;
-    Ok(res.0)
+    let mut buf = res.0;
+
+    if let Lz4Framing::Legacy = framing {
+        let hc_pos = header_checksum_pos(&buf)?;
+        buf[hc_pos] = header_checksum(&buf[..hc_pos]);
+    }
+
+    Ok(buf)
+}
+
+/// Returns the position of the header checksum byte of an LZ4 frame.
+fn header_checksum_pos(src: &[u8]) -> std::io::Result<usize> {
+    if src.len() < MAGIC.len() + 3 || !src.starts_with(MAGIC) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Invalid LZ4 frame header",
+        ));
+    }
+
+    let flg = src[MAGIC.len()];
+    let mut pos = MAGIC.len() + 2; // FLG and BD bytes
+    if flg & 0x08 != 0 {
+        pos += 8; // Content size
+    }
+    if flg & 0x01 != 0 {
+        pos += 4; // Dictionary id
+    }
+
+    if src.len() <= pos {
+        Err(Error::new(
+            ErrorKind::InvalidData,
+            "Invalid LZ4 frame header",
+        ))
+    } else {
+        Ok(pos)
+    }
+}
+
+fn header_checksum(bytes: &[u8]) -> u8 {
+    ((xxh32(bytes, 0) >> 8) & 0xff) as u8
+}
+
+const PRIME32_1: u32 = 2654435761;
+const PRIME32_2: u32 = 2246822519;
+const PRIME32_3: u32 = 3266489917;
+const PRIME32_4: u32 = 668265263;
+const PRIME32_5: u32 = 374761393;
+
+/// Returns the xxHash32 of `buf`, as used by LZ4 frame checksums.
+fn xxh32(mut buf: &[u8], seed: u32) -> u32 {
+    fn read_u32(buf: &[u8]) -> u32 {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(&buf[..4]);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn round(acc: u32, lane: u32) -> u32 {
+        acc.wrapping_add(lane.wrapping_mul(PRIME32_2))
+            .rotate_left(13)
+            .wrapping_mul(PRIME32_1)
+    }
+
+    let len = buf.len() as u32;
+
+    let mut h = if buf.len() >= 16 {
+        let mut v1 = seed.wrapping_add(PRIME32_1).wrapping_add(PRIME32_2);
+        let mut v2 = seed.wrapping_add(PRIME32_2);
+        let mut v3 = seed;
+        let mut v4 = seed.wrapping_sub(PRIME32_1);
+        while buf.len() >= 16 {
+            v1 = round(v1, read_u32(&buf[0..]));
+            v2 = round(v2, read_u32(&buf[4..]));
+            v3 = round(v3, read_u32(&buf[8..]));
+            v4 = round(v4, read_u32(&buf[12..]));
+            buf = &buf[16..];
+        }
+        v1.rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18))
+    } else {
+        seed.wrapping_add(PRIME32_5)
+    };
+
+    h = h.wrapping_add(len);
+
+    while buf.len() >= 4 {
+        h = h
+            .wrapping_add(read_u32(buf).wrapping_mul(PRIME32_3))
+            .rotate_left(17)
+            .wrapping_mul(PRIME32_4);
+        buf = &buf[4..];
+    }
+
+    for &b in buf {
+        h = h
+            .wrapping_add(u32::from(b).wrapping_mul(PRIME32_5))
+            .rotate_left(11)
+            .wrapping_mul(PRIME32_1);
+    }
+
+    h ^= h >> 15;
+    h = h.wrapping_mul(PRIME32_2);
+    h ^= h >> 13;
+    h = h.wrapping_mul(PRIME32_3);
+    h ^= h >> 16;
+    h
 }
@@ -0,0 +1,31 @@
+use std::io::{Error, ErrorKind, Read, Write};
+
+use lz4_flex::frame::{BlockMode, FrameDecoder, FrameEncoder, FrameInfo};
+
+use crate::codec::compression::CompressionCodec;
+
+/// LZ4 codec backed by the pure Rust `lz4_flex` crate.
+pub struct Lz4FlexCodec;
+
+impl CompressionCodec for Lz4FlexCodec {
+    fn compress(&self, src: &[u8]) -> std::io::Result<Vec<u8>> {
+        compress(src)
+    }
+
+    fn decoder<'a>(&self, src: &'a [u8], _max_size: usize) -> std::io::Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(decoder(src)?))
+    }
+}
+
+pub fn decoder<'a>(src: &'a [u8]) -> std::io::Result<impl Read + 'a> {
+    Ok(FrameDecoder::new(src))
+}
+
+pub fn compress(src: &[u8]) -> std::io::Result<Vec<u8>> {
+    // Same frame settings as `KafkaLZ4BlockOutputStream`
+    let info = FrameInfo::new().block_mode(BlockMode::Independent);
+    let mut enc = FrameEncoder::with_frame_info(info, vec![]);
+    enc.write_all(src)?;
+    enc.finish()
+        .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))
+}
@@ -1,3 +1,9 @@
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Read};
+use std::sync::{Arc, RwLock};
+
+use lazy_static::lazy_static;
+
 #[cfg(feature = "gzip")]
 pub mod gzip;
 
@@ -7,9 +13,18 @@ pub mod snappy;
 #[cfg(feature = "lz4")]
 pub mod lz4;
 
+#[cfg(feature = "lz4-pure")]
+pub mod lz4_pure;
+
 #[cfg(feature = "zstd")]
 pub mod zstd;
 
+#[cfg(feature = "zstd-pure")]
+pub mod zstd_pure;
+
+/// Default upper bound, in bytes, of the decompressed records of a single batch.
+pub const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 64 * 1024 * 1024;
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Compression {
     None,
@@ -32,3 +47,156 @@ impl Compression {
         }
     }
 }
+
+/// A codec used to compress and decompress the records of a `RecordBatch`.
+///
+/// Built-in codecs are registered according to enabled cargo features, any
+/// of them can be overridden with `register_codec`.
+///
+/// Registered codecs compress with the framing of message format v2. The
+/// framings of older formats, `lz4::Lz4Framing::Legacy` and
+/// `snappy::SnappyFraming::Xerial`, depend on the message format rather than
+/// on the compression type, so they bypass the registry: they are written
+/// with `compress_with` of their module.
+pub trait CompressionCodec: Send + Sync {
+    /// Compresses `src` in one go.
+    fn compress(&self, src: &[u8]) -> std::io::Result<Vec<u8>>;
+
+    /// Returns a reader streaming the decompressed content of `src`. The
+    /// returned reader is bounded to `max_size` bytes by the caller, the
+    /// limit is given as a hint for codecs that know sizes upfront.
+    fn decoder<'a>(&self, src: &'a [u8], max_size: usize) -> std::io::Result<Box<dyn Read + 'a>>;
+}
+
+lazy_static! {
+    static ref CODECS: RwLock<HashMap<Compression, Arc<dyn CompressionCodec>>> =
+        RwLock::new(builtin_codecs());
+}
+
+#[allow(unused_mut)]
+fn builtin_codecs() -> HashMap<Compression, Arc<dyn CompressionCodec>> {
+    let mut codecs: HashMap<Compression, Arc<dyn CompressionCodec>> = HashMap::new();
+
+    #[cfg(feature = "gzip")]
+    codecs.insert(Compression::Gzip, Arc::new(gzip::GzipCodec));
+
+    #[cfg(feature = "snappy")]
+    codecs.insert(Compression::Snappy, Arc::new(snappy::SnappyCodec));
+
+    #[cfg(feature = "lz4-pure")]
+    codecs.insert(Compression::Lz4, Arc::new(lz4_pure::Lz4FlexCodec));
+
+    // Prefer the C backend when both are enabled
+    #[cfg(feature = "lz4")]
+    codecs.insert(Compression::Lz4, Arc::new(lz4::Lz4Codec));
+
+    #[cfg(feature = "zstd-pure")]
+    codecs.insert(Compression::Zstd, Arc::new(zstd_pure::RuzstdCodec));
+
+    // Prefer the C backend when both are enabled
+    #[cfg(feature = "zstd")]
+    codecs.insert(Compression::Zstd, Arc::new(zstd::ZstdCodec));
+
+    codecs
+}
+
+/// Registers `codec` for `compression`, returns the codec it replaced if any,
+/// e.g. to register it back later.
+pub fn register_codec(
+    compression: Compression,
+    codec: Arc<dyn CompressionCodec>,
+) -> Option<Arc<dyn CompressionCodec>> {
+    CODECS
+        .write()
+        .expect("Poisoned codecs registry")
+        .insert(compression, codec)
+}
+
+/// Returns the codec currently registered for `compression`, if any.
+pub fn codec(compression: &Compression) -> Option<Arc<dyn CompressionCodec>> {
+    CODECS
+        .read()
+        .expect("Poisoned codecs registry")
+        .get(compression)
+        .cloned()
+}
+
+fn unsupported(compression: &Compression) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Unsupported compression format: {:?}", compression),
+    )
+}
+
+/// Compresses `src` with the codec registered for `compression`.
+pub(crate) fn compress(compression: &Compression, src: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    match compression {
+        Compression::None => Ok(src),
+        _ => codec(compression)
+            .ok_or_else(|| unsupported(compression))?
+            .compress(&src),
+    }
+}
+
+/// Returns a reader streaming the decompressed content of `src`, which fails
+/// as soon as more than `max_size` bytes have been inflated.
+pub(crate) fn decoder<'a>(
+    compression: &Compression,
+    src: &'a [u8],
+    max_size: usize,
+) -> std::io::Result<Box<dyn Read + 'a>> {
+    let dec: Box<dyn Read + 'a> = match compression {
+        Compression::None => Box::new(src),
+        _ => codec(compression)
+            .ok_or_else(|| unsupported(compression))?
+            .decoder(src, max_size)?,
+    };
+
+    Ok(Box::new(Bounded::new(dec, max_size)))
+}
+
+/// Reader adapter that fails with `InvalidData` when its inner reader
+/// yields more than `limit` bytes.
+pub(crate) struct Bounded<R> {
+    inner: R,
+    limit: usize,
+    remaining: usize,
+}
+
+impl<R: Read> Bounded<R> {
+    pub(crate) fn new(inner: R, limit: usize) -> Self {
+        Bounded {
+            inner,
+            limit,
+            remaining: limit,
+        }
+    }
+}
+
+impl<R: Read> Read for Bounded<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.remaining == 0 {
+            // Only fail if the inner reader actually has more to give
+            let mut probe = [0u8; 1];
+            return match self.inner.read(&mut probe)? {
+                0 => Ok(0),
+                _ => Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "Decompressed size exceeds the configured maximum of {} bytes",
+                        self.limit
+                    ),
+                )),
+            };
+        }
+
+        let max = std::cmp::min(buf.len(), self.remaining);
+        let n = self.inner.read(&mut buf[..max])?;
+        self.remaining -= n;
+        Ok(n)
+    }
+}
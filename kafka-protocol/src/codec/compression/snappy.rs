@@ -1,51 +1,176 @@
-use std::io::{Error, ErrorKind};
+use std::io::{Cursor, Error, ErrorKind, Read};
 
-use snap::{Decoder, Encoder};
+use snap::{decompress_len, Decoder, Encoder};
+
+use crate::codec::compression::CompressionCodec;
 
 const JAVA_MAGIC: &'static [u8] = &[0x82, b'S', b'N', b'A', b'P', b'P', b'Y', 0];
 
+/// Default uncompressed block size of snappy-java's `SnappyOutputStream`.
+pub const DEFAULT_XERIAL_BLOCK_SIZE: usize = 32 * 1024;
+
+/// The snappy framing to write, which depends on the message format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnappyFraming {
+    /// A single raw snappy block.
+    Raw,
+    /// The block framing of snappy-java (xerial), as expected by Java
+    /// consumers of message format v0 and v1. Input is split into blocks
+    /// of at most `block_size` uncompressed bytes.
+    Xerial { block_size: usize },
+}
+
+impl SnappyFraming {
+    /// Returns the framing to write along with a given message format version.
+    pub fn for_magic(magic: i8) -> Self {
+        match magic {
+            0 | 1 => SnappyFraming::Xerial {
+                block_size: DEFAULT_XERIAL_BLOCK_SIZE,
+            },
+            _ => SnappyFraming::Raw,
+        }
+    }
+}
+
 macro_rules! err(
     ($($arg:tt)*) => (Err(Error::new(ErrorKind::InvalidData, format!($($arg)*))))
 );
 
-pub fn decompress(src: &[u8]) -> std::io::Result<Vec<u8>> {
-    if !src.starts_with(JAVA_MAGIC) {
-        Decoder::new().decompress_vec(src).map_err(Error::from)
-    } else {
-        // See SnappyInputStream.java in https://github.com/xerial/snappy-java
+/// Snappy codec backed by `snap`, reads both raw and snappy-java framings.
+pub struct SnappyCodec;
 
-        ensure(12, src)?;
-        let mut bytes = [0u8; 4];
-        bytes.copy_from_slice(&src[8..12]);
-        let version = i32::from_be_bytes(bytes);
+impl CompressionCodec for SnappyCodec {
+    fn compress(&self, src: &[u8]) -> std::io::Result<Vec<u8>> {
+        compress(src)
+    }
+
+    fn decoder<'a>(&self, src: &'a [u8], max_size: usize) -> std::io::Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(decoder(src, max_size)?))
+    }
+}
+
+pub fn decoder<'a>(src: &'a [u8], max_size: usize) -> std::io::Result<impl Read + 'a> {
+    SnappyDecoder::new(src, max_size)
+}
+
+pub fn compress(src: &[u8]) -> std::io::Result<Vec<u8>> {
+    compress_with(src, SnappyFraming::Raw)
+}
+
+/// Compresses `src`, framed as per `framing`.
+/// `SnappyCodec`, which the registry uses, always writes `Raw` blocks.
+pub fn compress_with(src: &[u8], framing: SnappyFraming) -> std::io::Result<Vec<u8>> {
+    match framing {
+        SnappyFraming::Raw => Encoder::new().compress_vec(src).map_err(Error::from),
+
+        SnappyFraming::Xerial { block_size } => {
+            if block_size == 0 {
+                return err!("Invalid snappy-java block size {:?}", block_size);
+            }
+
+            // See SnappyOutputStream.java in https://github.com/xerial/snappy-java
+
+            let mut buf = Vec::with_capacity(16 + src.len());
+            buf.extend_from_slice(JAVA_MAGIC);
+            buf.extend_from_slice(&1i32.to_be_bytes()); // version
+            buf.extend_from_slice(&1i32.to_be_bytes()); // compatible version
 
-        if version != 1 {
-            return err!("Unsupported snappy-java codec version {:?}", version);
+            let mut enc = Encoder::new();
+            for block in src.chunks(block_size) {
+                let compressed = enc.compress_vec(block)?;
+                buf.extend_from_slice(&(compressed.len() as i32).to_be_bytes());
+                buf.extend_from_slice(&compressed);
+            }
+
+            Ok(buf)
         }
-        // &src[12..16] is the "compatible version"; ignore for now
+    }
+}
+
+/// Streams the content of either a raw snappy block or a snappy-java
+/// framed input, one block at a time.
+struct SnappyDecoder<'a> {
+    src: &'a [u8],
+    pos: usize,
+    block: Cursor<Vec<u8>>,
+    remaining: usize,
+}
+
+impl<'a> SnappyDecoder<'a> {
+    fn new(src: &'a [u8], max_size: usize) -> std::io::Result<Self> {
+        let mut dec = SnappyDecoder {
+            src,
+            pos: src.len(),
+            block: Cursor::new(vec![]),
+            remaining: max_size,
+        };
 
-        let mut decompressed = vec![];
+        if !src.starts_with(JAVA_MAGIC) {
+            dec.decompress_block(src)?;
+        } else {
+            // See SnappyInputStream.java in https://github.com/xerial/snappy-java
 
-        let mut i = 16;
-        while i < src.len() {
-            ensure(i + 4, src)?;
+            ensure(16, src)?;
             let mut bytes = [0u8; 4];
-            bytes.copy_from_slice(&src[i..i + 4]);
-            let n = i32::from_be_bytes(bytes) as usize;
-            i += 4;
-
-            ensure(i + n, src)?;
-            let chunk = Decoder::new().decompress_vec(&src[i..i + n])?;
-            decompressed.extend(chunk);
-            i += n;
+            bytes.copy_from_slice(&src[8..12]);
+            let version = i32::from_be_bytes(bytes);
+
+            if version != 1 {
+                return err!("Unsupported snappy-java codec version {:?}", version);
+            }
+            // &src[12..16] is the "compatible version"; ignore for now
+
+            dec.pos = 16;
+        }
+
+        Ok(dec)
+    }
+
+    /// Decompresses a raw snappy block, after having checked that its
+    /// announced length fits in what remains of the allowed size.
+    fn decompress_block(&mut self, block: &[u8]) -> std::io::Result<()> {
+        let len = decompress_len(block)?;
+        if len > self.remaining {
+            return err!("Snappy decompressed size exceeds the configured maximum");
+        }
+        self.remaining -= len;
+        self.block = Cursor::new(Decoder::new().decompress_vec(block)?);
+        Ok(())
+    }
+
+    /// Moves to the next snappy-java chunk, returns false when there is none.
+    fn next_chunk(&mut self) -> std::io::Result<bool> {
+        let src = self.src;
+        let i = self.pos;
+        if i >= src.len() {
+            return Ok(false);
         }
 
-        Ok(decompressed)
+        ensure(i + 4, src)?;
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(&src[i..i + 4]);
+        let n = i32::from_be_bytes(bytes);
+        if n < 0 {
+            return err!("Invalid snappy-java chunk size {:?}", n);
+        }
+        let n = n as usize;
+
+        ensure(i + 4 + n, src)?;
+        self.decompress_block(&src[i + 4..i + 4 + n])?;
+        self.pos = i + 4 + n;
+        Ok(true)
     }
 }
 
-pub fn compress(src: &[u8]) -> std::io::Result<Vec<u8>> {
-    Encoder::new().compress_vec(src).map_err(Error::from)
+impl<'a> Read for SnappyDecoder<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            let n = self.block.read(buf)?;
+            if n > 0 || buf.is_empty() || !self.next_chunk()? {
+                return Ok(n);
+            }
+        }
+    }
 }
 
 fn ensure(size: usize, slice: &[u8]) -> std::io::Result<()> {
@@ -1,5 +1,24 @@
-pub fn decompress(src: &[u8]) -> std::io::Result<Vec<u8>> {
-    zstd::decode_all(src)
+use std::io::Read;
+
+use zstd::stream::read::Decoder;
+
+use crate::codec::compression::CompressionCodec;
+
+/// Zstd codec backed by the `zstd` C library.
+pub struct ZstdCodec;
+
+impl CompressionCodec for ZstdCodec {
+    fn compress(&self, src: &[u8]) -> std::io::Result<Vec<u8>> {
+        compress(src)
+    }
+
+    fn decoder<'a>(&self, src: &'a [u8], _max_size: usize) -> std::io::Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(decoder(src)?))
+    }
+}
+
+pub fn decoder<'a>(src: &'a [u8]) -> std::io::Result<impl Read + 'a> {
+    Decoder::with_buffer(src)
 }
 
 pub fn compress(src: &[u8]) -> std::io::Result<Vec<u8>> {
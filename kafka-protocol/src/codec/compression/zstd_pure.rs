@@ -0,0 +1,27 @@
+use std::io::{Error, ErrorKind, Read};
+
+use ruzstd::decoding::StreamingDecoder;
+use ruzstd::encoding::{compress_to_vec, CompressionLevel};
+
+use crate::codec::compression::CompressionCodec;
+
+/// Zstd codec backed by the pure Rust `ruzstd` crate.
+pub struct RuzstdCodec;
+
+impl CompressionCodec for RuzstdCodec {
+    fn compress(&self, src: &[u8]) -> std::io::Result<Vec<u8>> {
+        compress(src)
+    }
+
+    fn decoder<'a>(&self, src: &'a [u8], _max_size: usize) -> std::io::Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(decoder(src)?))
+    }
+}
+
+pub fn decoder<'a>(src: &'a [u8]) -> std::io::Result<impl Read + 'a> {
+    StreamingDecoder::new(src).map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))
+}
+
+pub fn compress(src: &[u8]) -> std::io::Result<Vec<u8>> {
+    Ok(compress_to_vec(src, CompressionLevel::Fastest))
+}
@@ -0,0 +1,139 @@
+use std::collections::VecDeque;
+
+use serde::de::{self, Deserialize};
+
+use crate::codec::{Deserializer, Result};
+use crate::model::{ApiKey, RequestHeader, Response, ResponseHeader};
+use crate::request::Request;
+use crate::types::NullableString;
+
+/// A request sent on a connection and awaiting its response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InFlightRequest {
+    pub correlation_id: i32,
+    pub api_key: ApiKey,
+    pub api_version: i16,
+}
+
+/// Registry of the requests in flight on a single connection.
+///
+/// A response header only carries the correlation id of its request, so
+/// the api key and version it was sent with are recorded here in order to
+/// decode the response. Brokers answer requests of a connection in order,
+/// which is enforced when matching responses.
+#[derive(Debug, Default)]
+pub struct InFlightRequests {
+    next_correlation_id: i32,
+    pending: VecDeque<InFlightRequest>,
+}
+
+impl InFlightRequests {
+    pub fn new() -> Self {
+        InFlightRequests::default()
+    }
+
+    /// Returns the header to send a request with, under a newly allocated
+    /// correlation id, and registers the request as awaiting a response.
+    pub fn register(
+        &mut self,
+        api_key: ApiKey,
+        api_version: i16,
+        client_id: NullableString,
+    ) -> RequestHeader {
+        let header = self.register_oneway(api_key, api_version, client_id);
+        self.pending.push_back(InFlightRequest {
+            correlation_id: header.correlation_id(),
+            api_key,
+            api_version,
+        });
+        header
+    }
+
+    /// Returns the header to send `req` with, filled in from its type and
+    /// variant, and registers it as awaiting a response.
+    pub fn register_request<R: Request>(
+        &mut self,
+        req: &R,
+        client_id: NullableString,
+    ) -> RequestHeader {
+        self.register(R::API_KEY, req.api_version(), client_id)
+    }
+
+    /// Returns the header to send a request with, under a newly allocated
+    /// correlation id, for requests the broker doesn't answer, such as a
+    /// `Produce` request with `acks = 0`.
+    pub fn register_oneway(
+        &mut self,
+        api_key: ApiKey,
+        api_version: i16,
+        client_id: NullableString,
+    ) -> RequestHeader {
+        let correlation_id = self.next_correlation_id;
+        self.next_correlation_id = correlation_id.checked_add(1).unwrap_or(0);
+
+        RequestHeader::new(api_key, api_version, correlation_id, client_id)
+    }
+
+    /// Returns the number of requests awaiting a response.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns the request a response with `correlation_id` answers, and
+    /// stops tracking it.
+    ///
+    /// Fails when the response answers a request other than the oldest
+    /// one in flight, or no request at all. Either way the connection is
+    /// out of sync and should be closed, the registry is left untouched.
+    pub fn complete(&mut self, correlation_id: i32) -> Result<InFlightRequest> {
+        match self.pending.front() {
+            Some(req) if req.correlation_id == correlation_id => {
+                Ok(self.pending.pop_front().unwrap())
+            }
+            Some(req) if self.contains(correlation_id) => Err(de::Error::custom(format!(
+                "Out of order response: got correlation id {}, expected {}",
+                correlation_id, req.correlation_id
+            ))),
+            _ => Err(de::Error::custom(format!(
+                "Unknown correlation id: {}",
+                correlation_id
+            ))),
+        }
+    }
+
+    /// Decodes a response frame, size excluded, into the response type of
+    /// the request it answers, its header being of the version of that
+    /// request.
+    pub fn decode(&mut self, frame: &[u8]) -> Result<(InFlightRequest, Response)> {
+        // All response header versions start with the correlation id
+        let correlation_id = i32::deserialize(&mut Deserializer::from_bytes(frame, 0))?;
+        let req = self.complete(correlation_id)?;
+
+        let header_version = req.api_key.response_header_version(req.api_version);
+        let mut deserializer = Deserializer::from_bytes(frame, header_version as usize);
+        ResponseHeader::deserialize(&mut deserializer)?;
+        let body = &frame[frame.len() - deserializer.len()..];
+
+        let mut deserializer = Deserializer::from_bytes(body, req.api_version as usize);
+        let resp = Response::deserialize_for(req.api_key, &mut deserializer)?;
+
+        if deserializer.len() == 0 {
+            Ok((req, resp))
+        } else {
+            Err(de::Error::custom(format!(
+                "{} bytes remaining",
+                deserializer.len()
+            )))
+        }
+    }
+
+    fn contains(&self, correlation_id: i32) -> bool {
+        self.pending
+            .iter()
+            .any(|req| req.correlation_id == correlation_id)
+    }
+}
@@ -1,34 +1,66 @@
-// Copied from rust-snappy (snap)
+// Table-driven implementation copied from rust-snappy (snap)
 // https://github.com/BurntSushi/rust-snappy/blob/master/src/crc32.rs
 
 use lazy_static::lazy_static;
 
 const CASTAGNOLI_POLY: u32 = 0x82f63b78;
+const IEEE_POLY: u32 = 0xedb88320;
 
 lazy_static! {
     static ref TABLE: [u32; 256] = make_table(CASTAGNOLI_POLY);
-    static ref TABLE16: [[u32; 256]; 16] = {
-        let mut tab = [[0; 256]; 16];
-        tab[0] = make_table(CASTAGNOLI_POLY);
-        for i in 0..256 {
-            let mut crc = tab[0][i];
-            for j in 1..16 {
-                crc = (crc >> 8) ^ tab[0][crc as u8 as usize];
-                tab[j][i] = crc;
-            }
-        }
-        tab
-    };
+    static ref TABLE16: [[u32; 256]; 16] = make_table16(CASTAGNOLI_POLY);
+    static ref IEEE_TABLE: [u32; 256] = make_table(IEEE_POLY);
+    static ref IEEE_TABLE16: [[u32; 256]; 16] = make_table16(IEEE_POLY);
 }
 
+/// Returns the CRC32 checksum of `buf` using the Castagnoli polynomial, as
+/// used by `RecordBatch` (message format v2).
+///
+/// Uses SSE4.2 or ARMv8 CRC instructions when available at runtime.
 pub fn crc32c(buf: &[u8]) -> u32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("sse4.2") {
+            return unsafe { crc32c_sse42(buf) };
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("crc") {
+            return unsafe { crc32c_armv8(buf) };
+        }
+    }
+
     crc32c_slice8(buf)
 }
 
+/// Returns the CRC32 checksum of `buf` using the IEEE polynomial, as used by
+/// messages of format v0 and v1.
+///
+/// Uses ARMv8 CRC instructions when available at runtime.
+pub fn crc32(buf: &[u8]) -> u32 {
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("crc") {
+            return unsafe { crc32_armv8(buf) };
+        }
+    }
+
+    crc32_slice8(buf)
+}
+
 /// Returns the CRC32 checksum of `buf` using the Castagnoli polynomial.
-fn crc32c_slice8(mut buf: &[u8]) -> u32 {
-    let tab = &*TABLE;
-    let tab8 = &*TABLE16;
+pub(crate) fn crc32c_slice8(buf: &[u8]) -> u32 {
+    slice8(buf, &TABLE, &TABLE16)
+}
+
+/// Returns the CRC32 checksum of `buf` using the IEEE polynomial.
+pub(crate) fn crc32_slice8(buf: &[u8]) -> u32 {
+    slice8(buf, &IEEE_TABLE, &IEEE_TABLE16)
+}
+
+fn slice8(mut buf: &[u8], tab: &[u32; 256], tab8: &[[u32; 256]; 16]) -> u32 {
     let mut bytes = [0u8; 4];
     let mut crc: u32 = !0;
     while buf.len() >= 8 {
@@ -50,6 +82,74 @@ fn crc32c_slice8(mut buf: &[u8]) -> u32 {
     !crc
 }
 
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.2")]
+unsafe fn crc32c_sse42(buf: &[u8]) -> u32 {
+    use std::arch::x86_64::{_mm_crc32_u64, _mm_crc32_u8};
+
+    let mut crc: u64 = !0u32 as u64;
+    let mut chunks = buf.chunks_exact(8);
+    let mut bytes = [0u8; 8];
+    for chunk in &mut chunks {
+        bytes.copy_from_slice(chunk);
+        crc = _mm_crc32_u64(crc, u64::from_le_bytes(bytes));
+    }
+    let mut crc = crc as u32;
+    for &b in chunks.remainder() {
+        crc = _mm_crc32_u8(crc, b);
+    }
+    !crc
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "crc")]
+unsafe fn crc32c_armv8(buf: &[u8]) -> u32 {
+    use std::arch::aarch64::{__crc32cb, __crc32cd};
+
+    let mut crc: u32 = !0;
+    let mut chunks = buf.chunks_exact(8);
+    let mut bytes = [0u8; 8];
+    for chunk in &mut chunks {
+        bytes.copy_from_slice(chunk);
+        crc = __crc32cd(crc, u64::from_le_bytes(bytes));
+    }
+    for &b in chunks.remainder() {
+        crc = __crc32cb(crc, b);
+    }
+    !crc
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "crc")]
+unsafe fn crc32_armv8(buf: &[u8]) -> u32 {
+    use std::arch::aarch64::{__crc32b, __crc32d};
+
+    let mut crc: u32 = !0;
+    let mut chunks = buf.chunks_exact(8);
+    let mut bytes = [0u8; 8];
+    for chunk in &mut chunks {
+        bytes.copy_from_slice(chunk);
+        crc = __crc32d(crc, u64::from_le_bytes(bytes));
+    }
+    for &b in chunks.remainder() {
+        crc = __crc32b(crc, b);
+    }
+    !crc
+}
+
+fn make_table16(poly: u32) -> [[u32; 256]; 16] {
+    let mut tab = [[0; 256]; 16];
+    tab[0] = make_table(poly);
+    for i in 0..256 {
+        let mut crc = tab[0][i];
+        for j in 1..16 {
+            crc = (crc >> 8) ^ tab[0][crc as u8 as usize];
+            tab[j][i] = crc;
+        }
+    }
+    tab
+}
+
 fn make_table(poly: u32) -> [u32; 256] {
     let mut tab = [0; 256];
     for i in 0u32..256u32 {
@@ -1,25 +1,37 @@
 use std::cell::RefCell;
+use std::convert::TryFrom;
 use std::fmt;
 use std::io::prelude::*;
+use std::marker::PhantomData;
 use std::rc::Rc;
 
 use serde::de::{
     self, Deserialize, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
 };
 
-use crate::codec::compression::Compression;
+use crate::codec::compression::{self, Compression, DEFAULT_MAX_DECOMPRESSED_SIZE};
 use crate::codec::crc32::crc32c;
 use crate::codec::error::{Error, Result};
-use crate::model::HeaderResponse;
+use crate::model::{ApiKey, RequestHeader, ResponseHeader};
+use crate::request::Request;
 use crate::types::*;
 
-pub fn decode_resp<'a, T>(input: &'a [u8], version: usize) -> Result<(HeaderResponse, T)>
+/// Decodes a response to a request of `api_key`, with its header of the
+/// version the request gives, and its body of type `T` and `version`.
+pub fn decode_resp<'a, T>(
+    input: &'a [u8],
+    api_key: ApiKey,
+    version: usize,
+) -> Result<(ResponseHeader, T)>
 where
     T: Deserialize<'a>,
 {
-    let mut deserializer = Deserializer::from_bytes(input, version);
+    let header_version = api_key.response_header_version(version as i16);
+    let mut deserializer = Deserializer::from_bytes(input, header_version as usize);
+    let header = ResponseHeader::deserialize(&mut deserializer)?;
+    let input = &input[input.len() - deserializer.len()..];
 
-    let header = HeaderResponse::deserialize(&mut deserializer)?;
+    let mut deserializer = Deserializer::from_bytes(input, version);
     let resp = T::deserialize(&mut deserializer)?;
 
     if deserializer.len() == 0 {
@@ -32,12 +44,36 @@ where
     }
 }
 
+/// Decodes a response to `req`, of its response type and version.
+pub fn decode_response<R: Request>(input: &[u8], req: &R) -> Result<(ResponseHeader, R::Response)> {
+    decode_resp(input, R::API_KEY, req.api_version() as usize)
+}
+
+/// Decodes the header of a request frame, size excluded, of the version its
+/// api key and version give. Returns it along with the remaining body.
+pub fn decode_req_header(input: &[u8]) -> Result<(RequestHeader, &[u8])> {
+    // All request header versions start with the api key and version
+    let mut deserializer = Deserializer::from_bytes(input, 0);
+    let api_key = i16::deserialize(&mut deserializer)?;
+    let api_version = i16::deserialize(&mut deserializer)?;
+    let api_key = match ApiKey::try_from(api_key) {
+        Ok(api_key) => api_key,
+        Err(_) => return Err(de::Error::custom(format!("unknown api key: {}", api_key))),
+    };
+
+    let header_version = api_key.request_header_version(api_version);
+    let mut deserializer = Deserializer::from_bytes(input, header_version as usize);
+    let header = RequestHeader::deserialize(&mut deserializer)?;
+    Ok((header, &input[input.len() - deserializer.len()..]))
+}
+
 #[derive(Debug)]
 pub struct Deserializer<'b, 'de: 'b> {
     input: Rc<RefCell<&'b [u8]>>,
     identifiers: Vec<&'de str>,
     struct_variant: usize,
     record_attributes: Option<Attributes>,
+    max_decompressed_size: usize,
 }
 
 impl<'b, 'de> Deserializer<'b, 'de> {
@@ -47,6 +83,7 @@ impl<'b, 'de> Deserializer<'b, 'de> {
             identifiers: vec![],
             struct_variant: version,
             record_attributes: None,
+            max_decompressed_size: DEFAULT_MAX_DECOMPRESSED_SIZE,
         }
     }
 
@@ -54,6 +91,12 @@ impl<'b, 'de> Deserializer<'b, 'de> {
         self.input.borrow().len()
     }
 
+    /// Sets the maximum size, in bytes, that the records of a single
+    /// compressed `RecordBatch` are allowed to inflate to.
+    pub fn set_max_decompressed_size(&mut self, max_decompressed_size: usize) {
+        self.max_decompressed_size = max_decompressed_size;
+    }
+
     fn peek_attributes(&mut self) -> Result<Attributes> {
         // pub struct RecordBatch {
         //     pub base_offset: i64,
@@ -77,8 +120,20 @@ impl<'b, 'de> Deserializer<'b, 'de> {
         ensure(batch_len_pos, "batch_length", *self.input.borrow())?;
         let mut bytes = [0u8; 4];
         bytes.copy_from_slice(&self.input.borrow()[batch_len_pos - 4..batch_len_pos]);
-        let batch_length = i32::from_be_bytes(bytes) as usize;
+        let batch_length = i32::from_be_bytes(bytes);
+        if batch_length < RecordBatch::INNER_SIZE as i32 {
+            return Err(de::Error::custom(format!(
+                "Invalid batch_length: {}",
+                batch_length
+            )));
+        }
+        let batch_length = batch_length as usize;
         let records_size = batch_length - RecordBatch::INNER_SIZE;
+        ensure(
+            RecordBatch::HEADING_SIZE + batch_length,
+            "record batch",
+            *self.input.borrow(),
+        )?;
 
         // Find `crc` first byte position and read it from current raw input
         let crc_pos = (8 * batch_len_pos + 32 + 8 + 32) / 8;
@@ -115,6 +170,7 @@ impl<'b, 'de> Deserializer<'b, 'de> {
             compression,
             records_len,
             records_size,
+            max_decompressed_size: self.max_decompressed_size,
         })
     }
 }
@@ -125,36 +181,69 @@ struct Attributes {
     is_control: bool,
     records_len: i32,
     records_size: usize,
+    max_decompressed_size: usize,
 }
 
-trait DeserializerExt<'b> {
-    fn record_attributes(&self) -> &Attributes;
+/// Hooks of the Kafka deserializer, for values serde has no model of.
+/// Other deserializers fail on them.
+trait DeserializerExt<'b, 'de>: de::Deserializer<'de> {
+    fn record_attributes(&self) -> std::result::Result<&Attributes, Self::Error>;
+
+    fn input(&self) -> std::result::Result<Rc<RefCell<&'b [u8]>>, Self::Error>;
 
-    fn input(&self) -> Rc<RefCell<&'b [u8]>>;
+    /// Deserializes a compact array, prefixed by its length N + 1 as an
+    /// unsigned varint, as a seq of N elements.
+    fn deserialize_compact_array<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>;
 }
 
-impl<'b, 'de, D> DeserializerExt<'b> for D
+impl<'b, 'de, D> DeserializerExt<'b, 'de> for D
 where
     D: de::Deserializer<'de>,
 {
-    default fn record_attributes(&self) -> &Attributes {
-        unimplemented!()
+    default fn record_attributes(&self) -> std::result::Result<&Attributes, Self::Error> {
+        Err(de::Error::custom("records require the kafka deserializer"))
     }
 
-    default fn input(&self) -> Rc<RefCell<&'b [u8]>> {
-        unimplemented!()
+    default fn input(&self) -> std::result::Result<Rc<RefCell<&'b [u8]>>, Self::Error> {
+        Err(de::Error::custom(
+            "raw input requires the kafka deserializer",
+        ))
+    }
+
+    default fn deserialize_compact_array<V>(
+        self,
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::custom(
+            "compact arrays require the kafka deserializer",
+        ))
     }
 }
 
-impl<'b, 'de> DeserializerExt<'b> for &mut Deserializer<'b, 'de> {
-    fn record_attributes(&self) -> &Attributes {
+impl<'b, 'de> DeserializerExt<'b, 'de> for &mut Deserializer<'b, 'de> {
+    fn record_attributes(&self) -> Result<&Attributes> {
         self.record_attributes
             .as_ref()
-            .expect("Attributes haven't been set")
+            .ok_or_else(|| de::Error::custom("record attributes haven't been set"))
     }
 
-    fn input(&self) -> Rc<RefCell<&'b [u8]>> {
-        self.input.clone()
+    fn input(&self) -> Result<Rc<RefCell<&'b [u8]>>> {
+        Ok(self.input.clone())
+    }
+
+    fn deserialize_compact_array<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let (len, _) = unvar_u32(&mut *self.input.borrow_mut())?;
+        // A null array, of length 0, is decoded as empty
+        let len = len.saturating_sub(1) as usize;
+        de::Deserializer::deserialize_tuple(self, len, visitor)
     }
 }
 
@@ -264,11 +353,16 @@ impl<'a, 'b, 'de> de::Deserializer<'de> for &'a mut Deserializer<'b, 'de> {
         unimplemented!()
     }
 
-    fn deserialize_f64<V>(self, _visitor: V) -> Result<V::Value>
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        unimplemented!()
+        ensure(8, "f64", *self.input.borrow())?;
+        let (val, rest) = self.input.borrow().split_at(8);
+        *self.input.borrow_mut() = rest;
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(val);
+        visitor.visit_f64(f64::from_be_bytes(bytes))
     }
 
     fn deserialize_char<V>(self, _visitor: V) -> Result<V::Value>
@@ -355,26 +449,24 @@ impl<'a, 'b, 'de> de::Deserializer<'de> for &'a mut Deserializer<'b, 'de> {
     where
         V: Visitor<'de>,
     {
-        if self.record_attributes.is_some() {
-            let len = self.record_attributes().records_len;
-            visitor.visit_seq(SeqDeserializer::new(&mut self, len))
-        } else {
-            ensure(4, "seq size (i32)", *self.input.borrow())?;
-            let (val, rest) = self.input.borrow().split_at(4);
-            *self.input.borrow_mut() = rest;
-            let mut bytes = [0u8; 4];
-            bytes.copy_from_slice(val);
-            let len = i32::from_be_bytes(bytes);
-
-            visitor.visit_seq(SeqDeserializer::new(&mut self, len))
-        }
+        ensure(4, "seq size (i32)", *self.input.borrow())?;
+        let (val, rest) = self.input.borrow().split_at(4);
+        *self.input.borrow_mut() = rest;
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(val);
+        let len = i32::from_be_bytes(bytes);
+
+        visitor.visit_seq(SeqDeserializer::new(&mut self, len))
     }
 
-    fn deserialize_tuple<V>(self, _len: usize, _visitor: V) -> Result<V::Value>
+    fn deserialize_tuple<V>(mut self, len: usize, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        unimplemented!()
+        if len > std::i32::MAX as usize {
+            return Err(de::Error::custom(format!("tuple is too long: {}", len)));
+        }
+        visitor.visit_seq(SeqDeserializer::new(&mut self, len as i32))
     }
 
     fn deserialize_tuple_struct<V>(
@@ -827,341 +919,448 @@ impl<'de> Deserialize<'de> for Varlong {
     }
 }
 
-pub(crate) fn zag_i32(reader: &mut impl Read) -> Result<(i32, usize)> {
-    let (i, nb_read) = zag_i64(reader)?;
-    if i < i64::from(i32::min_value()) || i > i64::from(i32::max_value()) {
-        Err(de::Error::custom("int out of range"))
-    } else {
-        Ok((i as i32, nb_read))
-    }
-}
+impl<'de> Deserialize<'de> for Uuid {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Uuid, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct UuidVisitor {
+            nb_read: Rc<RefCell<usize>>,
+        }
 
-pub(crate) fn zag_i64(reader: &mut impl Read) -> Result<(i64, usize)> {
-    let (z, nb_read) = decode_variable(reader)?;
-    Ok(if z & 0x1 == 0 {
-        ((z >> 1) as i64, nb_read)
-    } else {
-        (!(z >> 1) as i64, nb_read)
-    })
-}
+        impl VisitorExt for UuidVisitor {
+            fn consumed(&self) -> Rc<RefCell<usize>> {
+                self.nb_read.clone()
+            }
+        }
 
-fn decode_variable(reader: &mut impl Read) -> Result<(u64, usize)> {
-    let mut i = 0u64;
-    let mut buf = [0u8; 1];
+        impl<'de> Visitor<'de> for UuidVisitor {
+            type Value = Uuid;
 
-    let mut j = 0;
-    loop {
-        if j > 9 {
-            // j * 7 > 64
-            return Err(de::Error::custom(
-                "overflow when decoding zigzag integer value",
-            ));
-        }
-        reader.read_exact(&mut buf[..])?;
-        i |= (u64::from(buf[0] & 0x7F)) << (j * 7);
-        j += 1;
-        if (buf[0] >> 7) == 0 {
-            break;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a 16 bytes uuid")
+            }
+
+            fn visit_bytes<E>(self, bytes: &[u8]) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                ensure(16, "uuid", bytes).map_err(de::Error::custom)?;
+                let mut buf = [0u8; 16];
+                buf.copy_from_slice(&bytes[..16]);
+                *self.nb_read.borrow_mut() = 16;
+                Ok(Uuid(buf))
+            }
         }
-    }
 
-    Ok((i, j))
+        deserializer.deserialize_bytes(UuidVisitor {
+            nb_read: Rc::new(RefCell::new(0)),
+        })
+    }
 }
 
-impl<'de> Deserialize<'de> for Records {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Records, D::Error>
+impl<'de> Deserialize<'de> for UnsignedVarint {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<UnsignedVarint, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        struct RecordsVisitor;
+        struct UnsignedVarintVisitor {
+            nb_read: Rc<RefCell<usize>>,
+        }
 
-        impl<'de> Visitor<'de> for RecordsVisitor {
-            type Value = Records;
+        impl VisitorExt for UnsignedVarintVisitor {
+            fn consumed(&self) -> Rc<RefCell<usize>> {
+                self.nb_read.clone()
+            }
+        }
+
+        impl<'de> Visitor<'de> for UnsignedVarintVisitor {
+            type Value = UnsignedVarint;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "potentially compressed records")
+                write!(formatter, "a variable length u32")
             }
 
-            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Records, A::Error>
+            fn visit_bytes<E>(self, bytes: &[u8]) -> std::result::Result<Self::Value, E>
             where
-                A: SeqAccess<'de>,
+                E: de::Error,
             {
-                let mut records = vec![];
-                while let Some(record) = seq.next_element()? {
-                    records.push(record);
-                }
-                Ok(Records(records))
+                ensure(1, "unsigned varint (u32)", bytes).map_err(de::Error::custom)?;
+                let mut rdr = std::io::Cursor::new(bytes);
+                let (i, nb_read) = unvar_u32(&mut rdr).map_err(de::Error::custom)?;
+                *self.nb_read.borrow_mut() = nb_read;
+                Ok(UnsignedVarint(i))
             }
         }
 
-        let compression = &deserializer.record_attributes().compression;
+        deserializer.deserialize_bytes(UnsignedVarintVisitor {
+            nb_read: Rc::new(RefCell::new(0)),
+        })
+    }
+}
 
-        if let Compression::None = compression {
-            return Ok(deserializer.deserialize_seq(RecordsVisitor)?);
-        }
+/// Implements `Deserialize` for a compact string or byte buf, prefixed by
+/// its length N + 1 as an unsigned varint, 0 standing for null.
+macro_rules! impl_de_compact {
+    ($($ty:ident, $visitor:ident, $what:expr, $from:ident;)*) => {
+        $(
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> std::result::Result<$ty, D::Error>
+                where
+                    D: de::Deserializer<'de>,
+                {
+                    struct $visitor {
+                        nb_read: Rc<RefCell<usize>>,
+                    }
+
+                    impl VisitorExt for $visitor {
+                        fn consumed(&self) -> Rc<RefCell<usize>> {
+                            self.nb_read.clone()
+                        }
+                    }
+
+                    impl<'de> Visitor<'de> for $visitor {
+                        type Value = $ty;
+
+                        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                            write!(formatter, "a kafka {}", $what)
+                        }
+
+                        fn visit_bytes<E>(self, bytes: &[u8]) -> std::result::Result<Self::Value, E>
+                        where
+                            E: de::Error,
+                        {
+                            let (val, nb_read) =
+                                read_compact(bytes, $what).map_err(de::Error::custom)?;
+                            *self.nb_read.borrow_mut() = nb_read;
+                            $from(val).map_err(de::Error::custom)
+                        }
+                    }
+
+                    deserializer.deserialize_bytes($visitor {
+                        nb_read: Rc::new(RefCell::new(0)),
+                    })
+                }
+            }
+        )*
+    };
+}
 
-        let size = deserializer.record_attributes().records_size;
-        let input = deserializer.input();
-        let bytes = *input.borrow();
+impl_de_compact! {
+    CompactString, CompactStringVisitor, "compact str", compact_string;
+    CompactNullableString, CompactNullableStringVisitor, "compact nullable str",
+        compact_nullable_string;
+    CompactBytes, CompactBytesVisitor, "compact byte buf", compact_bytes;
+    CompactNullableBytes, CompactNullableBytesVisitor, "compact nullable byte buf",
+        compact_nullable_bytes;
+}
 
-        let decompressed;
-        match compression {
-            #[cfg(feature = "gzip")]
-            Compression::Gzip => {
-                use crate::codec::compression::gzip;
-                decompressed = gzip::decompress(&bytes[..size]).map_err(de::Error::custom)?;
-            }
+fn compact_string(val: Option<&[u8]>) -> Result<CompactString> {
+    let val = val.ok_or_else::<Error, _>(|| de::Error::custom("compact str is null"))?;
+    Ok(CompactString(String::from_utf8(val.to_vec())?))
+}
 
-            #[cfg(feature = "snappy")]
-            Compression::Snappy => {
-                use crate::codec::compression::snappy;
-                decompressed = snappy::decompress(&bytes[..size]).map_err(de::Error::custom)?;
-            }
+fn compact_nullable_string(val: Option<&[u8]>) -> Result<CompactNullableString> {
+    match val {
+        Some(val) => Ok(CompactNullableString(Some(String::from_utf8(
+            val.to_vec(),
+        )?))),
+        None => Ok(CompactNullableString(None)),
+    }
+}
 
-            #[cfg(feature = "lz4")]
-            Compression::Lz4 => {
-                use crate::codec::compression::lz4;
-                decompressed = lz4::decompress(&bytes[..size]).map_err(de::Error::custom)?;
-            }
+fn compact_bytes(val: Option<&[u8]>) -> Result<CompactBytes> {
+    let val = val.ok_or_else::<Error, _>(|| de::Error::custom("compact byte buf is null"))?;
+    Ok(CompactBytes(val.to_vec()))
+}
+
+fn compact_nullable_bytes(val: Option<&[u8]>) -> Result<CompactNullableBytes> {
+    Ok(CompactNullableBytes(val.map(<[u8]>::to_vec)))
+}
+
+/// Reads a compact string or byte buf at the start of `bytes`, returns it,
+/// `None` when null, along with the number of bytes read.
+fn read_compact<'a>(bytes: &'a [u8], what: &str) -> Result<(Option<&'a [u8]>, usize)> {
+    let mut rdr = std::io::Cursor::new(bytes);
+    let (len, nb_read) = unvar_u32(&mut rdr)?;
+    if len == 0 {
+        return Ok((None, nb_read));
+    }
+
+    let size = len as usize - 1;
+    ensure(nb_read + size, what, bytes)?;
+    Ok((Some(&bytes[nb_read..nb_read + size]), nb_read + size))
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for CompactArray<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<CompactArray<T>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct CompactArrayVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for CompactArrayVisitor<T> {
+            type Value = CompactArray<T>;
 
-            #[cfg(feature = "zstd")]
-            Compression::Zstd => {
-                use crate::codec::compression::zstd;
-                decompressed = zstd::decompress(&bytes[..size]).map_err(de::Error::custom)?;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a kafka compact array")
             }
 
-            _ => {
-                return Err(de::Error::custom(format!(
-                    "Unsupported compression format: {:?}",
-                    compression
-                )));
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut vals = vec![];
+                while let Some(val) = seq.next_element()? {
+                    vals.push(val);
+                }
+                Ok(CompactArray(vals))
             }
         }
 
-        *input.borrow_mut() = &decompressed;
-        let res = Ok(deserializer.deserialize_seq(RecordsVisitor)?);
-        *input.borrow_mut() = &bytes[size..];
-
-        res
+        deserializer.deserialize_compact_array(CompactArrayVisitor(PhantomData))
     }
 }
 
-struct WrapBytes<'a> {
-    underlying: &'a mut Vec<u8>,
-    nb_read: usize,
-}
-
-impl<'de, 'a> DeserializeSeed<'de> for WrapBytes<'a> {
-    type Value = ();
-
-    fn deserialize<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+impl<'de> Deserialize<'de> for TaggedFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<TaggedFields, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        struct WrapBytesVisitor<'a> {
-            underlying: &'a mut Vec<u8>,
+        struct TaggedFieldsVisitor {
             nb_read: Rc<RefCell<usize>>,
-        };
+        }
 
-        impl<'a> VisitorExt for WrapBytesVisitor<'a> {
+        impl VisitorExt for TaggedFieldsVisitor {
             fn consumed(&self) -> Rc<RefCell<usize>> {
                 self.nb_read.clone()
             }
         }
 
-        impl<'de, 'a> Visitor<'de> for WrapBytesVisitor<'a> {
-            type Value = ();
+        impl<'de> Visitor<'de> for TaggedFieldsVisitor {
+            type Value = TaggedFields;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "Vec<u8>")
+                write!(formatter, "kafka tagged fields")
             }
 
             fn visit_bytes<E>(self, bytes: &[u8]) -> std::result::Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                self.underlying
-                    .write(&bytes[..*self.nb_read.borrow()])
-                    .map_err(de::Error::custom)?;
-                Ok(())
+                let mut rdr = bytes;
+                let fields = read_tagged_fields(&mut rdr).map_err(de::Error::custom)?;
+                *self.nb_read.borrow_mut() = bytes.len() - rdr.len();
+                Ok(TaggedFields(fields))
             }
         }
 
-        deserializer.deserialize_bytes(WrapBytesVisitor {
-            underlying: self.underlying,
-            nb_read: Rc::new(RefCell::new(self.nb_read)),
+        deserializer.deserialize_bytes(TaggedFieldsVisitor {
+            nb_read: Rc::new(RefCell::new(0)),
         })
     }
 }
 
-impl<'de> Deserialize<'de> for Record {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Record, D::Error>
+impl<'de> Deserialize<'de> for AnyApiKey {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<AnyApiKey, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        if deserializer.record_attributes().is_control {
-            Ok(Record::Control(RecControl::deserialize(deserializer)?))
-        } else {
-            Ok(Record::Data(RecData::deserialize(deserializer)?))
-        }
+        i16::deserialize(deserializer).map(AnyApiKey::from)
     }
 }
 
-impl<'de> Deserialize<'de> for RecData {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<RecData, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        struct RecDataVisitor;
+/// Reads tagged fields, prefixed by their count, at the start of `buf`,
+/// which is advanced past them.
+pub(crate) fn read_tagged_fields(buf: &mut &[u8]) -> Result<Vec<(u32, Vec<u8>)>> {
+    let (count, _) = unvar_u32(buf)?;
+    let mut fields = vec![];
+    for _ in 0..count {
+        let (tag, _) = unvar_u32(buf)?;
+        let (size, _) = unvar_u32(buf)?;
+        ensure(size as usize, "tagged field", buf)?;
+        let (data, rest) = buf.split_at(size as usize);
+        fields.push((tag, data.to_vec()));
+        *buf = rest;
+    }
+    Ok(fields)
+}
 
-        impl<'de> Visitor<'de> for RecDataVisitor {
-            type Value = RecData;
+pub(crate) fn unvar_u32(reader: &mut impl Read) -> Result<(u32, usize)> {
+    let (i, nb_read) = decode_variable(reader)?;
+    if i > u64::from(std::u32::MAX) {
+        Err(de::Error::custom("unsigned int out of range"))
+    } else {
+        Ok((i as u32, nb_read))
+    }
+}
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "kafka batch")
-            }
+pub(crate) fn zag_i32(reader: &mut impl Read) -> Result<(i32, usize)> {
+    let (i, nb_read) = zag_i64(reader)?;
+    if i < i64::from(i32::min_value()) || i > i64::from(i32::max_value()) {
+        Err(de::Error::custom("int out of range"))
+    } else {
+        Ok((i as i32, nb_read))
+    }
+}
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<Self::Value, V::Error>
-            where
-                V: MapAccess<'de>,
-            {
-                let length: Option<Varint>;
-                let attributes: Option<i8>;
-                let timestamp_delta: Option<Varlong>;
-                let offset_delta: Option<Varint>;
-                let key_length: Option<Varint>;
-                let key: Option<Option<Vec<u8>>>;
-                let value_len: Option<Varint>;
-                let value: Option<Vec<u8>>;
-                let header_len: Option<Varint>;
-                let headers: Option<Vec<HeaderRecord>>;
-
-                length = map.next_value::<Varint>().map(Some)?;
-                attributes = map.next_value::<i8>().map(Some)?;
-                timestamp_delta = map.next_value::<Varlong>().map(Some)?;
-                offset_delta = map.next_value::<Varint>().map(Some)?;
-
-                key_length = map.next_value::<Varint>().map(Some)?;
-                if **key_length.as_ref().unwrap() > -1 {
-                    let mut buf = vec![];
-                    let _ = map.next_value_seed(WrapBytes {
-                        underlying: &mut buf,
-                        nb_read: **key_length.as_ref().unwrap() as usize,
-                    });
-                    key = Some(Some(buf));
-                } else {
-                    key = Some(None);
-                }
+pub(crate) fn zag_i64(reader: &mut impl Read) -> Result<(i64, usize)> {
+    let (z, nb_read) = decode_variable(reader)?;
+    Ok(if z & 0x1 == 0 {
+        ((z >> 1) as i64, nb_read)
+    } else {
+        (!(z >> 1) as i64, nb_read)
+    })
+}
 
-                value_len = map.next_value::<Varint>().map(Some)?;
-                let mut buf = vec![];
-                let _ = map.next_value_seed(WrapBytes {
-                    underlying: &mut buf,
-                    nb_read: **value_len.as_ref().unwrap() as usize,
-                });
-                value = Some(buf);
-
-                header_len = map.next_value::<Varint>().map(Some)?;
-                let mut buf = vec![];
-                for _ in 0..**header_len.as_ref().unwrap() {
-                    buf.push(map.next_value::<HeaderRecord>()?);
-                }
-                headers = Some(buf);
-
-                Ok(RecData {
-                    length: length.unwrap(),
-                    attributes: attributes.unwrap(),
-                    timestamp_delta: timestamp_delta.unwrap(),
-                    offset_delta: offset_delta.unwrap(),
-                    key_length: key_length.unwrap(),
-                    key: key.unwrap(),
-                    value_len: value_len.unwrap(),
-                    value: value.unwrap(),
-                    header_len: header_len.unwrap(),
-                    headers: headers.unwrap(),
-                })
-            }
-        }
+fn decode_variable(reader: &mut impl Read) -> Result<(u64, usize)> {
+    let mut i = 0u64;
+    let mut buf = [0u8; 1];
 
-        const NAME: &'static str = "RecData";
-        const FIELDS: &'static [&'static str] = &[
-            "length",
-            "attributes",
-            "timestamp_delta",
-            "offset_delta",
-            "key_length",
-            "key",
-            "value_len",
-            "value",
-            "header_len",
-            "headers",
-        ];
-
-        deserializer.deserialize_struct(NAME, FIELDS, RecDataVisitor)
+    let mut j = 0;
+    loop {
+        if j > 9 {
+            // j * 7 > 64
+            return Err(de::Error::custom(
+                "overflow when decoding zigzag integer value",
+            ));
+        }
+        reader.read_exact(&mut buf[..])?;
+        i |= (u64::from(buf[0] & 0x7F)) << (j * 7);
+        j += 1;
+        if (buf[0] >> 7) == 0 {
+            break;
+        }
     }
+
+    Ok((i, j))
 }
 
-impl<'de> Deserialize<'de> for HeaderRecord {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<HeaderRecord, D::Error>
+impl<'de> Deserialize<'de> for Records {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Records, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        struct HeaderRecordVisitor;
+        let attributes = deserializer.record_attributes()?;
+        let size = attributes.records_size;
+        let input = deserializer.input()?;
+        let bytes = *input.borrow();
+        ensure(size, "records", bytes).map_err(de::Error::custom)?;
 
-        impl<'de> Visitor<'de> for HeaderRecordVisitor {
-            type Value = HeaderRecord;
+        let records = if let Compression::None = attributes.compression {
+            read_records(&mut &bytes[..size], attributes).map_err(de::Error::custom)?
+        } else {
+            // Records are decoded while being inflated, so that the
+            // decompressed payload is never buffered as a whole.
+            let mut rdr = compression::decoder(
+                &attributes.compression,
+                &bytes[..size],
+                attributes.max_decompressed_size,
+            )
+            .map_err(de::Error::custom)?;
+            read_records(&mut rdr, attributes).map_err(de::Error::custom)?
+        };
+        *input.borrow_mut() = &bytes[size..];
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "kafka record header")
-            }
+        Ok(Records(records))
+    }
+}
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<Self::Value, V::Error>
-            where
-                V: MapAccess<'de>,
-            {
-                let key_length: Option<Varint>;
-                let key: Option<String>;
-                let value_length: Option<Varint>;
-                let value: Option<Option<Vec<u8>>>;
-
-                key_length = map.next_value::<Varint>().map(Some)?;
-                let mut buf = vec![];
-                let _ = map.next_value_seed(WrapBytes {
-                    underlying: &mut buf,
-                    nb_read: **key_length.as_ref().unwrap() as usize,
-                });
-                key = Some(String::from_utf8(buf).map_err(de::Error::custom)?);
-
-                value_length = map.next_value::<Varint>().map(Some)?;
-                if **value_length.as_ref().unwrap() > -1 {
-                    let mut buf = vec![];
-                    let _ = map.next_value_seed(WrapBytes {
-                        underlying: &mut buf,
-                        nb_read: **value_length.as_ref().unwrap() as usize,
-                    });
-
-                    value = Some(Some(buf));
-                } else {
-                    value = Some(None);
-                }
+/// Reads the records of a batch, compressed or not.
+fn read_records(rdr: &mut impl Read, attributes: &Attributes) -> Result<Vec<Record>> {
+    let mut records = vec![];
+    for _ in 0..attributes.records_len {
+        records.push(read_record(rdr, attributes.is_control)?);
+    }
+    Ok(records)
+}
 
-                Ok(HeaderRecord {
-                    key_length: key_length.unwrap(),
-                    key: key.unwrap(),
-                    value_length: value_length.unwrap(),
-                    value: value.unwrap(),
-                })
-            }
-        }
+fn read_record(rdr: &mut impl Read, is_control: bool) -> Result<Record> {
+    if is_control {
+        let mut bytes = [0u8; 2];
+        rdr.read_exact(&mut bytes)?;
+        let version = i16::from_be_bytes(bytes);
+        rdr.read_exact(&mut bytes)?;
+        let r#type = i16::from_be_bytes(bytes);
+        Ok(Record::Control(RecControl { version, r#type }))
+    } else {
+        Ok(Record::Data(read_rec_data(rdr)?))
+    }
+}
+
+fn read_rec_data(rdr: &mut impl Read) -> Result<RecData> {
+    let (length, _) = zag_i32(rdr)?;
+    let mut bytes = [0u8; 1];
+    rdr.read_exact(&mut bytes)?;
+    let attributes = i8::from_be_bytes(bytes);
+    let (timestamp_delta, _) = zag_i64(rdr)?;
+    let (offset_delta, _) = zag_i32(rdr)?;
+
+    let (key_length, _) = zag_i32(rdr)?;
+    let key = read_opt_bytes(rdr, key_length, "record key")?;
+
+    let (value_len, _) = zag_i32(rdr)?;
+    let value = read_opt_bytes(rdr, value_len, "record value")?.unwrap_or_default();
+
+    let (header_len, _) = zag_i32(rdr)?;
+    let mut headers = vec![];
+    for _ in 0..header_len {
+        let (key_length, _) = zag_i32(rdr)?;
+        let key = read_opt_bytes(rdr, key_length, "header key")?.unwrap_or_default();
+        let key = String::from_utf8(key)?;
+
+        let (value_length, _) = zag_i32(rdr)?;
+        let value = read_opt_bytes(rdr, value_length, "header value")?;
+
+        headers.push(HeaderRecord {
+            key_length: Varint(key_length),
+            key,
+            value_length: Varint(value_length),
+            value,
+        });
+    }
 
-        const NAME: &'static str = "HeaderRecord";
-        const FIELDS: &'static [&'static str] = &["key_length", "key", "value_length", "value"];
+    Ok(RecData {
+        length: Varint(length),
+        attributes,
+        timestamp_delta: Varlong(timestamp_delta),
+        offset_delta: Varint(offset_delta),
+        key_length: Varint(key_length),
+        key,
+        value_len: Varint(value_len),
+        value,
+        header_len: Varint(header_len),
+        headers,
+    })
+}
 
-        deserializer.deserialize_struct(NAME, FIELDS, HeaderRecordVisitor)
+/// Reads `len` bytes, or nothing when `len` is -1, without trusting `len`
+/// for upfront allocations.
+fn read_opt_bytes(rdr: &mut impl Read, len: i32, what: &str) -> Result<Option<Vec<u8>>> {
+    if len == -1 {
+        return Ok(None);
+    } else if len < 0 {
+        return Err(de::Error::custom(format!(
+            "Invalid {} length: {}",
+            what, len
+        )));
     }
+
+    let mut buf = vec![];
+    rdr.by_ref().take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() != len as usize {
+        return Err(de::Error::custom(format!(
+            "Not enough bytes ({:?}) to deserialize {} of size {:?}",
+            buf.len(),
+            what,
+            len
+        )));
+    }
+
+    Ok(Some(buf))
 }
 
-fn ensure(size: usize, what: &str, slice: &[u8]) -> Result<()> {
+pub(crate) fn ensure(size: usize, what: &str, slice: &[u8]) -> Result<()> {
     if slice.len() < size {
         Err(de::Error::custom(format!(
             "Not enough bytes ({:?}) to deserialize {} of size {:?}",
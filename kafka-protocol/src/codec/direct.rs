@@ -0,0 +1,406 @@
+//! Direct binary encoding/decoding, without serde.
+//!
+//! Generated requests/responses and their inner structs implement `Encode`
+//! and `Decode`, writing and reading their fields in order, as a faster
+//! alternative to the `Serializer`/`Deserializer` path.
+
+use std::convert::TryFrom;
+
+use serde::de;
+
+use crate::codec::de::{ensure, read_tagged_fields, unvar_u32, zag_i32, zag_i64};
+use crate::codec::error::Result;
+use crate::codec::ser::{compact_len, var_u32, zig_i32, zig_i64};
+use crate::model::ApiKey;
+use crate::types::*;
+
+/// Encodes a value of the protocol, appending it to `buf`.
+pub trait Encode {
+    fn encode(&self, buf: &mut Vec<u8>);
+}
+
+/// Decodes a value of the protocol in `version`, advancing `buf` past it.
+pub trait Decode: Sized {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self>;
+}
+
+/// Encodes a value to a new buffer.
+pub fn encode<T: Encode>(val: &T) -> Vec<u8> {
+    let mut buf = Vec::new();
+    val.encode(&mut buf);
+    buf
+}
+
+/// Decodes a value in `version`, failing unless all of `input` is consumed.
+pub fn decode<T: Decode>(input: &[u8], version: i16) -> Result<T> {
+    let mut buf = input;
+    let val = T::decode(&mut buf, version)?;
+
+    if buf.is_empty() {
+        Ok(val)
+    } else {
+        Err(de::Error::custom(format!(
+            "{} bytes remaining after decoding",
+            buf.len()
+        )))
+    }
+}
+
+fn take<'a>(buf: &mut &'a [u8], size: usize, what: &str) -> Result<&'a [u8]> {
+    ensure(size, what, buf)?;
+    let (val, rest) = buf.split_at(size);
+    *buf = rest;
+    Ok(val)
+}
+
+macro_rules! impl_int {
+    ($($ty:ty, $size:expr, $what:expr;)*) => {
+        $(
+            impl Encode for $ty {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_be_bytes());
+                }
+            }
+
+            impl Decode for $ty {
+                fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+                    let mut bytes = [0u8; $size];
+                    bytes.copy_from_slice(take(buf, $size, $what)?);
+                    Ok(<$ty>::from_be_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_int! {
+    i8, 1, "i8";
+    i16, 2, "i16";
+    i32, 4, "i32";
+    i64, 8, "i64";
+    u32, 4, "u32";
+    f64, 8, "f64";
+}
+
+impl Encode for bool {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+}
+
+impl Decode for bool {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        match take(buf, 1, "bool")?[0] {
+            0u8 => Ok(false),
+            1u8 => Ok(true),
+            _ => Err(de::Error::custom("not a boolean")),
+        }
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        (self.len() as i16).encode(buf);
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Decode for String {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self> {
+        let size = i16::decode(buf, version)?;
+        if size < 0 {
+            return Err(de::Error::custom(format!("invalid str size: {}", size)));
+        }
+        let val = take(buf, size as usize, "str")?;
+        Ok(String::from_utf8(val.to_vec())?)
+    }
+}
+
+impl Encode for NullableString {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self.0 {
+            Some(ref val) => val.encode(buf),
+            None => (-1i16).encode(buf),
+        }
+    }
+}
+
+impl Decode for NullableString {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self> {
+        let size = i16::decode(buf, version)?;
+        if size == -1 {
+            return Ok(NullableString(None));
+        }
+        let val = take(buf, size as usize, "nullable str")?;
+        Ok(NullableString(Some(String::from_utf8(val.to_vec())?)))
+    }
+}
+
+impl Encode for Bytes {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        (self.len() as i32).encode(buf);
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl Decode for Bytes {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self> {
+        let size = i32::decode(buf, version)?;
+        if size < 0 {
+            return Err(de::Error::custom(format!(
+                "invalid byte buf size: {}",
+                size
+            )));
+        }
+        Ok(Bytes(take(buf, size as usize, "byte buf")?.to_vec()))
+    }
+}
+
+impl Encode for NullableBytes {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self.0 {
+            Some(ref val) => {
+                (val.len() as i32).encode(buf);
+                buf.extend_from_slice(val);
+            }
+            None => (-1i32).encode(buf),
+        }
+    }
+}
+
+impl Decode for NullableBytes {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self> {
+        let size = i32::decode(buf, version)?;
+        if size == -1 {
+            return Ok(NullableBytes(None));
+        }
+        let val = take(buf, size as usize, "byte buf")?;
+        Ok(NullableBytes(Some(val.to_vec())))
+    }
+}
+
+impl Encode for Varint {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // Writing to a Vec can't fail
+        zig_i32(self.0, buf).unwrap();
+    }
+}
+
+impl Decode for Varint {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        Ok(Varint(zag_i32(buf)?.0))
+    }
+}
+
+impl Encode for Varlong {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // Writing to a Vec can't fail
+        zig_i64(self.0, buf).unwrap();
+    }
+}
+
+impl Decode for Varlong {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        Ok(Varlong(zag_i64(buf)?.0))
+    }
+}
+
+impl Encode for Uuid {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl Decode for Uuid {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(take(buf, 16, "uuid")?);
+        Ok(Uuid(bytes))
+    }
+}
+
+impl Encode for UnsignedVarint {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // Writing to a Vec can't fail
+        var_u32(self.0, buf).unwrap();
+    }
+}
+
+impl Decode for UnsignedVarint {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        Ok(UnsignedVarint(unvar_u32(buf)?.0))
+    }
+}
+
+/// Encodes the length N + 1 of a compact value of length N, 0 for null.
+///
+/// Panics on lengths of `u32::MAX` or more, which can't be encoded.
+fn encode_compact_len(len: Option<usize>, buf: &mut Vec<u8>) {
+    let len = compact_len(len).unwrap_or_else(|err| panic!("{}", err));
+    UnsignedVarint(len).encode(buf);
+}
+
+/// Decodes a compact string or byte buf, `None` when null.
+fn decode_compact<'a>(buf: &mut &'a [u8], what: &str) -> Result<Option<&'a [u8]>> {
+    match unvar_u32(buf)?.0 {
+        0 => Ok(None),
+        len => take(buf, len as usize - 1, what).map(Some),
+    }
+}
+
+impl Encode for CompactString {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_compact_len(Some(self.len()), buf);
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Decode for CompactString {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        match decode_compact(buf, "compact str")? {
+            Some(val) => Ok(CompactString(String::from_utf8(val.to_vec())?)),
+            None => Err(de::Error::custom("compact str is null")),
+        }
+    }
+}
+
+impl Encode for CompactNullableString {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_compact_len(self.as_ref().map(String::len), buf);
+        if let Some(ref val) = self.0 {
+            buf.extend_from_slice(val.as_bytes());
+        }
+    }
+}
+
+impl Decode for CompactNullableString {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        match decode_compact(buf, "compact nullable str")? {
+            Some(val) => Ok(CompactNullableString(Some(String::from_utf8(
+                val.to_vec(),
+            )?))),
+            None => Ok(CompactNullableString(None)),
+        }
+    }
+}
+
+impl Encode for CompactBytes {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_compact_len(Some(self.len()), buf);
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl Decode for CompactBytes {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        match decode_compact(buf, "compact byte buf")? {
+            Some(val) => Ok(CompactBytes(val.to_vec())),
+            None => Err(de::Error::custom("compact byte buf is null")),
+        }
+    }
+}
+
+impl Encode for CompactNullableBytes {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_compact_len(self.as_ref().map(Vec::len), buf);
+        if let Some(ref val) = self.0 {
+            buf.extend_from_slice(val);
+        }
+    }
+}
+
+impl Decode for CompactNullableBytes {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        let val = decode_compact(buf, "compact nullable byte buf")?;
+        Ok(CompactNullableBytes(val.map(<[u8]>::to_vec)))
+    }
+}
+
+impl Encode for ApiKey {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        (*self as i16).encode(buf);
+    }
+}
+
+impl Decode for ApiKey {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self> {
+        let key = i16::decode(buf, version)?;
+        ApiKey::try_from(key).map_err(|_| de::Error::custom(format!("unknown api key: {}", key)))
+    }
+}
+
+impl Encode for AnyApiKey {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        i16::from(*self).encode(buf);
+    }
+}
+
+impl Decode for AnyApiKey {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self> {
+        i16::decode(buf, version).map(AnyApiKey::from)
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        (self.len() as i32).encode(buf);
+        for val in self {
+            val.encode(buf);
+        }
+    }
+}
+
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self> {
+        // A null array, of length -1, is decoded as empty
+        let len = i32::decode(buf, version)?.max(0) as usize;
+        // Doesn't preallocate more than the input could hold
+        let mut vals = Vec::with_capacity(len.min(buf.len()));
+        for _ in 0..len {
+            vals.push(T::decode(buf, version)?);
+        }
+        Ok(vals)
+    }
+}
+
+impl<T: Encode> Encode for CompactArray<T> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_compact_len(Some(self.len()), buf);
+        for val in self.iter() {
+            val.encode(buf);
+        }
+    }
+}
+
+impl<T: Decode> Decode for CompactArray<T> {
+    fn decode(buf: &mut &[u8], version: i16) -> Result<Self> {
+        // A null array, of length 0, is decoded as empty
+        let len = unvar_u32(buf)?.0.saturating_sub(1) as usize;
+        // Doesn't preallocate more than the input could hold
+        let mut vals = Vec::with_capacity(len.min(buf.len()));
+        for _ in 0..len {
+            vals.push(T::decode(buf, version)?);
+        }
+        Ok(CompactArray(vals))
+    }
+}
+
+impl Encode for TaggedFields {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // Counts and sizes over u32::MAX can't be encoded
+        let len = u32::try_from(self.len()).expect("too many tagged fields");
+        UnsignedVarint(len).encode(buf);
+        for (tag, data) in self.iter() {
+            UnsignedVarint(*tag).encode(buf);
+            let size = u32::try_from(data.len()).expect("tagged field is too long");
+            UnsignedVarint(size).encode(buf);
+            buf.extend_from_slice(data);
+        }
+    }
+}
+
+impl Decode for TaggedFields {
+    fn decode(buf: &mut &[u8], _version: i16) -> Result<Self> {
+        Ok(TaggedFields(read_tagged_fields(buf)?))
+    }
+}
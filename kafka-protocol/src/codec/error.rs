@@ -1,4 +1,4 @@
-use std::{error, error::Error as _, fmt, io};
+use std::{error, fmt, io};
 
 use serde::{de, ser};
 
@@ -25,22 +25,18 @@ impl de::Error for Error {
     }
 }
 
-impl error::Error for Error {
-    fn description(&self) -> &str {
-        &self.message
-    }
-}
+impl error::Error for Error {}
 
 impl fmt::Display for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str(error::Error::description(self))
+        formatter.write_str(&self.message)
     }
 }
 
 impl From<io::Error> for Error {
     fn from(source: io::Error) -> Self {
         Error {
-            message: format!("{:?}: {}", source.kind(), source.description()),
+            message: format!("{:?}: {}", source.kind(), source),
         }
     }
 }
@@ -48,7 +44,7 @@ impl From<io::Error> for Error {
 impl From<std::string::FromUtf8Error> for Error {
     fn from(source: std::string::FromUtf8Error) -> Self {
         Error {
-            message: format!("{:?}: {}", source.utf8_error(), source.description()),
+            message: format!("{:?}: {}", source.utf8_error(), source),
         }
     }
 }
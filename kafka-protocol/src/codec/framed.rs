@@ -0,0 +1,136 @@
+use bytes::{Buf, BytesMut};
+use serde::Serialize;
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::codec::framing::{check_frame_size, check_write_size};
+use crate::codec::{decode_req_header, encode_req, encode_resp, Error, Result};
+use crate::model::{RequestHeader, ResponseHeader};
+
+pub use crate::codec::framing::DEFAULT_MAX_FRAME_SIZE;
+
+/// Client side codec, to be used along with `Framed<TcpStream, _>`.
+///
+/// Encodes requests and decodes response frames, size excluded. As the
+/// version of a response header and the type of its body depend on the
+/// request it answers, frames are left to be decoded along with the request,
+/// e.g. by `InFlightRequests::decode`.
+#[derive(Debug, Clone)]
+pub struct ClientCodec {
+    max_frame_size: usize,
+}
+
+impl ClientCodec {
+    pub fn new() -> Self {
+        ClientCodec::with_max_frame_size(DEFAULT_MAX_FRAME_SIZE)
+    }
+
+    pub fn with_max_frame_size(max_frame_size: usize) -> Self {
+        ClientCodec { max_frame_size }
+    }
+}
+
+impl Default for ClientCodec {
+    fn default() -> Self {
+        ClientCodec::new()
+    }
+}
+
+impl<T: Serialize> Encoder<(RequestHeader, T)> for ClientCodec {
+    type Error = Error;
+
+    fn encode(&mut self, (header, req): (RequestHeader, T), dst: &mut BytesMut) -> Result<()> {
+        put_frame(encode_req(&header, &req)?, self.max_frame_size, dst)
+    }
+}
+
+impl Decoder for ClientCodec {
+    type Item = BytesMut;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        take_frame(src, self.max_frame_size)
+    }
+}
+
+/// Server side codec, to be used along with `Framed<TcpStream, _>`.
+///
+/// Decodes the header of request frames, which gives the api key and
+/// version to decode the body with, and encodes responses.
+#[derive(Debug, Clone)]
+pub struct ServerCodec {
+    max_frame_size: usize,
+}
+
+impl ServerCodec {
+    pub fn new() -> Self {
+        ServerCodec::with_max_frame_size(DEFAULT_MAX_FRAME_SIZE)
+    }
+
+    pub fn with_max_frame_size(max_frame_size: usize) -> Self {
+        ServerCodec { max_frame_size }
+    }
+}
+
+impl Default for ServerCodec {
+    fn default() -> Self {
+        ServerCodec::new()
+    }
+}
+
+impl<T: Serialize> Encoder<(ResponseHeader, T)> for ServerCodec {
+    type Error = Error;
+
+    fn encode(&mut self, (header, resp): (ResponseHeader, T), dst: &mut BytesMut) -> Result<()> {
+        put_frame(encode_resp(&header, &resp)?, self.max_frame_size, dst)
+    }
+}
+
+impl Decoder for ServerCodec {
+    type Item = (RequestHeader, BytesMut);
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        match take_frame(src, self.max_frame_size)? {
+            Some(frame) => Ok(Some(split_header(frame)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Removes a complete frame, without its size, from the front of `src`.
+fn take_frame(src: &mut BytesMut, max_frame_size: usize) -> Result<Option<BytesMut>> {
+    if src.len() < 4 {
+        return Ok(None);
+    }
+
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&src[..4]);
+    let size = check_frame_size(i32::from_be_bytes(bytes), max_frame_size)?;
+
+    if src.len() < 4 + size {
+        src.reserve(4 + size - src.len());
+        return Ok(None);
+    }
+
+    src.advance(4);
+    Ok(Some(src.split_to(size)))
+}
+
+/// Appends an encoded frame, size included, to `dst`.
+fn put_frame(frame: Vec<u8>, max_frame_size: usize, dst: &mut BytesMut) -> Result<()> {
+    check_write_size(frame.len() - 4, max_frame_size)?;
+    dst.extend_from_slice(&frame);
+    Ok(())
+}
+
+/// Decodes the request header at the start of `frame`, returns it along
+/// with the remaining body.
+fn split_header(mut frame: BytesMut) -> Result<(RequestHeader, BytesMut)> {
+    let (header, remaining) = {
+        let (header, body) = decode_req_header(&frame)?;
+        (header, body.len())
+    };
+
+    let body = frame.split_off(frame.len() - remaining);
+    Ok((header, body))
+}
@@ -0,0 +1,204 @@
+use std::convert::TryFrom;
+use std::io::{Read, Write};
+
+use serde::de::{self, DeserializeOwned};
+use serde::Serialize;
+
+use crate::codec::{decode_resp, decode_response, Result, Serializer};
+use crate::model::{ApiKey, RequestHeader, ResponseHeader};
+use crate::request::Request;
+use crate::types::NullableString;
+
+/// Default maximum frame size, same as the broker `socket.request.max.bytes`.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 100 * 1024 * 1024;
+
+/// Checks the size prefix of a frame against `max_frame_size`.
+pub(crate) fn check_frame_size(size: i32, max_frame_size: usize) -> Result<usize> {
+    if size < 0 {
+        return Err(de::Error::custom(format!("Invalid frame size: {}", size)));
+    }
+
+    let size = size as usize;
+    if size > max_frame_size {
+        return Err(de::Error::custom(format!(
+            "Frame size {} exceeds the configured maximum of {} bytes",
+            size, max_frame_size
+        )));
+    }
+
+    Ok(size)
+}
+
+/// Checks the length of a frame to write against `max_frame_size`, returns
+/// its size prefix.
+pub(crate) fn check_write_size(len: usize, max_frame_size: usize) -> Result<i32> {
+    match i32::try_from(len) {
+        Ok(size) => check_frame_size(size, max_frame_size).map(|_| size),
+        Err(_) => Err(de::Error::custom(format!("Invalid frame size: {}", len))),
+    }
+}
+
+/// Reads length-prefixed Kafka frames from a blocking reader.
+///
+/// The frame buffer is reused from one frame to the next, and only grows
+/// as bytes are actually received.
+pub struct FrameReader<R> {
+    inner: R,
+    max_frame_size: usize,
+    buf: Vec<u8>,
+}
+
+impl<R: Read> FrameReader<R> {
+    pub fn new(inner: R) -> Self {
+        FrameReader::with_max_frame_size(inner, DEFAULT_MAX_FRAME_SIZE)
+    }
+
+    pub fn with_max_frame_size(inner: R, max_frame_size: usize) -> Self {
+        FrameReader {
+            inner,
+            max_frame_size,
+            buf: vec![],
+        }
+    }
+
+    /// Reads the next frame, returns its content, size excluded.
+    pub fn read_frame(&mut self) -> Result<&[u8]> {
+        let mut bytes = [0u8; 4];
+        self.inner.read_exact(&mut bytes)?;
+        let size = check_frame_size(i32::from_be_bytes(bytes), self.max_frame_size)?;
+
+        self.buf.clear();
+        (&mut self.inner)
+            .take(size as u64)
+            .read_to_end(&mut self.buf)?;
+        if self.buf.len() < size {
+            return Err(de::Error::custom(format!(
+                "Unexpected end of frame: got {} of {} bytes",
+                self.buf.len(),
+                size
+            )));
+        }
+
+        Ok(&self.buf)
+    }
+
+    /// Reads the next frame and decodes it as a response of type `T`, to a
+    /// request of `api_key` and `version`.
+    pub fn read_resp<T>(&mut self, api_key: ApiKey, version: usize) -> Result<(ResponseHeader, T)>
+    where
+        T: DeserializeOwned,
+    {
+        decode_resp(self.read_frame()?, api_key, version)
+    }
+
+    /// Reads the next frame and decodes it as a response to `req`.
+    pub fn read_response<Q: Request>(&mut self, req: &Q) -> Result<(ResponseHeader, Q::Response)> {
+        decode_response(self.read_frame()?, req)
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Writes length-prefixed Kafka frames to a blocking writer.
+///
+/// Requests and responses are encoded in a buffer reused from one frame to
+/// the next.
+pub struct FrameWriter<W> {
+    inner: W,
+    max_frame_size: usize,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> FrameWriter<W> {
+    pub fn new(inner: W) -> Self {
+        FrameWriter::with_max_frame_size(inner, DEFAULT_MAX_FRAME_SIZE)
+    }
+
+    pub fn with_max_frame_size(inner: W, max_frame_size: usize) -> Self {
+        FrameWriter {
+            inner,
+            max_frame_size,
+            buf: vec![],
+        }
+    }
+
+    /// Writes `frame`, prefixed with its size.
+    pub fn write_frame(&mut self, frame: &[u8]) -> Result<()> {
+        let size = check_write_size(frame.len(), self.max_frame_size)?;
+        self.inner.write_all(&size.to_be_bytes())?;
+        self.inner.write_all(frame)?;
+        Ok(())
+    }
+
+    /// Encodes and writes a request frame.
+    pub fn write_req<T: Serialize>(&mut self, header: &RequestHeader, req: &T) -> Result<()> {
+        self.write_encoded(|s| {
+            header.serialize(&mut *s)?;
+            req.serialize(s)
+        })
+    }
+
+    /// Encodes and writes a request frame, with a header filled in from the
+    /// type and variant of the request.
+    pub fn write_request<R: Request>(
+        &mut self,
+        req: &R,
+        correlation_id: i32,
+        client_id: NullableString,
+    ) -> Result<()> {
+        self.write_req(&req.header(correlation_id, client_id), req)
+    }
+
+    /// Encodes and writes a response frame.
+    pub fn write_resp<T: Serialize>(&mut self, header: &ResponseHeader, resp: &T) -> Result<()> {
+        self.write_encoded(|s| {
+            header.serialize(&mut *s)?;
+            resp.serialize(s)
+        })
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        Ok(self.inner.flush()?)
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn write_encoded<F>(&mut self, encode: F) -> Result<()>
+    where
+        F: FnOnce(&mut Serializer) -> Result<()>,
+    {
+        let mut buf = std::mem::take(&mut self.buf);
+        buf.clear();
+        buf.extend_from_slice(&[0; 4]);
+
+        let mut serializer = Serializer { buf };
+        let res = encode(&mut serializer);
+        self.buf = serializer.buf;
+        res?;
+
+        let size = check_write_size(self.buf.len() - 4, self.max_frame_size)?;
+        self.buf[..4].copy_from_slice(&size.to_be_bytes());
+        self.inner.write_all(&self.buf)?;
+        Ok(())
+    }
+}
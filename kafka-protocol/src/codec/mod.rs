@@ -1,18 +1,29 @@
-mod compression;
-mod crc32;
+pub mod compression;
+pub mod correlation;
+pub mod crc32;
 
 pub mod de;
+pub mod direct;
 pub mod error;
+#[cfg(feature = "tokio")]
+pub mod framed;
+pub mod framing;
+pub mod record_set;
 pub mod ser;
 
-pub use crate::codec::compression::Compression;
-pub use crate::codec::de::{decode_resp, Deserializer};
+pub use crate::codec::compression::{Compression, DEFAULT_MAX_DECOMPRESSED_SIZE};
+pub use crate::codec::correlation::{InFlightRequest, InFlightRequests};
+pub use crate::codec::de::{decode_req_header, decode_resp, decode_response, Deserializer};
+pub use crate::codec::direct::{Decode, Encode};
 pub use crate::codec::error::{Error, Result};
-pub use crate::codec::ser::{encode_req, Serializer};
+pub use crate::codec::framing::{FrameReader, FrameWriter, DEFAULT_MAX_FRAME_SIZE};
+pub use crate::codec::record_set::{RecordSet, RecordSetEntry};
+pub use crate::codec::ser::{encode_req, encode_request, encode_resp, Serializer};
 
 #[cfg(test)]
 mod tests {
     use serde::{Deserialize, Serialize};
+    use std::fmt::Debug;
     use std::io::Cursor;
 
     use matches::assert_matches;
@@ -42,17 +53,16 @@ mod tests {
         }
     }
 
-    pub fn read_resp<R, T>(rdr: &mut R, version: usize) -> Result<(HeaderResponse, T)>
+    pub fn read_resp<R, T>(
+        rdr: &mut R,
+        api_key: ApiKey,
+        version: usize,
+    ) -> Result<(ResponseHeader, T)>
     where
         R: std::io::Read,
         T: serde::de::DeserializeOwned,
     {
-        let mut buf = [0u8; 4];
-        rdr.read_exact(&mut buf)?;
-        let size = i32::from_be_bytes(buf);
-        let mut bytes = vec![0; size as usize];
-        rdr.read_exact(&mut bytes)?;
-        decode_resp::<T>(&bytes, version)
+        FrameReader::new(rdr).read_resp(api_key, version)
     }
 
     #[test]
@@ -152,14 +162,55 @@ mod tests {
         assert_eq!(b1, b2);
     }
 
+    #[test]
+    fn serde_compact_types() {
+        fn check<T>(val: T, expected: &[u8])
+        where
+            T: Serialize + for<'de> Deserialize<'de> + Encode + Decode + PartialEq + Debug,
+        {
+            assert_eq!(expected, &encode_single(&val).unwrap()[..]);
+            assert_eq!(expected, &direct::encode(&val)[..]);
+            assert_eq!(val, decode_single::<T>(expected, None).unwrap());
+            assert_eq!(val, direct::decode::<T>(expected, 0).unwrap());
+        }
+
+        check(Uuid([7; 16]), &[7; 16]);
+        check(1.5f64, &1.5f64.to_be_bytes());
+        check(UnsignedVarint(300), &[0xac, 0x02]);
+        check(CompactString::from("yes"), &[4, 121, 101, 115]);
+        check(CompactNullableString::from(""), &[1]);
+        check(CompactNullableString(None), &[0]);
+        check(CompactBytes(vec![1, 2]), &[3, 1, 2]);
+        check(CompactNullableBytes::from(vec![1]), &[2, 1]);
+        check(CompactNullableBytes(None), &[0]);
+        check(
+            CompactArray(vec![CompactString::from("a"), CompactString::from("b")]),
+            &[3, 2, 97, 2, 98],
+        );
+        check(CompactArray::<i32>(vec![]), &[1]);
+        check(
+            TaggedFields(vec![(0, vec![1, 2]), (300, vec![])]),
+            &[2, 0, 2, 1, 2, 0xac, 0x02, 0],
+        );
+        check(TaggedFields(vec![]), &[0]);
+
+        // A null compact array is decoded as empty
+        assert_eq!(
+            CompactArray::<i32>(vec![]),
+            decode_single::<CompactArray<i32>>(&[0], None).unwrap()
+        );
+        assert!(decode_single::<CompactString>(&[0], None).is_err());
+        // Other deserializers fail on compact arrays
+        let deserializer = serde::de::value::UnitDeserializer::<serde::de::value::Error>::new();
+        assert!(CompactArray::<i32>::deserialize(deserializer).is_err());
+        assert!(direct::decode::<CompactBytes>(&[0], 0).is_err());
+        assert!(decode_single::<TaggedFields>(&[1, 0, 2, 1], None).is_err());
+        assert!(direct::decode::<TaggedFields>(&[1, 0, 2, 1], 0).is_err());
+    }
+
     #[test]
     fn versions_req_ser() {
-        let header = HeaderRequest {
-            api_key: ApiKey::ApiVersions,
-            api_version: 0,
-            correlation_id: 42,
-            client_id: NullableString(None),
-        };
+        let header = RequestHeader::new(ApiKey::ApiVersions, 0, 42, NullableString(None));
         let bytes = encode_req(&header, &ApiVersionsRequest::V0 {}).unwrap();
         assert_eq!(vec![0, 0, 0, 10, 0, 18, 0, 0, 0, 0, 0, 42, 255, 255], bytes);
     }
@@ -179,8 +230,9 @@ mod tests {
             39, 0, 0, 0, 1, 0, 40, 0, 0, 0, 1, 0, 41, 0, 0, 0, 1, 0, 42, 0, 0, 0, 1,
         ]);
 
-        let (header, resp) = read_resp::<_, ApiVersionsResponse>(&mut bytes, 0).unwrap();
-        assert_eq!(42, header.correlation);
+        let (header, resp) =
+            read_resp::<_, ApiVersionsResponse>(&mut bytes, ApiKey::ApiVersions, 0).unwrap();
+        assert_eq!(42, header.correlation_id());
         assert_matches!(resp, ApiVersionsResponse::V0 {
             error_code,ref  api_versions
         } if error_code == 0 && api_versions.len() == 43);
@@ -220,8 +272,664 @@ mod tests {
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
 
-        let (header, resp) = decode_resp::<FetchResponse>(&mut bytes, 0).unwrap();
+        let (header, resp) = decode_resp::<FetchResponse>(&mut bytes, ApiKey::Fetch, 0).unwrap();
         println!("{:?}", header);
         println!("{:?}", resp);
     }
+
+    fn compressed_batch(compression: Compression, nb_records: usize) -> Vec<u8> {
+        let mut rbb = RecordBatch::builder();
+        rbb.set_compression(compression);
+        for i in 0..nb_records {
+            rbb.add_record(
+                1_572_000_000 + i as i64,
+                RecData::new(vec![42; 1024]).set_key(format!("key-{}", i).into_bytes()),
+            );
+        }
+        encode_single(&rbb.build()).unwrap()
+    }
+
+    #[test]
+    fn record_batch_compression_serde() {
+        let compressions = vec![
+            Compression::None,
+            #[cfg(feature = "gzip")]
+            Compression::Gzip,
+            #[cfg(feature = "snappy")]
+            Compression::Snappy,
+            #[cfg(feature = "lz4")]
+            Compression::Lz4,
+            #[cfg(feature = "zstd")]
+            Compression::Zstd,
+        ];
+
+        for compression in compressions {
+            let bytes = compressed_batch(compression.clone(), 10);
+            let batch = decode_single::<RecordBatch>(&bytes, None).unwrap();
+            assert_eq!(compression, batch.compression());
+            assert_eq!(10, batch.len());
+            for (i, rec) in batch.iter().enumerate() {
+                assert_eq!(i as i32, rec.offset_delta());
+                assert_eq!(Some(format!("key-{}", i).into_bytes()), rec.key);
+                assert_eq!(vec![42; 1024], rec.value);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn record_batch_max_decompressed_size() {
+        let bytes = compressed_batch(Compression::Gzip, 100);
+
+        let mut deserializer = Deserializer::from_bytes(&bytes, 0);
+        deserializer.set_max_decompressed_size(10 * 1024);
+        let res = RecordBatch::deserialize(&mut deserializer);
+        assert_matches!(res, Err(Error { ref message }) if message.contains("maximum"));
+
+        let mut deserializer = Deserializer::from_bytes(&bytes, 0);
+        deserializer.set_max_decompressed_size(200 * 1024);
+        let batch = RecordBatch::deserialize(&mut deserializer).unwrap();
+        assert_eq!(100, batch.len());
+    }
+
+    #[test]
+    #[cfg(feature = "lz4")]
+    fn lz4_legacy_framing() {
+        use crate::codec::compression::lz4::{self, Lz4Framing};
+        use std::io::Read;
+
+        let data = b"kafka lz4 framing".repeat(100);
+
+        let standard = lz4::compress_with(&data, Lz4Framing::for_magic(2)).unwrap();
+        let legacy = lz4::compress_with(&data, Lz4Framing::for_magic(0)).unwrap();
+        assert_eq!(standard.len(), legacy.len());
+        assert_ne!(standard, legacy);
+
+        for (src, framing) in vec![
+            (&standard, Lz4Framing::Standard),
+            (&legacy, Lz4Framing::Legacy),
+            (&standard, Lz4Framing::Legacy),
+        ] {
+            let mut buf = vec![];
+            lz4::decoder_with(src, framing)
+                .unwrap()
+                .read_to_end(&mut buf)
+                .unwrap();
+            assert_eq!(data, buf);
+        }
+
+        let mut buf = vec![];
+        let res = lz4::decoder(&legacy).and_then(|mut dec| dec.read_to_end(&mut buf));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "snappy")]
+    fn snappy_xerial_framing() {
+        use crate::codec::compression::snappy::{self, SnappyFraming};
+        use std::io::Read;
+
+        let data = b"kafka snappy framing".repeat(100);
+
+        let raw = snappy::compress_with(&data, SnappyFraming::for_magic(2)).unwrap();
+        let xerial = snappy::compress_with(&data, SnappyFraming::for_magic(1)).unwrap();
+        let small_blocks =
+            snappy::compress_with(&data, SnappyFraming::Xerial { block_size: 128 }).unwrap();
+        assert!(xerial.starts_with(&[0x82, b'S', b'N', b'A', b'P', b'P', b'Y', 0]));
+        assert!(small_blocks.len() > xerial.len());
+
+        for src in vec![raw, xerial, small_blocks] {
+            let mut buf = vec![];
+            snappy::decoder(&src, DEFAULT_MAX_DECOMPRESSED_SIZE)
+                .unwrap()
+                .read_to_end(&mut buf)
+                .unwrap();
+            assert_eq!(data, buf);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn register_compression_codec() {
+        use crate::codec::compression::{self, CompressionCodec};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct Instrumented {
+            inner: Arc<dyn CompressionCodec>,
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl CompressionCodec for Instrumented {
+            fn compress(&self, src: &[u8]) -> std::io::Result<Vec<u8>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                self.inner.compress(src)
+            }
+
+            fn decoder<'a>(
+                &self,
+                src: &'a [u8],
+                max_size: usize,
+            ) -> std::io::Result<Box<dyn std::io::Read + 'a>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                self.inner.decoder(src, max_size)
+            }
+        }
+
+        // Registers back the built-in codec, even on failure, for other tests
+        struct Restore(Arc<dyn CompressionCodec>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                compression::register_codec(Compression::Gzip, self.0.clone());
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let builtin = compression::codec(&Compression::Gzip).unwrap();
+        let previous = compression::register_codec(
+            Compression::Gzip,
+            Arc::new(Instrumented {
+                inner: builtin.clone(),
+                calls: calls.clone(),
+            }),
+        );
+        let restore = Restore(previous.unwrap());
+
+        let bytes = compressed_batch(Compression::Gzip, 3);
+        let batch = decode_single::<RecordBatch>(&bytes, None).unwrap();
+        assert_eq!(3, batch.len());
+        assert!(calls.load(Ordering::SeqCst) >= 2);
+
+        drop(restore);
+        let restored = compression::codec(&Compression::Gzip).unwrap();
+        assert!(Arc::ptr_eq(&builtin, &restored));
+    }
+
+    #[test]
+    #[cfg(all(feature = "lz4", feature = "lz4-pure"))]
+    fn lz4_backends_interop() {
+        use crate::codec::compression::{lz4::Lz4Codec, lz4_pure::Lz4FlexCodec, CompressionCodec};
+        use std::io::Read;
+
+        let data = b"kafka lz4 backends".repeat(100);
+        let codecs: Vec<Box<dyn CompressionCodec>> =
+            vec![Box::new(Lz4Codec), Box::new(Lz4FlexCodec)];
+        for enc in codecs.iter() {
+            let compressed = enc.compress(&data).unwrap();
+            for dec in codecs.iter() {
+                let mut buf = vec![];
+                dec.decoder(&compressed, DEFAULT_MAX_DECOMPRESSED_SIZE)
+                    .unwrap()
+                    .read_to_end(&mut buf)
+                    .unwrap();
+                assert_eq!(data, buf);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "zstd", feature = "zstd-pure"))]
+    fn zstd_backends_interop() {
+        use crate::codec::compression::{
+            zstd::ZstdCodec, zstd_pure::RuzstdCodec, CompressionCodec,
+        };
+        use std::io::Read;
+
+        let data = b"kafka zstd backends".repeat(100);
+        let codecs: Vec<Box<dyn CompressionCodec>> =
+            vec![Box::new(ZstdCodec), Box::new(RuzstdCodec)];
+        for enc in codecs.iter() {
+            let compressed = enc.compress(&data).unwrap();
+            for dec in codecs.iter() {
+                let mut buf = vec![];
+                dec.decoder(&compressed, DEFAULT_MAX_DECOMPRESSED_SIZE)
+                    .unwrap()
+                    .read_to_end(&mut buf)
+                    .unwrap();
+                assert_eq!(data, buf);
+            }
+        }
+    }
+
+    #[test]
+    fn crc32_checksums() {
+        use crate::codec::crc32::*;
+
+        assert_eq!(0xe3069283, crc32c(b"123456789"));
+        assert_eq!(0x8a9136aa, crc32c(&[0u8; 32]));
+        assert_eq!(0x62a8ab43, crc32c(&[0xffu8; 32]));
+        assert_eq!(0xcbf43926, crc32(b"123456789"));
+        assert_eq!(
+            0x414fa339,
+            crc32(b"The quick brown fox jumps over the lazy dog")
+        );
+
+        let data = (0..=255u8).cycle().take(1031).collect::<Vec<_>>();
+        for i in 0..data.len() {
+            assert_eq!(crc32c_slice8(&data[i..]), crc32c(&data[i..]));
+            assert_eq!(crc32_slice8(&data[i..]), crc32(&data[i..]));
+        }
+    }
+
+    #[test]
+    fn record_batch_validation() {
+        let mut bytes = compressed_batch(Compression::None, 3);
+        assert_eq!(RecordBatch::validate_bytes(&bytes), vec![]);
+        #[cfg(feature = "gzip")]
+        {
+            let gzipped = compressed_batch(Compression::Gzip, 3);
+            assert_eq!(RecordBatch::validate_bytes(&gzipped), vec![]);
+            assert_matches!(
+                RecordBatch::validate_bytes_with_limit(&gzipped, 8)[..],
+                [BatchFinding::Malformed { .. }]
+            );
+        }
+
+        let batch = decode_single::<RecordBatch>(&bytes, None).unwrap();
+        assert_eq!(batch.validate(), vec![]);
+
+        // Compaction may remove the last records of a batch
+        let mut compacted = batch.clone();
+        compacted.last_offset_delta = 5;
+        assert_eq!(compacted.validate(), vec![]);
+
+        let mut tampered = batch.clone();
+        tampered.last_offset_delta = 1;
+        tampered.max_timestamp -= 1;
+        assert_eq!(
+            tampered.validate(),
+            vec![
+                BatchFinding::LastOffsetDeltaMismatch {
+                    last_offset_delta: 1,
+                    actual: 2,
+                },
+                BatchFinding::MaxTimestampMismatch {
+                    max_timestamp: batch.max_timestamp - 1,
+                    actual: batch.max_timestamp,
+                },
+            ]
+        );
+
+        assert_matches!(
+            RecordBatch::validate_bytes(&bytes[..bytes.len() - 1])[..],
+            [BatchFinding::Truncated { .. }]
+        );
+
+        // Declare one record less, checksum is left untouched
+        bytes[60] -= 1;
+        let findings = RecordBatch::validate_bytes(&bytes);
+        assert_matches!(findings[0], BatchFinding::CrcMismatch { .. });
+        assert_eq!(
+            findings[1..],
+            [
+                BatchFinding::RecordsLenMismatch {
+                    records_len: 2,
+                    actual: 3,
+                },
+                BatchFinding::MaxTimestampMismatch {
+                    max_timestamp: batch.max_timestamp,
+                    actual: batch.max_timestamp - 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn record_set_truncated_tail() {
+        let batch = compressed_batch(Compression::None, 3);
+        let mut bytes = batch.clone();
+        bytes.extend_from_slice(&batch);
+
+        let entries = RecordSet::new(&bytes).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_matches!(entries[1], RecordSetEntry::Batch(_));
+
+        let truncated = &bytes[..bytes.len() - 10];
+        let record_set = RecordSet::new(truncated);
+        assert!(!record_set.first_batch_truncated());
+        let entries = record_set.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_matches!(entries[0], RecordSetEntry::Batch(_));
+        assert_eq!(
+            entries[1],
+            RecordSetEntry::Incomplete {
+                available: batch.len() - 10,
+                required: Some(batch.len()),
+            }
+        );
+
+        let record_set = RecordSet::new(&batch[..8]);
+        assert!(record_set.first_batch_truncated());
+        assert_eq!(
+            record_set.collect::<Result<Vec<_>>>().unwrap(),
+            vec![RecordSetEntry::Incomplete {
+                available: 8,
+                required: None,
+            }]
+        );
+
+        assert!(RecordSet::new(&batch[..batch.len() - 1]).first_batch_truncated());
+        assert_eq!(RecordSet::new(&[]).count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "tokio")]
+    fn framed_client_server_codecs() {
+        use crate::codec::framed::{ClientCodec, ServerCodec};
+        use bytes::BytesMut;
+        use tokio_util::codec::{Decoder, Encoder};
+
+        let header = RequestHeader::new(ApiKey::ApiVersions, 0, 42, NullableString::from("me"));
+        let mut buf = BytesMut::new();
+        ClientCodec::new()
+            .encode((header, ApiVersionsRequest::V0 {}), &mut buf)
+            .unwrap();
+
+        let mut server = ServerCodec::new();
+        let mut partial = buf.split_to(6);
+        assert_matches!(server.decode(&mut partial), Ok(None));
+        partial.unsplit(buf);
+        let (header, body) = server.decode(&mut partial).unwrap().unwrap();
+        assert_eq!(header.api_key(), AnyApiKey::Known(ApiKey::ApiVersions));
+        assert_eq!(header.correlation_id(), 42);
+        assert!(body.is_empty());
+
+        let resp = ApiVersionsResponse::V0 {
+            error_code: 0,
+            api_versions: vec![],
+        };
+        let mut buf = BytesMut::new();
+        let header = ResponseHeader::new(ApiKey::ApiVersions, 0, 42);
+        server.encode((header, resp.clone()), &mut buf).unwrap();
+
+        let frame = ClientCodec::new().decode(&mut buf).unwrap().unwrap();
+        let (header, decoded) =
+            decode_resp::<ApiVersionsResponse>(&frame, ApiKey::ApiVersions, 0).unwrap();
+        assert_eq!(header.correlation_id(), 42);
+        assert_eq!(decoded, resp);
+        assert!(buf.is_empty());
+
+        let mut buf = BytesMut::from(&[0u8, 0, 0, 2, 0, 18][..]);
+        assert!(server.decode(&mut buf).is_err()); // Truncated header
+        let mut buf = BytesMut::from(&[0u8, 0, 0, 4, 0x7f, 0xff, 0, 0][..]);
+        assert!(server.decode(&mut buf).is_err()); // Unknown api key
+
+        let mut buf = BytesMut::from(&[0u8, 0, 1, 0][..]);
+        assert!(ClientCodec::with_max_frame_size(255)
+            .decode(&mut buf)
+            .is_err());
+        let mut buf = BytesMut::from(&[0xffu8, 0xff, 0xff, 0xff][..]);
+        assert!(ClientCodec::new().decode(&mut buf).is_err());
+    }
+
+    #[test]
+    fn in_flight_requests() {
+        let mut in_flight = InFlightRequests::new();
+
+        let h0 = in_flight.register(ApiKey::ApiVersions, 1, NullableString(None));
+        let h1 = in_flight.register_oneway(ApiKey::Produce, 3, NullableString(None));
+        let h2 = in_flight.register(ApiKey::ApiVersions, 0, NullableString(None));
+        assert_eq!(
+            (
+                h0.correlation_id(),
+                h1.correlation_id(),
+                h2.correlation_id()
+            ),
+            (0, 1, 2)
+        );
+        assert_eq!(in_flight.len(), 2);
+
+        let resp = ApiVersionsResponse::V0 {
+            error_code: 0,
+            api_versions: vec![],
+        };
+        let header = ResponseHeader::new(ApiKey::ApiVersions, 0, 2);
+        let frame = encode_resp(&header, &resp).unwrap();
+        assert!(in_flight.decode(&frame[4..]).is_err()); // Out of order
+        assert!(in_flight.complete(1).is_err()); // Oneway, unknown
+
+        let resp0 = ApiVersionsResponse::V1 {
+            error_code: 0,
+            api_versions: vec![],
+            throttle_time_ms: 10,
+        };
+        let header0 = ResponseHeader::new(ApiKey::ApiVersions, 1, 0);
+        let frame0 = encode_resp(&header0, &resp0).unwrap();
+        let (req, decoded) = in_flight.decode(&frame0[4..]).unwrap();
+        assert_eq!(req.api_version, 1);
+        assert_eq!(decoded, Response::ApiVersions(resp0));
+
+        let (req, decoded) = in_flight.decode(&frame[4..]).unwrap();
+        assert_eq!(req.correlation_id, 2);
+        assert_eq!(decoded.api_key(), ApiKey::ApiVersions);
+        assert!(in_flight.is_empty());
+    }
+
+    #[test]
+    fn api_versions_metadata() {
+        use crate::versions::ApiVersions;
+
+        assert_eq!(
+            (0, 11),
+            (FetchRequest::FIRST_VERSION, FetchRequest::LAST_VERSION)
+        );
+        assert!(FetchRequest::has_version(11));
+        assert!(FetchRequest::deprecated_versions().is_empty());
+        assert!(!FetchRequest::is_deprecated(0));
+        assert!(FetchRequest::check_version(12).is_err());
+
+        assert_eq!(Some(4), FetchRequest::field_since("isolation_level"));
+        assert!(!FetchRequest::has_field("isolation_level", 3));
+        assert!(FetchRequest::has_field("isolation_level", 4));
+        assert!(FetchRequest::has_field("topics.partitions.fetch_offset", 0));
+        assert!(FetchRequest::check_field("rack_id", 11).is_ok());
+        assert!(FetchRequest::check_field("rack_id", 10).is_err());
+        assert!(FetchRequest::check_field("no_such_field", 11).is_err());
+
+        let req = ApiVersionsRequest::V2 {};
+        assert_eq!(2, req.version());
+    }
+
+    #[test]
+    fn api_key_negotiation() {
+        use crate::versions::ApiVersions;
+
+        fn check(api_key: AnyApiKey, expected: &[u8]) {
+            assert_eq!(expected, &encode_single(&api_key).unwrap()[..]);
+            assert_eq!(expected, &direct::encode(&api_key)[..]);
+            assert_eq!(api_key, decode_single::<AnyApiKey>(expected, None).unwrap());
+            assert_eq!(api_key, direct::decode::<AnyApiKey>(expected, 0).unwrap());
+        }
+
+        check(AnyApiKey::Known(ApiKey::Metadata), &[0, 3]);
+        check(AnyApiKey::Unknown(1000), &[3, 232]);
+        assert_eq!(AnyApiKey::from(ApiKey::Fetch), AnyApiKey::from(1));
+        assert_eq!(None, AnyApiKey::from(-1).known());
+        assert!(direct::decode::<ApiKey>(&[3, 232], 0).is_err());
+
+        let api_versions = |versions: &[(i16, i16, i16)]| {
+            versions
+                .iter()
+                .map(|&(api_key, min_version, max_version)| {
+                    api_versions_response::v0::ApiVersions {
+                        api_key: AnyApiKey::from(api_key),
+                        min_version,
+                        max_version,
+                    }
+                })
+                .collect()
+        };
+
+        // A more recent broker, with an unknown api key
+        let resp = ApiVersionsResponse::V1 {
+            error_code: 0,
+            api_versions: api_versions(&[(1000, 0, 1), (1, 0, 100), (3, 0, 8), (18, 3, 3)]),
+            throttle_time_ms: 0,
+        };
+        let bytes = direct::encode(&resp);
+        let resp = direct::decode::<ApiVersionsResponse>(&bytes, 1).unwrap();
+        assert_eq!(
+            resp,
+            decode_single::<ApiVersionsResponse>(&bytes, Some(1)).unwrap()
+        );
+
+        assert_eq!(AnyApiKey::Unknown(1000), resp.api_key_versions()[0].0);
+        assert_eq!(
+            Some(FetchRequest::LAST_VERSION),
+            resp.negotiate_version::<FetchRequest>()
+        );
+        assert_eq!(
+            Some(MetadataRequest::LAST_VERSION),
+            resp.negotiate_version::<MetadataRequest>()
+        );
+        assert_eq!(None, resp.negotiate_version::<ApiVersionsRequest>());
+        assert_eq!(None, resp.negotiate_version::<ProduceRequest>());
+    }
+
+    #[test]
+    fn blocking_framing() {
+        let header = RequestHeader::new(ApiKey::ApiVersions, 0, 42, NullableString::from("me"));
+        let mut writer = FrameWriter::new(vec![]);
+        writer
+            .write_req(&header, &ApiVersionsRequest::V0 {})
+            .unwrap();
+        writer.write_frame(b"frame").unwrap();
+        let bytes = writer.into_inner();
+        assert_eq!(
+            &bytes[..bytes.len() - 9],
+            &encode_req(&header, &ApiVersionsRequest::V0 {}).unwrap()[..]
+        );
+
+        let mut reader = FrameReader::new(Cursor::new(bytes));
+        assert_eq!(reader.read_frame().unwrap().len(), 12);
+        assert_eq!(reader.read_frame().unwrap(), b"frame");
+        assert!(reader.read_frame().is_err());
+
+        let mut reader = FrameReader::new(Cursor::new(vec![0xff, 0xff, 0xff, 0xfe]));
+        assert!(reader.read_frame().is_err());
+
+        let mut reader = FrameReader::with_max_frame_size(Cursor::new(vec![0, 0, 1, 0]), 255);
+        assert!(reader.read_frame().is_err());
+
+        let mut reader = FrameReader::new(Cursor::new(vec![0, 0, 0, 4, 1, 2]));
+        assert!(reader.read_frame().is_err());
+
+        let mut writer = FrameWriter::with_max_frame_size(vec![], 4);
+        assert!(writer.write_frame(b"frame").is_err());
+        assert!(writer.get_ref().is_empty());
+
+        // Sizes that don't fit the i32 prefix are rejected whatever the maximum
+        let oversized = i32::max_value() as usize + 1;
+        assert!(framing::check_write_size(oversized, usize::max_value()).is_err());
+        assert_eq!(5, framing::check_write_size(5, usize::max_value()).unwrap());
+    }
+
+    #[test]
+    fn typed_requests() {
+        use crate::request::Request;
+
+        let req = ApiVersionsRequest::V1 {};
+        assert_eq!(ApiKey::ApiVersions, ApiVersionsRequest::API_KEY);
+        assert_eq!(1, req.api_version());
+
+        let bytes = encode_request(&req, 7, NullableString::from("me")).unwrap();
+        let (header, body) = decode_req_header(&bytes[4..]).unwrap();
+        assert_eq!(
+            header,
+            RequestHeader::new(ApiKey::ApiVersions, 1, 7, NullableString::from("me"))
+        );
+        assert!(body.is_empty());
+
+        let mut writer = FrameWriter::new(vec![]);
+        writer
+            .write_request(&req, 7, NullableString::from("me"))
+            .unwrap();
+        assert_eq!(bytes, writer.into_inner());
+
+        let resp = ApiVersionsResponse::V1 {
+            error_code: 0,
+            api_versions: vec![],
+            throttle_time_ms: 10,
+        };
+        let header = ResponseHeader::new(ApiKey::ApiVersions, 1, 7);
+        let frame = encode_resp(&header, &resp).unwrap();
+        let (_, decoded) = decode_response(&frame[4..], &req).unwrap();
+        assert_eq!(resp, decoded);
+
+        let mut reader = FrameReader::new(Cursor::new(frame));
+        let (header, decoded) = reader.read_response(&req).unwrap();
+        assert_eq!(7, header.correlation_id());
+        assert_eq!(resp, decoded);
+
+        let mut in_flight = InFlightRequests::new();
+        let header = in_flight.register_request(&req, NullableString(None));
+        assert_eq!(
+            (AnyApiKey::Known(ApiKey::ApiVersions), 1),
+            (header.api_key(), header.api_version())
+        );
+        assert_eq!(1, in_flight.len());
+    }
+
+    #[test]
+    fn direct_codec() {
+        let metadata = MetadataResponse::V1 {
+            brokers: vec![metadata_response::v1::Brokers {
+                node_id: 1,
+                host: "localhost".to_owned(),
+                port: 9092,
+                rack: NullableString(None),
+            }],
+            controller_id: 1,
+            topics: vec![metadata_response::v1::Topics {
+                error_code: 0,
+                name: "topic".to_owned(),
+                is_internal: false,
+                partitions: vec![metadata_response::v1::Partitions {
+                    error_code: 0,
+                    partition_index: 0,
+                    leader_id: 1,
+                    replica_nodes: vec![1, 2],
+                    isr_nodes: vec![1],
+                }],
+            }],
+        };
+        let bytes = direct::encode(&metadata);
+        assert_eq!(encode_single(&metadata).unwrap(), bytes);
+        assert_eq!(
+            metadata,
+            direct::decode::<MetadataResponse>(&bytes, 1).unwrap()
+        );
+
+        let fetch = FetchResponse::V4 {
+            throttle_time_ms: 0,
+            responses: vec![fetch_response::v4::Responses {
+                topic: "topic".to_owned(),
+                partition_responses: vec![fetch_response::v4::PartitionResponses {
+                    partition_header: fetch_response::v4::PartitionHeader {
+                        partition: 0,
+                        error_code: 0,
+                        high_watermark: 42,
+                        last_stable_offset: 42,
+                        aborted_transactions: vec![],
+                    },
+                    record_set: NullableBytes::from(vec![1, 2, 3]),
+                }],
+            }],
+        };
+        let bytes = direct::encode(&fetch);
+        assert_eq!(encode_single(&fetch).unwrap(), bytes);
+        assert_eq!(
+            decode_single::<FetchResponse>(&bytes, Some(4)).unwrap(),
+            direct::decode::<FetchResponse>(&bytes, 4).unwrap()
+        );
+
+        let header = RequestHeader::new(ApiKey::Metadata, 1, 42, NullableString::from("me"));
+        let bytes = direct::encode(&header);
+        assert_eq!(encode_single(&header).unwrap(), bytes);
+        assert_eq!(direct::decode::<RequestHeader>(&bytes, 0).unwrap(), header);
+
+        assert!(direct::decode::<MetadataResponse>(&bytes, 1).is_err());
+        assert!(direct::decode::<MetadataResponse>(&[], 42).is_err());
+    }
 }
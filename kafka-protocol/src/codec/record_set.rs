@@ -0,0 +1,108 @@
+use serde::Deserialize;
+
+use crate::codec::{Deserializer, Result, DEFAULT_MAX_DECOMPRESSED_SIZE};
+use crate::types::RecordBatch;
+
+/// An entry of a record set, as returned by `RecordSet`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RecordSetEntry {
+    /// A complete batch.
+    Batch(RecordBatch),
+    /// The partial batch brokers send at the end of a record set when it
+    /// reaches the fetch size. `required` is the full size of the batch,
+    /// when enough bytes are available to know it.
+    Incomplete {
+        available: usize,
+        required: Option<usize>,
+    },
+}
+
+/// Iterates over the batches of a record set, as found in fetch responses.
+///
+/// Unlike deserializing `RecordBatch` until the input is exhausted, a
+/// truncated trailing batch is reported as `RecordSetEntry::Incomplete`
+/// instead of an error. It is always the last entry.
+pub struct RecordSet<'a> {
+    input: &'a [u8],
+    pos: usize,
+    max_decompressed_size: usize,
+    done: bool,
+}
+
+impl<'a> RecordSet<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        RecordSet {
+            input,
+            pos: 0,
+            max_decompressed_size: DEFAULT_MAX_DECOMPRESSED_SIZE,
+            done: false,
+        }
+    }
+
+    /// Sets the maximum size, in bytes, that the records of a single
+    /// compressed batch are allowed to inflate to.
+    pub fn set_max_decompressed_size(&mut self, max_decompressed_size: usize) {
+        self.max_decompressed_size = max_decompressed_size;
+    }
+
+    /// Returns true when not even the first batch is complete, meaning
+    /// it alone exceeds the fetch size, which must then be raised for
+    /// the consumer to make progress.
+    pub fn first_batch_truncated(&self) -> bool {
+        match required_size(self.input) {
+            Some(size) => size > self.input.len(),
+            None => !self.input.is_empty(),
+        }
+    }
+}
+
+impl<'a> Iterator for RecordSet<'a> {
+    type Item = Result<RecordSetEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = &self.input[self.pos..];
+        if self.done || input.is_empty() {
+            return None;
+        }
+
+        let required = required_size(input);
+        let size = match required {
+            Some(size) if size <= input.len() => size,
+            _ => {
+                self.done = true;
+                return Some(Ok(RecordSetEntry::Incomplete {
+                    available: input.len(),
+                    required,
+                }));
+            }
+        };
+
+        let mut deserializer = Deserializer::from_bytes(&input[..size], 0);
+        deserializer.set_max_decompressed_size(self.max_decompressed_size);
+        match RecordBatch::deserialize(&mut deserializer) {
+            Ok(batch) => {
+                self.pos += size;
+                Some(Ok(RecordSetEntry::Batch(batch)))
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Returns the size of the batch at the start of `input`, as announced by
+/// its `batch_length`, if available.
+fn required_size(input: &[u8]) -> Option<usize> {
+    if input.len() < RecordBatch::HEADING_SIZE {
+        return None;
+    }
+
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&input[8..12]);
+    let batch_length = i32::from_be_bytes(bytes);
+
+    // Invalid lengths are left to the deserializer to report
+    Some(RecordBatch::HEADING_SIZE + batch_length.max(0) as usize)
+}
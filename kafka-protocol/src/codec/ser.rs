@@ -1,15 +1,33 @@
+use std::convert::TryFrom;
 use std::io::prelude::*;
 
 use arrayvec::ArrayVec;
 use serde::ser::{self, Serialize};
 
-use crate::codec::compression::Compression;
+use crate::codec::compression;
 use crate::codec::crc32::crc32c;
 use crate::codec::error::{Error, Result};
-use crate::model::HeaderRequest;
+use crate::model::{RequestHeader, ResponseHeader};
+use crate::request::Request;
 use crate::types::*;
 
-pub fn encode_req<T: Serialize>(header: &HeaderRequest, val: &T) -> Result<Vec<u8>> {
+pub fn encode_req<T: Serialize>(header: &RequestHeader, val: &T) -> Result<Vec<u8>> {
+    let mut serializer = Serializer::new_sized();
+    header.serialize(&mut serializer)?;
+    val.serialize(&mut serializer)?;
+    Ok(serializer.bytes_sized())
+}
+
+/// Encodes a request with a header filled in from its type and variant.
+pub fn encode_request<R: Request>(
+    req: &R,
+    correlation_id: i32,
+    client_id: NullableString,
+) -> Result<Vec<u8>> {
+    encode_req(&req.header(correlation_id, client_id), req)
+}
+
+pub fn encode_resp<T: Serialize>(header: &ResponseHeader, val: &T) -> Result<Vec<u8>> {
     let mut serializer = Serializer::new_sized();
     header.serialize(&mut serializer)?;
     val.serialize(&mut serializer)?;
@@ -40,6 +58,39 @@ impl Serializer {
     }
 }
 
+/// Hooks of the Kafka serializer, for values serde has no model of. Other
+/// serializers fail on them.
+trait SerializerExt: ser::Serializer {
+    /// Serializes a compact array, prefixed by its length N + 1 as an
+    /// unsigned varint.
+    fn serialize_compact_array<T: Serialize>(
+        self,
+        vals: &[T],
+    ) -> std::result::Result<Self::Ok, Self::Error>;
+}
+
+impl<S: ser::Serializer> SerializerExt for S {
+    default fn serialize_compact_array<T: Serialize>(
+        self,
+        _vals: &[T],
+    ) -> std::result::Result<Self::Ok, Self::Error> {
+        Err(ser::Error::custom(
+            "compact arrays require the kafka serializer",
+        ))
+    }
+}
+
+impl SerializerExt for &mut Serializer {
+    fn serialize_compact_array<T: Serialize>(self, vals: &[T]) -> Result<()> {
+        let len = compact_len(Some(vals.len())).map_err::<Error, _>(ser::Error::custom)?;
+        var_u32(len, &mut self.buf)?;
+        for val in vals {
+            val.serialize(&mut *self)?;
+        }
+        Ok(())
+    }
+}
+
 impl<'a> ser::Serializer for &'a mut Serializer {
     type Ok = ();
     type Error = Error;
@@ -98,8 +149,9 @@ impl<'a> ser::Serializer for &'a mut Serializer {
         unimplemented!()
     }
 
-    fn serialize_f64(self, _val: f64) -> Result<()> {
-        unimplemented!()
+    fn serialize_f64(self, val: f64) -> Result<()> {
+        self.buf.write_all(&val.to_be_bytes())?;
+        Ok(())
     }
 
     fn serialize_char(self, _val: char) -> Result<()> {
@@ -429,6 +481,135 @@ impl Serialize for Varlong {
     }
 }
 
+impl Serialize for Uuid {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl Serialize for UnsignedVarint {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let mut buf = ArrayVec::<[u8; UnsignedVarint::MAX_SIZE]>::new();
+        var_u32(self.0, &mut buf).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&buf)
+    }
+}
+
+impl Serialize for CompactString {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let buf = compact_bytes(Some(self.0.as_bytes())).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&buf)
+    }
+}
+
+impl Serialize for CompactNullableString {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let buf =
+            compact_bytes(self.0.as_ref().map(String::as_bytes)).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&buf)
+    }
+}
+
+impl Serialize for CompactBytes {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let buf = compact_bytes(Some(&self.0)).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&buf)
+    }
+}
+
+impl Serialize for CompactNullableBytes {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let buf = compact_bytes(self.0.as_deref()).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&buf)
+    }
+}
+
+impl<T: Serialize> Serialize for CompactArray<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_compact_array(&self.0)
+    }
+}
+
+impl Serialize for TaggedFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let buf = tagged_fields(&self.0).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&buf)
+    }
+}
+
+impl Serialize for AnyApiKey {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_i16((*self).into())
+    }
+}
+
+/// Returns the length N + 1 of a compact value of length N, 0 for null.
+pub(crate) fn compact_len(len: Option<usize>) -> std::result::Result<u32, String> {
+    match len {
+        None => Ok(0),
+        Some(len) if len < std::u32::MAX as usize => Ok(len as u32 + 1),
+        Some(len) => Err(format!("compact value is too long: {}", len)),
+    }
+}
+
+/// Returns a compact string or byte buf, prefixed by its compact length.
+fn compact_bytes(val: Option<&[u8]>) -> std::result::Result<Vec<u8>, String> {
+    let len = compact_len(val.map(<[u8]>::len))?;
+    let val = val.unwrap_or_default();
+
+    let mut buf = Vec::with_capacity(UnsignedVarint::size_of(len) + val.len());
+    var_u32(len, &mut buf).map_err(|e| e.to_string())?;
+    buf.extend_from_slice(val);
+    Ok(buf)
+}
+
+/// Returns tagged fields, prefixed by their count.
+fn tagged_fields(fields: &[(u32, Vec<u8>)]) -> std::result::Result<Vec<u8>, String> {
+    let mut buf = vec![];
+    let count = u32::try_from(fields.len())
+        .map_err(|_| format!("too many tagged fields: {}", fields.len()))?;
+    var_u32(count, &mut buf).map_err(|e| e.to_string())?;
+    for (tag, data) in fields {
+        let size = u32::try_from(data.len())
+            .map_err(|_| format!("tagged field is too long: {}", data.len()))?;
+        var_u32(*tag, &mut buf).map_err(|e| e.to_string())?;
+        var_u32(size, &mut buf).map_err(|e| e.to_string())?;
+        buf.extend_from_slice(data);
+    }
+    Ok(buf)
+}
+
+pub(crate) fn var_u32(n: u32, buf: impl Write) -> std::io::Result<usize> {
+    encode_variable(n as u64, buf)
+}
+
 pub(crate) fn zig_i32(n: i32, buf: impl Write) -> std::io::Result<usize> {
     zig_i64(n as i64, buf)
 }
@@ -510,41 +691,8 @@ impl Serialize for RecordBatch {
             record.serialize(&mut s).map_err(Error::custom)?;
         }
 
-        let mut records_bytes = s.buf;
-        match self.compression() {
-            Compression::None => (),
-
-            #[cfg(feature = "gzip")]
-            Compression::Gzip => {
-                use crate::codec::compression::gzip;
-                records_bytes = gzip::compress(&records_bytes).map_err(Error::custom)?;
-            }
-
-            #[cfg(feature = "snappy")]
-            Compression::Snappy => {
-                use crate::codec::compression::snappy;
-                records_bytes = snappy::compress(&records_bytes).map_err(Error::custom)?;
-            }
-
-            #[cfg(feature = "lz4")]
-            Compression::Lz4 => {
-                use crate::codec::compression::lz4;
-                records_bytes = lz4::compress(&records_bytes).map_err(Error::custom)?;
-            }
-
-            #[cfg(feature = "zstd")]
-            Compression::Zstd => {
-                use crate::codec::compression::zstd;
-                records_bytes = zstd::compress(&records_bytes).map_err(Error::custom)?;
-            }
-
-            _ => {
-                return Err(Error::custom(format!(
-                    "Unsupported compression format: {:?}",
-                    self.compression()
-                )));
-            }
-        }
+        let records_bytes =
+            compression::compress(&self.compression(), s.buf).map_err(Error::custom)?;
 
         let batch_length = RecordBatch::INNER_SIZE + records_bytes.len();
         let mut s = Serializer {
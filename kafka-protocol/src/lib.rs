@@ -2,4 +2,6 @@
 
 pub mod codec;
 pub mod model;
+pub mod request;
 pub mod types;
+pub mod versions;
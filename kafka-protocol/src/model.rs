@@ -1,16 +1,123 @@
 //! Generated from: https://kafka.apache.org/23/protocol.html
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-pub struct HeaderRequest {
-    pub api_key: crate::model::ApiKey,
-    pub api_version: i16,
-    pub correlation_id: i32,
-    pub client_id: crate::types::NullableString,
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
+pub enum RequestHeader {
+    V0 {
+        /// The id of the request type.
+        api_key: crate::types::AnyApiKey,
+        /// The version of the API.
+        api_version: i16,
+        /// A user-supplied integer value that will be passed back with the
+        /// response
+        correlation_id: i32,
+        /// A user specified identifier for the client making the request.
+        client_id: crate::types::NullableString,
+    },
+}
+
+impl crate::versions::ApiVersions for RequestHeader {
+    const NAME: &'static str = "RequestHeader";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 0;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("api_key", 0..=0),
+        ("api_version", 0..=0),
+        ("correlation_id", 0..=0),
+        ("client_id", 0..=0),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            RequestHeader::V0 { .. } => 0,
+        }
+    }
+}
+
+impl crate::codec::Encode for RequestHeader {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            RequestHeader::V0 {
+                api_key,
+                api_version,
+                correlation_id,
+                client_id,
+            } => {
+                crate::codec::Encode::encode(api_key, buf);
+                crate::codec::Encode::encode(api_version, buf);
+                crate::codec::Encode::encode(correlation_id, buf);
+                crate::codec::Encode::encode(client_id, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for RequestHeader {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(RequestHeader::V0 {
+                api_key: crate::codec::Decode::decode(buf, version)?,
+                api_version: crate::codec::Decode::decode(buf, version)?,
+                correlation_id: crate::codec::Decode::decode(buf, version)?,
+                client_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "RequestHeader", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
+pub enum ResponseHeader {
+    V0 {
+        /// The user-supplied value passed in with the request
+        correlation_id: i32,
+    },
+}
+
+impl crate::versions::ApiVersions for ResponseHeader {
+    const NAME: &'static str = "ResponseHeader";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 0;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("correlation_id", 0..=0)];
+
+    fn version(&self) -> i16 {
+        match self {
+            ResponseHeader::V0 { .. } => 0,
+        }
+    }
+}
+
+impl crate::codec::Encode for ResponseHeader {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ResponseHeader::V0 { correlation_id } => {
+                crate::codec::Encode::encode(correlation_id, buf);
+            }
+        }
+    }
 }
 
-#[derive(Debug, serde::Deserialize)]
-pub struct HeaderResponse {
-    pub correlation: i32,
+impl crate::codec::Decode for ResponseHeader {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ResponseHeader::V0 {
+                correlation_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ResponseHeader", version
+            ))),
+        }
+    }
 }
 
 ///  Numeric codes to indicate what problem occurred on the Kafka server.
@@ -314,7 +421,413 @@ pub enum ApiKey {
     IncrementalAlterConfigs = 44,
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl ApiKey {
+    /// Returns the version of the header requests of `api_version` are sent with.
+    pub fn request_header_version(self, _api_version: i16) -> i16 {
+        match self {
+            ApiKey::Produce => 0,
+            ApiKey::Fetch => 0,
+            ApiKey::ListOffsets => 0,
+            ApiKey::Metadata => 0,
+            ApiKey::LeaderAndIsr => 0,
+            ApiKey::StopReplica => 0,
+            ApiKey::UpdateMetadata => 0,
+            ApiKey::ControlledShutdown => 0,
+            ApiKey::OffsetCommit => 0,
+            ApiKey::OffsetFetch => 0,
+            ApiKey::FindCoordinator => 0,
+            ApiKey::JoinGroup => 0,
+            ApiKey::Heartbeat => 0,
+            ApiKey::LeaveGroup => 0,
+            ApiKey::SyncGroup => 0,
+            ApiKey::DescribeGroups => 0,
+            ApiKey::ListGroups => 0,
+            ApiKey::SaslHandshake => 0,
+            ApiKey::ApiVersions => 0,
+            ApiKey::CreateTopics => 0,
+            ApiKey::DeleteTopics => 0,
+            ApiKey::DeleteRecords => 0,
+            ApiKey::InitProducerId => 0,
+            ApiKey::OffsetForLeaderEpoch => 0,
+            ApiKey::AddPartitionsToTxn => 0,
+            ApiKey::AddOffsetsToTxn => 0,
+            ApiKey::EndTxn => 0,
+            ApiKey::WriteTxnMarkers => 0,
+            ApiKey::TxnOffsetCommit => 0,
+            ApiKey::DescribeAcls => 0,
+            ApiKey::CreateAcls => 0,
+            ApiKey::DeleteAcls => 0,
+            ApiKey::DescribeConfigs => 0,
+            ApiKey::AlterConfigs => 0,
+            ApiKey::AlterReplicaLogDirs => 0,
+            ApiKey::DescribeLogDirs => 0,
+            ApiKey::SaslAuthenticate => 0,
+            ApiKey::CreatePartitions => 0,
+            ApiKey::CreateDelegationToken => 0,
+            ApiKey::RenewDelegationToken => 0,
+            ApiKey::ExpireDelegationToken => 0,
+            ApiKey::DescribeDelegationToken => 0,
+            ApiKey::DeleteGroups => 0,
+            ApiKey::ElectPreferredLeaders => 0,
+            ApiKey::IncrementalAlterConfigs => 0,
+        }
+    }
+
+    /// Returns the version of the header responses to requests of `api_version`
+    /// are sent with.
+    pub fn response_header_version(self, _api_version: i16) -> i16 {
+        match self {
+            ApiKey::Produce => 0,
+            ApiKey::Fetch => 0,
+            ApiKey::ListOffsets => 0,
+            ApiKey::Metadata => 0,
+            ApiKey::LeaderAndIsr => 0,
+            ApiKey::StopReplica => 0,
+            ApiKey::UpdateMetadata => 0,
+            ApiKey::ControlledShutdown => 0,
+            ApiKey::OffsetCommit => 0,
+            ApiKey::OffsetFetch => 0,
+            ApiKey::FindCoordinator => 0,
+            ApiKey::JoinGroup => 0,
+            ApiKey::Heartbeat => 0,
+            ApiKey::LeaveGroup => 0,
+            ApiKey::SyncGroup => 0,
+            ApiKey::DescribeGroups => 0,
+            ApiKey::ListGroups => 0,
+            ApiKey::SaslHandshake => 0,
+            ApiKey::ApiVersions => 0,
+            ApiKey::CreateTopics => 0,
+            ApiKey::DeleteTopics => 0,
+            ApiKey::DeleteRecords => 0,
+            ApiKey::InitProducerId => 0,
+            ApiKey::OffsetForLeaderEpoch => 0,
+            ApiKey::AddPartitionsToTxn => 0,
+            ApiKey::AddOffsetsToTxn => 0,
+            ApiKey::EndTxn => 0,
+            ApiKey::WriteTxnMarkers => 0,
+            ApiKey::TxnOffsetCommit => 0,
+            ApiKey::DescribeAcls => 0,
+            ApiKey::CreateAcls => 0,
+            ApiKey::DeleteAcls => 0,
+            ApiKey::DescribeConfigs => 0,
+            ApiKey::AlterConfigs => 0,
+            ApiKey::AlterReplicaLogDirs => 0,
+            ApiKey::DescribeLogDirs => 0,
+            ApiKey::SaslAuthenticate => 0,
+            ApiKey::CreatePartitions => 0,
+            ApiKey::CreateDelegationToken => 0,
+            ApiKey::RenewDelegationToken => 0,
+            ApiKey::ExpireDelegationToken => 0,
+            ApiKey::DescribeDelegationToken => 0,
+            ApiKey::DeleteGroups => 0,
+            ApiKey::ElectPreferredLeaders => 0,
+            ApiKey::IncrementalAlterConfigs => 0,
+        }
+    }
+}
+
+impl RequestHeader {
+    /// Returns the header to send a request of `api_key` and
+    /// `api_version` with, of the version `ApiKey::request_header_version`
+    /// gives.
+    pub fn new(
+        api_key: ApiKey,
+        api_version: i16,
+        correlation_id: i32,
+        client_id: crate::types::NullableString,
+    ) -> RequestHeader {
+        debug_assert_eq!(api_key.request_header_version(api_version), 0);
+        RequestHeader::V0 {
+            api_key: api_key.into(),
+            api_version,
+            correlation_id,
+            client_id,
+        }
+    }
+
+    /// The id of the request type.
+    pub fn api_key(&self) -> crate::types::AnyApiKey {
+        match self {
+            RequestHeader::V0 { api_key, .. } => *api_key,
+        }
+    }
+
+    /// The version of the API.
+    pub fn api_version(&self) -> i16 {
+        match self {
+            RequestHeader::V0 { api_version, .. } => *api_version,
+        }
+    }
+
+    /// A user-supplied integer value that will be passed back with the
+    /// response
+    pub fn correlation_id(&self) -> i32 {
+        match self {
+            RequestHeader::V0 { correlation_id, .. } => *correlation_id,
+        }
+    }
+}
+
+impl ResponseHeader {
+    /// Returns the header to send the response to a request of `api_key` and
+    /// `api_version` with, of the version `ApiKey::response_header_version`
+    /// gives.
+    pub fn new(api_key: ApiKey, api_version: i16, correlation_id: i32) -> ResponseHeader {
+        debug_assert_eq!(api_key.response_header_version(api_version), 0);
+        ResponseHeader::V0 { correlation_id }
+    }
+
+    /// The user-supplied value passed in with the request
+    pub fn correlation_id(&self) -> i32 {
+        match self {
+            ResponseHeader::V0 { correlation_id, .. } => *correlation_id,
+        }
+    }
+}
+
+/// A response of any type, as identified by the `ApiKey` of its request.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub enum Response {
+    Produce(ProduceResponse),
+    Fetch(FetchResponse),
+    ListOffsets(ListOffsetsResponse),
+    Metadata(MetadataResponse),
+    LeaderAndIsr(LeaderAndIsrResponse),
+    StopReplica(StopReplicaResponse),
+    UpdateMetadata(UpdateMetadataResponse),
+    ControlledShutdown(ControlledShutdownResponse),
+    OffsetCommit(OffsetCommitResponse),
+    OffsetFetch(OffsetFetchResponse),
+    FindCoordinator(FindCoordinatorResponse),
+    JoinGroup(JoinGroupResponse),
+    Heartbeat(HeartbeatResponse),
+    LeaveGroup(LeaveGroupResponse),
+    SyncGroup(SyncGroupResponse),
+    DescribeGroups(DescribeGroupsResponse),
+    ListGroups(ListGroupsResponse),
+    SaslHandshake(SaslHandshakeResponse),
+    ApiVersions(ApiVersionsResponse),
+    CreateTopics(CreateTopicsResponse),
+    DeleteTopics(DeleteTopicsResponse),
+    DeleteRecords(DeleteRecordsResponse),
+    InitProducerId(InitProducerIdResponse),
+    OffsetForLeaderEpoch(OffsetForLeaderEpochResponse),
+    AddPartitionsToTxn(AddPartitionsToTxnResponse),
+    AddOffsetsToTxn(AddOffsetsToTxnResponse),
+    EndTxn(EndTxnResponse),
+    WriteTxnMarkers(WriteTxnMarkersResponse),
+    TxnOffsetCommit(TxnOffsetCommitResponse),
+    DescribeAcls(DescribeAclsResponse),
+    CreateAcls(CreateAclsResponse),
+    DeleteAcls(DeleteAclsResponse),
+    DescribeConfigs(DescribeConfigsResponse),
+    AlterConfigs(AlterConfigsResponse),
+    AlterReplicaLogDirs(AlterReplicaLogDirsResponse),
+    DescribeLogDirs(DescribeLogDirsResponse),
+    SaslAuthenticate(SaslAuthenticateResponse),
+    CreatePartitions(CreatePartitionsResponse),
+    CreateDelegationToken(CreateDelegationTokenResponse),
+    RenewDelegationToken(RenewDelegationTokenResponse),
+    ExpireDelegationToken(ExpireDelegationTokenResponse),
+    DescribeDelegationToken(DescribeDelegationTokenResponse),
+    DeleteGroups(DeleteGroupsResponse),
+    ElectPreferredLeaders(ElectPreferredLeadersResponse),
+    IncrementalAlterConfigs(IncrementalAlterConfigsResponse),
+}
+
+impl Response {
+    /// Deserializes the response to a request of type `api_key`.
+    pub fn deserialize_for<'de, D>(api_key: ApiKey, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+
+        match api_key {
+            ApiKey::Produce => ProduceResponse::deserialize(deserializer).map(Response::Produce),
+            ApiKey::Fetch => FetchResponse::deserialize(deserializer).map(Response::Fetch),
+            ApiKey::ListOffsets => {
+                ListOffsetsResponse::deserialize(deserializer).map(Response::ListOffsets)
+            }
+            ApiKey::Metadata => MetadataResponse::deserialize(deserializer).map(Response::Metadata),
+            ApiKey::LeaderAndIsr => {
+                LeaderAndIsrResponse::deserialize(deserializer).map(Response::LeaderAndIsr)
+            }
+            ApiKey::StopReplica => {
+                StopReplicaResponse::deserialize(deserializer).map(Response::StopReplica)
+            }
+            ApiKey::UpdateMetadata => {
+                UpdateMetadataResponse::deserialize(deserializer).map(Response::UpdateMetadata)
+            }
+            ApiKey::ControlledShutdown => ControlledShutdownResponse::deserialize(deserializer)
+                .map(Response::ControlledShutdown),
+            ApiKey::OffsetCommit => {
+                OffsetCommitResponse::deserialize(deserializer).map(Response::OffsetCommit)
+            }
+            ApiKey::OffsetFetch => {
+                OffsetFetchResponse::deserialize(deserializer).map(Response::OffsetFetch)
+            }
+            ApiKey::FindCoordinator => {
+                FindCoordinatorResponse::deserialize(deserializer).map(Response::FindCoordinator)
+            }
+            ApiKey::JoinGroup => {
+                JoinGroupResponse::deserialize(deserializer).map(Response::JoinGroup)
+            }
+            ApiKey::Heartbeat => {
+                HeartbeatResponse::deserialize(deserializer).map(Response::Heartbeat)
+            }
+            ApiKey::LeaveGroup => {
+                LeaveGroupResponse::deserialize(deserializer).map(Response::LeaveGroup)
+            }
+            ApiKey::SyncGroup => {
+                SyncGroupResponse::deserialize(deserializer).map(Response::SyncGroup)
+            }
+            ApiKey::DescribeGroups => {
+                DescribeGroupsResponse::deserialize(deserializer).map(Response::DescribeGroups)
+            }
+            ApiKey::ListGroups => {
+                ListGroupsResponse::deserialize(deserializer).map(Response::ListGroups)
+            }
+            ApiKey::SaslHandshake => {
+                SaslHandshakeResponse::deserialize(deserializer).map(Response::SaslHandshake)
+            }
+            ApiKey::ApiVersions => {
+                ApiVersionsResponse::deserialize(deserializer).map(Response::ApiVersions)
+            }
+            ApiKey::CreateTopics => {
+                CreateTopicsResponse::deserialize(deserializer).map(Response::CreateTopics)
+            }
+            ApiKey::DeleteTopics => {
+                DeleteTopicsResponse::deserialize(deserializer).map(Response::DeleteTopics)
+            }
+            ApiKey::DeleteRecords => {
+                DeleteRecordsResponse::deserialize(deserializer).map(Response::DeleteRecords)
+            }
+            ApiKey::InitProducerId => {
+                InitProducerIdResponse::deserialize(deserializer).map(Response::InitProducerId)
+            }
+            ApiKey::OffsetForLeaderEpoch => OffsetForLeaderEpochResponse::deserialize(deserializer)
+                .map(Response::OffsetForLeaderEpoch),
+            ApiKey::AddPartitionsToTxn => AddPartitionsToTxnResponse::deserialize(deserializer)
+                .map(Response::AddPartitionsToTxn),
+            ApiKey::AddOffsetsToTxn => {
+                AddOffsetsToTxnResponse::deserialize(deserializer).map(Response::AddOffsetsToTxn)
+            }
+            ApiKey::EndTxn => EndTxnResponse::deserialize(deserializer).map(Response::EndTxn),
+            ApiKey::WriteTxnMarkers => {
+                WriteTxnMarkersResponse::deserialize(deserializer).map(Response::WriteTxnMarkers)
+            }
+            ApiKey::TxnOffsetCommit => {
+                TxnOffsetCommitResponse::deserialize(deserializer).map(Response::TxnOffsetCommit)
+            }
+            ApiKey::DescribeAcls => {
+                DescribeAclsResponse::deserialize(deserializer).map(Response::DescribeAcls)
+            }
+            ApiKey::CreateAcls => {
+                CreateAclsResponse::deserialize(deserializer).map(Response::CreateAcls)
+            }
+            ApiKey::DeleteAcls => {
+                DeleteAclsResponse::deserialize(deserializer).map(Response::DeleteAcls)
+            }
+            ApiKey::DescribeConfigs => {
+                DescribeConfigsResponse::deserialize(deserializer).map(Response::DescribeConfigs)
+            }
+            ApiKey::AlterConfigs => {
+                AlterConfigsResponse::deserialize(deserializer).map(Response::AlterConfigs)
+            }
+            ApiKey::AlterReplicaLogDirs => AlterReplicaLogDirsResponse::deserialize(deserializer)
+                .map(Response::AlterReplicaLogDirs),
+            ApiKey::DescribeLogDirs => {
+                DescribeLogDirsResponse::deserialize(deserializer).map(Response::DescribeLogDirs)
+            }
+            ApiKey::SaslAuthenticate => {
+                SaslAuthenticateResponse::deserialize(deserializer).map(Response::SaslAuthenticate)
+            }
+            ApiKey::CreatePartitions => {
+                CreatePartitionsResponse::deserialize(deserializer).map(Response::CreatePartitions)
+            }
+            ApiKey::CreateDelegationToken => {
+                CreateDelegationTokenResponse::deserialize(deserializer)
+                    .map(Response::CreateDelegationToken)
+            }
+            ApiKey::RenewDelegationToken => RenewDelegationTokenResponse::deserialize(deserializer)
+                .map(Response::RenewDelegationToken),
+            ApiKey::ExpireDelegationToken => {
+                ExpireDelegationTokenResponse::deserialize(deserializer)
+                    .map(Response::ExpireDelegationToken)
+            }
+            ApiKey::DescribeDelegationToken => {
+                DescribeDelegationTokenResponse::deserialize(deserializer)
+                    .map(Response::DescribeDelegationToken)
+            }
+            ApiKey::DeleteGroups => {
+                DeleteGroupsResponse::deserialize(deserializer).map(Response::DeleteGroups)
+            }
+            ApiKey::ElectPreferredLeaders => {
+                ElectPreferredLeadersResponse::deserialize(deserializer)
+                    .map(Response::ElectPreferredLeaders)
+            }
+            ApiKey::IncrementalAlterConfigs => {
+                IncrementalAlterConfigsResponse::deserialize(deserializer)
+                    .map(Response::IncrementalAlterConfigs)
+            }
+        }
+    }
+
+    pub fn api_key(&self) -> ApiKey {
+        match self {
+            Response::Produce(_) => ApiKey::Produce,
+            Response::Fetch(_) => ApiKey::Fetch,
+            Response::ListOffsets(_) => ApiKey::ListOffsets,
+            Response::Metadata(_) => ApiKey::Metadata,
+            Response::LeaderAndIsr(_) => ApiKey::LeaderAndIsr,
+            Response::StopReplica(_) => ApiKey::StopReplica,
+            Response::UpdateMetadata(_) => ApiKey::UpdateMetadata,
+            Response::ControlledShutdown(_) => ApiKey::ControlledShutdown,
+            Response::OffsetCommit(_) => ApiKey::OffsetCommit,
+            Response::OffsetFetch(_) => ApiKey::OffsetFetch,
+            Response::FindCoordinator(_) => ApiKey::FindCoordinator,
+            Response::JoinGroup(_) => ApiKey::JoinGroup,
+            Response::Heartbeat(_) => ApiKey::Heartbeat,
+            Response::LeaveGroup(_) => ApiKey::LeaveGroup,
+            Response::SyncGroup(_) => ApiKey::SyncGroup,
+            Response::DescribeGroups(_) => ApiKey::DescribeGroups,
+            Response::ListGroups(_) => ApiKey::ListGroups,
+            Response::SaslHandshake(_) => ApiKey::SaslHandshake,
+            Response::ApiVersions(_) => ApiKey::ApiVersions,
+            Response::CreateTopics(_) => ApiKey::CreateTopics,
+            Response::DeleteTopics(_) => ApiKey::DeleteTopics,
+            Response::DeleteRecords(_) => ApiKey::DeleteRecords,
+            Response::InitProducerId(_) => ApiKey::InitProducerId,
+            Response::OffsetForLeaderEpoch(_) => ApiKey::OffsetForLeaderEpoch,
+            Response::AddPartitionsToTxn(_) => ApiKey::AddPartitionsToTxn,
+            Response::AddOffsetsToTxn(_) => ApiKey::AddOffsetsToTxn,
+            Response::EndTxn(_) => ApiKey::EndTxn,
+            Response::WriteTxnMarkers(_) => ApiKey::WriteTxnMarkers,
+            Response::TxnOffsetCommit(_) => ApiKey::TxnOffsetCommit,
+            Response::DescribeAcls(_) => ApiKey::DescribeAcls,
+            Response::CreateAcls(_) => ApiKey::CreateAcls,
+            Response::DeleteAcls(_) => ApiKey::DeleteAcls,
+            Response::DescribeConfigs(_) => ApiKey::DescribeConfigs,
+            Response::AlterConfigs(_) => ApiKey::AlterConfigs,
+            Response::AlterReplicaLogDirs(_) => ApiKey::AlterReplicaLogDirs,
+            Response::DescribeLogDirs(_) => ApiKey::DescribeLogDirs,
+            Response::SaslAuthenticate(_) => ApiKey::SaslAuthenticate,
+            Response::CreatePartitions(_) => ApiKey::CreatePartitions,
+            Response::CreateDelegationToken(_) => ApiKey::CreateDelegationToken,
+            Response::RenewDelegationToken(_) => ApiKey::RenewDelegationToken,
+            Response::ExpireDelegationToken(_) => ApiKey::ExpireDelegationToken,
+            Response::DescribeDelegationToken(_) => ApiKey::DescribeDelegationToken,
+            Response::DeleteGroups(_) => ApiKey::DeleteGroups,
+            Response::ElectPreferredLeaders(_) => ApiKey::ElectPreferredLeaders,
+            Response::IncrementalAlterConfigs(_) => ApiKey::IncrementalAlterConfigs,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ProduceRequest {
     V0 {
         /// The number of acknowledgments the producer requires the leader to have
@@ -408,6 +921,186 @@ pub enum ProduceRequest {
     },
 }
 
+impl crate::versions::ApiVersions for ProduceRequest {
+    const NAME: &'static str = "ProduceRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 7;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("acks", 0..=7),
+        ("timeout", 0..=7),
+        ("topic_data", 0..=7),
+        ("topic_data.topic", 0..=7),
+        ("topic_data.data", 0..=7),
+        ("topic_data.data.partition", 0..=7),
+        ("topic_data.data.record_set", 0..=7),
+        ("transactional_id", 3..=7),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ProduceRequest::V0 { .. } => 0,
+            ProduceRequest::V1 { .. } => 1,
+            ProduceRequest::V2 { .. } => 2,
+            ProduceRequest::V3 { .. } => 3,
+            ProduceRequest::V4 { .. } => 4,
+            ProduceRequest::V5 { .. } => 5,
+            ProduceRequest::V6 { .. } => 6,
+            ProduceRequest::V7 { .. } => 7,
+        }
+    }
+}
+
+impl crate::request::Request for ProduceRequest {
+    const API_KEY: ApiKey = ApiKey::Produce;
+    type Response = ProduceResponse;
+}
+
+impl crate::codec::Encode for ProduceRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ProduceRequest::V0 {
+                acks,
+                timeout,
+                topic_data,
+            } => {
+                crate::codec::Encode::encode(acks, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(topic_data, buf);
+            }
+            ProduceRequest::V1 {
+                acks,
+                timeout,
+                topic_data,
+            } => {
+                crate::codec::Encode::encode(acks, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(topic_data, buf);
+            }
+            ProduceRequest::V2 {
+                acks,
+                timeout,
+                topic_data,
+            } => {
+                crate::codec::Encode::encode(acks, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(topic_data, buf);
+            }
+            ProduceRequest::V3 {
+                transactional_id,
+                acks,
+                timeout,
+                topic_data,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(acks, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(topic_data, buf);
+            }
+            ProduceRequest::V4 {
+                transactional_id,
+                acks,
+                timeout,
+                topic_data,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(acks, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(topic_data, buf);
+            }
+            ProduceRequest::V5 {
+                transactional_id,
+                acks,
+                timeout,
+                topic_data,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(acks, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(topic_data, buf);
+            }
+            ProduceRequest::V6 {
+                transactional_id,
+                acks,
+                timeout,
+                topic_data,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(acks, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(topic_data, buf);
+            }
+            ProduceRequest::V7 {
+                transactional_id,
+                acks,
+                timeout,
+                topic_data,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(acks, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(topic_data, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ProduceRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ProduceRequest::V0 {
+                acks: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                topic_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ProduceRequest::V1 {
+                acks: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                topic_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(ProduceRequest::V2 {
+                acks: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                topic_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(ProduceRequest::V3 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                acks: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                topic_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(ProduceRequest::V4 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                acks: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                topic_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(ProduceRequest::V5 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                acks: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                topic_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            6 => Ok(ProduceRequest::V6 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                acks: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                topic_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            7 => Ok(ProduceRequest::V7 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                acks: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                topic_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ProduceRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod produce_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -426,120 +1119,70 @@ pub mod produce_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicData {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub data: Vec<Data>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Data {
-            /// Topic partition id
-            pub partition: i32,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
+        pub use super::v0::Data;
+        pub use super::v0::TopicData;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicData {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub data: Vec<Data>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Data {
-            /// Topic partition id
-            pub partition: i32,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
+        pub use super::v0::Data;
+        pub use super::v0::TopicData;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicData {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub data: Vec<Data>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Data {
-            /// Topic partition id
-            pub partition: i32,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
+        pub use super::v0::Data;
+        pub use super::v0::TopicData;
     }
     pub mod v4 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicData {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub data: Vec<Data>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Data {
-            /// Topic partition id
-            pub partition: i32,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
+        pub use super::v0::Data;
+        pub use super::v0::TopicData;
     }
     pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicData {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub data: Vec<Data>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Data {
-            /// Topic partition id
-            pub partition: i32,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
+        pub use super::v0::Data;
+        pub use super::v0::TopicData;
     }
     pub mod v6 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicData {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub data: Vec<Data>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Data {
-            /// Topic partition id
-            pub partition: i32,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
+        pub use super::v0::Data;
+        pub use super::v0::TopicData;
     }
     pub mod v7 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicData {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub data: Vec<Data>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Data {
-            /// Topic partition id
-            pub partition: i32,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
+        pub use super::v0::Data;
+        pub use super::v0::TopicData;
+    }
+}
+
+impl crate::codec::Encode for produce_request::v0::TopicData {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.data, buf);
+    }
+}
+
+impl crate::codec::Decode for produce_request::v0::TopicData {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(produce_request::v0::TopicData {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            data: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for produce_request::v0::Data {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.record_set, buf);
+    }
+}
+
+impl crate::codec::Decode for produce_request::v0::Data {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(produce_request::v0::Data {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            record_set: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ProduceResponse {
     V0 {
         /// null
@@ -596,6 +1239,138 @@ pub enum ProduceResponse {
     },
 }
 
+impl crate::versions::ApiVersions for ProduceResponse {
+    const NAME: &'static str = "ProduceResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 7;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("responses", 0..=7),
+        ("responses.topic", 0..=7),
+        ("responses.partition_responses", 0..=7),
+        ("responses.partition_responses.partition", 0..=7),
+        ("responses.partition_responses.error_code", 0..=7),
+        ("responses.partition_responses.base_offset", 0..=7),
+        ("throttle_time_ms", 1..=7),
+        ("responses.partition_responses.log_append_time", 2..=7),
+        ("responses.partition_responses.log_start_offset", 5..=7),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ProduceResponse::V0 { .. } => 0,
+            ProduceResponse::V1 { .. } => 1,
+            ProduceResponse::V2 { .. } => 2,
+            ProduceResponse::V3 { .. } => 3,
+            ProduceResponse::V4 { .. } => 4,
+            ProduceResponse::V5 { .. } => 5,
+            ProduceResponse::V6 { .. } => 6,
+            ProduceResponse::V7 { .. } => 7,
+        }
+    }
+}
+
+impl crate::codec::Encode for ProduceResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ProduceResponse::V0 { responses } => {
+                crate::codec::Encode::encode(responses, buf);
+            }
+            ProduceResponse::V1 {
+                responses,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            ProduceResponse::V2 {
+                responses,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            ProduceResponse::V3 {
+                responses,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            ProduceResponse::V4 {
+                responses,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            ProduceResponse::V5 {
+                responses,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            ProduceResponse::V6 {
+                responses,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            ProduceResponse::V7 {
+                responses,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ProduceResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ProduceResponse::V0 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ProduceResponse::V1 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(ProduceResponse::V2 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(ProduceResponse::V3 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(ProduceResponse::V4 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(ProduceResponse::V5 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            6 => Ok(ProduceResponse::V6 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            7 => Ok(ProduceResponse::V7 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ProduceResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod produce_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -616,22 +1391,8 @@ pub mod produce_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// null
-            pub base_offset: i64,
-        }
+        pub use super::v0::PartitionResponses;
+        pub use super::v0::Responses;
     }
     pub mod v2 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -657,29 +1418,14 @@ pub mod produce_response {
         }
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// null
-            pub base_offset: i64,
-            /// The timestamp returned by broker after appending the messages. If
-            /// CreateTime is used for the topic, the timestamp will be -1. If
-            /// LogAppendTime is used for the topic, the timestamp will be the broker
-            /// local time when the messages are appended.
-            pub log_append_time: i64,
-        }
+        pub use super::v2::PartitionResponses;
+        pub use super::v2::Responses;
     }
     pub mod v4 {
+        pub use super::v2::PartitionResponses;
+        pub use super::v2::Responses;
+    }
+    pub mod v5 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Responses {
             /// Name of topic
@@ -700,89 +1446,132 @@ pub mod produce_response {
             /// LogAppendTime is used for the topic, the timestamp will be the broker
             /// local time when the messages are appended.
             pub log_append_time: i64,
-        }
-    }
-    pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// null
-            pub base_offset: i64,
-            /// The timestamp returned by broker after appending the messages. If
-            /// CreateTime is used for the topic, the timestamp will be -1. If
-            /// LogAppendTime is used for the topic, the timestamp will be the broker
-            /// local time when the messages are appended.
-            pub log_append_time: i64,
-            /// The start offset of the log at the time this produce response was
-            /// created
-            pub log_start_offset: i64,
+            /// The start offset of the log at the time this produce response was
+            /// created
+            pub log_start_offset: i64,
         }
     }
     pub mod v6 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// null
-            pub base_offset: i64,
-            /// The timestamp returned by broker after appending the messages. If
-            /// CreateTime is used for the topic, the timestamp will be -1. If
-            /// LogAppendTime is used for the topic, the timestamp will be the broker
-            /// local time when the messages are appended.
-            pub log_append_time: i64,
-            /// The start offset of the log at the time this produce response was
-            /// created
-            pub log_start_offset: i64,
-        }
+        pub use super::v5::PartitionResponses;
+        pub use super::v5::Responses;
     }
     pub mod v7 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// null
-            pub base_offset: i64,
-            /// The timestamp returned by broker after appending the messages. If
-            /// CreateTime is used for the topic, the timestamp will be -1. If
-            /// LogAppendTime is used for the topic, the timestamp will be the broker
-            /// local time when the messages are appended.
-            pub log_append_time: i64,
-            /// The start offset of the log at the time this produce response was
-            /// created
-            pub log_start_offset: i64,
-        }
+        pub use super::v5::PartitionResponses;
+        pub use super::v5::Responses;
+    }
+}
+
+impl crate::codec::Encode for produce_response::v0::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for produce_response::v0::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(produce_response::v0::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for produce_response::v0::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.base_offset, buf);
+    }
+}
+
+impl crate::codec::Decode for produce_response::v0::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(produce_response::v0::PartitionResponses {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            base_offset: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for produce_response::v2::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for produce_response::v2::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(produce_response::v2::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for produce_response::v2::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.base_offset, buf);
+        crate::codec::Encode::encode(&self.log_append_time, buf);
+    }
+}
+
+impl crate::codec::Decode for produce_response::v2::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(produce_response::v2::PartitionResponses {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            base_offset: crate::codec::Decode::decode(buf, version)?,
+            log_append_time: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for produce_response::v5::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for produce_response::v5::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(produce_response::v5::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for produce_response::v5::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.base_offset, buf);
+        crate::codec::Encode::encode(&self.log_append_time, buf);
+        crate::codec::Encode::encode(&self.log_start_offset, buf);
+    }
+}
+
+impl crate::codec::Decode for produce_response::v5::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(produce_response::v5::PartitionResponses {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            base_offset: crate::codec::Decode::decode(buf, version)?,
+            log_append_time: crate::codec::Decode::decode(buf, version)?,
+            log_start_offset: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum FetchRequest {
     V0 {
         /// Broker id of the follower. For normal consumers, use -1.
@@ -1055,6 +1844,377 @@ pub enum FetchRequest {
     },
 }
 
+impl crate::versions::ApiVersions for FetchRequest {
+    const NAME: &'static str = "FetchRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 11;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("replica_id", 0..=11),
+        ("max_wait_time", 0..=11),
+        ("min_bytes", 0..=11),
+        ("topics", 0..=11),
+        ("topics.topic", 0..=11),
+        ("topics.partitions", 0..=11),
+        ("topics.partitions.partition", 0..=11),
+        ("topics.partitions.fetch_offset", 0..=11),
+        ("topics.partitions.partition_max_bytes", 0..=11),
+        ("max_bytes", 3..=11),
+        ("isolation_level", 4..=11),
+        ("topics.partitions.log_start_offset", 5..=11),
+        ("session_id", 7..=11),
+        ("session_epoch", 7..=11),
+        ("forgotten_topics_data", 7..=11),
+        ("forgotten_topics_data.topic", 7..=11),
+        ("forgotten_topics_data.partitions", 7..=11),
+        ("topics.partitions.current_leader_epoch", 9..=11),
+        ("rack_id", 11..=11),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            FetchRequest::V0 { .. } => 0,
+            FetchRequest::V1 { .. } => 1,
+            FetchRequest::V2 { .. } => 2,
+            FetchRequest::V3 { .. } => 3,
+            FetchRequest::V4 { .. } => 4,
+            FetchRequest::V5 { .. } => 5,
+            FetchRequest::V6 { .. } => 6,
+            FetchRequest::V7 { .. } => 7,
+            FetchRequest::V8 { .. } => 8,
+            FetchRequest::V9 { .. } => 9,
+            FetchRequest::V10 { .. } => 10,
+            FetchRequest::V11 { .. } => 11,
+        }
+    }
+}
+
+impl crate::request::Request for FetchRequest {
+    const API_KEY: ApiKey = ApiKey::Fetch;
+    type Response = FetchResponse;
+}
+
+impl crate::codec::Encode for FetchRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            FetchRequest::V0 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            FetchRequest::V1 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            FetchRequest::V2 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            FetchRequest::V3 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            FetchRequest::V4 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                isolation_level,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            FetchRequest::V5 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                isolation_level,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            FetchRequest::V6 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                isolation_level,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            FetchRequest::V7 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                isolation_level,
+                session_id,
+                session_epoch,
+                topics,
+                forgotten_topics_data,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(session_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(forgotten_topics_data, buf);
+            }
+            FetchRequest::V8 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                isolation_level,
+                session_id,
+                session_epoch,
+                topics,
+                forgotten_topics_data,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(session_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(forgotten_topics_data, buf);
+            }
+            FetchRequest::V9 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                isolation_level,
+                session_id,
+                session_epoch,
+                topics,
+                forgotten_topics_data,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(session_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(forgotten_topics_data, buf);
+            }
+            FetchRequest::V10 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                isolation_level,
+                session_id,
+                session_epoch,
+                topics,
+                forgotten_topics_data,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(session_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(forgotten_topics_data, buf);
+            }
+            FetchRequest::V11 {
+                replica_id,
+                max_wait_time,
+                min_bytes,
+                max_bytes,
+                isolation_level,
+                session_id,
+                session_epoch,
+                topics,
+                forgotten_topics_data,
+                rack_id,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(max_wait_time, buf);
+                crate::codec::Encode::encode(min_bytes, buf);
+                crate::codec::Encode::encode(max_bytes, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(session_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(forgotten_topics_data, buf);
+                crate::codec::Encode::encode(rack_id, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for FetchRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(FetchRequest::V0 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(FetchRequest::V1 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(FetchRequest::V2 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(FetchRequest::V3 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(FetchRequest::V4 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(FetchRequest::V5 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            6 => Ok(FetchRequest::V6 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            7 => Ok(FetchRequest::V7 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                session_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+                forgotten_topics_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            8 => Ok(FetchRequest::V8 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                session_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+                forgotten_topics_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            9 => Ok(FetchRequest::V9 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                session_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+                forgotten_topics_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            10 => Ok(FetchRequest::V10 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                session_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+                forgotten_topics_data: crate::codec::Decode::decode(buf, version)?,
+            }),
+            11 => Ok(FetchRequest::V11 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                max_wait_time: crate::codec::Decode::decode(buf, version)?,
+                min_bytes: crate::codec::Decode::decode(buf, version)?,
+                max_bytes: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                session_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+                forgotten_topics_data: crate::codec::Decode::decode(buf, version)?,
+                rack_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "FetchRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod fetch_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -1075,76 +2235,20 @@ pub mod fetch_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v4 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v5 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -1168,34 +2272,12 @@ pub mod fetch_request {
         }
     }
     pub mod v6 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Earliest available offset of the follower replica. The field is only
-            /// used when request is sent by follower.
-            pub log_start_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
+        pub use super::v5::Partitions;
+        pub use super::v5::Topics;
     }
     pub mod v7 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to remove from the fetch session.
-            pub partitions: Vec<Partitions>,
-        }
+        pub use super::v5::Partitions;
+        pub use super::v5::Topics;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct ForgottenTopicsData {
             /// Name of topic
@@ -1203,20 +2285,14 @@ pub mod fetch_request {
             /// Partitions to remove from the fetch session.
             pub partitions: Vec<i32>,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Earliest available offset of the follower replica. The field is only
-            /// used when request is sent by follower.
-            pub log_start_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
     }
     pub mod v8 {
+        pub use super::v5::Partitions;
+        pub use super::v5::Topics;
+        pub use super::v7::ForgottenTopicsData;
+    }
+    pub mod v9 {
+        pub use super::v7::ForgottenTopicsData;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
             /// Name of topic
@@ -1225,51 +2301,16 @@ pub mod fetch_request {
             pub partitions: Vec<Partitions>,
         }
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ForgottenTopicsData {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to remove from the fetch session.
-            pub partitions: Vec<i32>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Partitions {
             /// Topic partition id
             pub partition: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Earliest available offset of the follower replica. The field is only
-            /// used when request is sent by follower.
-            pub log_start_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
-    }
-    pub mod v9 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to remove from the fetch session.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ForgottenTopicsData {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to remove from the fetch session.
-            pub partitions: Vec<i32>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The current leader epoch, if provided, is used to fence consumers/
-            /// replicas with old metadata. If the epoch provided by the client is
-            /// larger than the current epoch known to the broker, then the
-            /// UNKNOWN_LEADER_EPOCH error code will be returned. If the provided
-            /// epoch is smaller, then the FENCED_LEADER_EPOCH error code will be
-            /// returned.
-            pub current_leader_epoch: i32,
+            /// The current leader epoch, if provided, is used to fence consumers/
+            /// replicas with old metadata. If the epoch provided by the client is
+            /// larger than the current epoch known to the broker, then the
+            /// UNKNOWN_LEADER_EPOCH error code will be returned. If the provided
+            /// epoch is smaller, then the FENCED_LEADER_EPOCH error code will be
+            /// returned.
+            pub current_leader_epoch: i32,
             /// Message offset.
             pub fetch_offset: i64,
             /// Earliest available offset of the follower replica. The field is only
@@ -1280,78 +2321,144 @@ pub mod fetch_request {
         }
     }
     pub mod v10 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to remove from the fetch session.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ForgottenTopicsData {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to remove from the fetch session.
-            pub partitions: Vec<i32>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The current leader epoch, if provided, is used to fence consumers/
-            /// replicas with old metadata. If the epoch provided by the client is
-            /// larger than the current epoch known to the broker, then the
-            /// UNKNOWN_LEADER_EPOCH error code will be returned. If the provided
-            /// epoch is smaller, then the FENCED_LEADER_EPOCH error code will be
-            /// returned.
-            pub current_leader_epoch: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Earliest available offset of the follower replica. The field is only
-            /// used when request is sent by follower.
-            pub log_start_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
+        pub use super::v7::ForgottenTopicsData;
+        pub use super::v9::Partitions;
+        pub use super::v9::Topics;
     }
     pub mod v11 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to remove from the fetch session.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ForgottenTopicsData {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to remove from the fetch session.
-            pub partitions: Vec<i32>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The current leader epoch, if provided, is used to fence consumers/
-            /// replicas with old metadata. If the epoch provided by the client is
-            /// larger than the current epoch known to the broker, then the
-            /// UNKNOWN_LEADER_EPOCH error code will be returned. If the provided
-            /// epoch is smaller, then the FENCED_LEADER_EPOCH error code will be
-            /// returned.
-            pub current_leader_epoch: i32,
-            /// Message offset.
-            pub fetch_offset: i64,
-            /// Earliest available offset of the follower replica. The field is only
-            /// used when request is sent by follower.
-            pub log_start_offset: i64,
-            /// Maximum bytes to fetch.
-            pub partition_max_bytes: i32,
-        }
+        pub use super::v7::ForgottenTopicsData;
+        pub use super::v9::Partitions;
+        pub use super::v9::Topics;
+    }
+}
+
+impl crate::codec::Encode for fetch_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_request::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.fetch_offset, buf);
+        crate::codec::Encode::encode(&self.partition_max_bytes, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_request::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_request::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            fetch_offset: crate::codec::Decode::decode(buf, version)?,
+            partition_max_bytes: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_request::v5::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_request::v5::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_request::v5::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_request::v5::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.fetch_offset, buf);
+        crate::codec::Encode::encode(&self.log_start_offset, buf);
+        crate::codec::Encode::encode(&self.partition_max_bytes, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_request::v5::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_request::v5::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            fetch_offset: crate::codec::Decode::decode(buf, version)?,
+            log_start_offset: crate::codec::Decode::decode(buf, version)?,
+            partition_max_bytes: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_request::v7::ForgottenTopicsData {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_request::v7::ForgottenTopicsData {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_request::v7::ForgottenTopicsData {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_request::v9::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_request::v9::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_request::v9::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_request::v9::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.current_leader_epoch, buf);
+        crate::codec::Encode::encode(&self.fetch_offset, buf);
+        crate::codec::Encode::encode(&self.log_start_offset, buf);
+        crate::codec::Encode::encode(&self.partition_max_bytes, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_request::v9::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_request::v9::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            current_leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            fetch_offset: crate::codec::Decode::decode(buf, version)?,
+            log_start_offset: crate::codec::Decode::decode(buf, version)?,
+            partition_max_bytes: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum FetchResponse {
     V0 {
         /// null
@@ -1456,33 +2563,253 @@ pub enum FetchResponse {
     },
 }
 
-pub mod fetch_response {
-    pub mod v0 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
+impl crate::versions::ApiVersions for FetchResponse {
+    const NAME: &'static str = "FetchResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 11;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("responses", 0..=11),
+        ("responses.topic", 0..=11),
+        ("responses.partition_responses", 0..=11),
+        ("responses.partition_responses.partition_header", 0..=11),
+        (
+            "responses.partition_responses.partition_header.partition",
+            0..=11,
+        ),
+        (
+            "responses.partition_responses.partition_header.error_code",
+            0..=11,
+        ),
+        (
+            "responses.partition_responses.partition_header.high_watermark",
+            0..=11,
+        ),
+        ("responses.partition_responses.record_set", 0..=11),
+        ("throttle_time_ms", 1..=11),
+        (
+            "responses.partition_responses.partition_header.last_stable_offset",
+            4..=11,
+        ),
+        (
+            "responses.partition_responses.partition_header.aborted_transactions",
+            4..=11,
+        ),
+        (
+            "responses.partition_responses.partition_header.aborted_transactions.producer_id",
+            4..=11,
+        ),
+        (
+            "responses.partition_responses.partition_header.aborted_transactions.first_offset",
+            4..=11,
+        ),
+        (
+            "responses.partition_responses.partition_header.log_start_offset",
+            5..=11,
+        ),
+        ("error_code", 7..=11),
+        ("session_id", 7..=11),
+        (
+            "responses.partition_responses.partition_header.preferred_read_replica",
+            11..=11,
+        ),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            FetchResponse::V0 { .. } => 0,
+            FetchResponse::V1 { .. } => 1,
+            FetchResponse::V2 { .. } => 2,
+            FetchResponse::V3 { .. } => 3,
+            FetchResponse::V4 { .. } => 4,
+            FetchResponse::V5 { .. } => 5,
+            FetchResponse::V6 { .. } => 6,
+            FetchResponse::V7 { .. } => 7,
+            FetchResponse::V8 { .. } => 8,
+            FetchResponse::V9 { .. } => 9,
+            FetchResponse::V10 { .. } => 10,
+            FetchResponse::V11 { .. } => 11,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// null
-            pub partition_header: PartitionHeader,
-            /// null
-            pub record_set: crate::types::NullableBytes,
+    }
+}
+
+impl crate::codec::Encode for FetchResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            FetchResponse::V0 { responses } => {
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V1 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V2 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V3 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V4 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V5 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V6 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V7 {
+                throttle_time_ms,
+                error_code,
+                session_id,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V8 {
+                throttle_time_ms,
+                error_code,
+                session_id,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V9 {
+                throttle_time_ms,
+                error_code,
+                session_id,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V10 {
+                throttle_time_ms,
+                error_code,
+                session_id,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            FetchResponse::V11 {
+                throttle_time_ms,
+                error_code,
+                session_id,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(session_id, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionHeader {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// Last committed offset.
-            pub high_watermark: i64,
+    }
+}
+
+impl crate::codec::Decode for FetchResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(FetchResponse::V0 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(FetchResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(FetchResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(FetchResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(FetchResponse::V4 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(FetchResponse::V5 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            6 => Ok(FetchResponse::V6 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            7 => Ok(FetchResponse::V7 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            8 => Ok(FetchResponse::V8 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            9 => Ok(FetchResponse::V9 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            10 => Ok(FetchResponse::V10 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            11 => Ok(FetchResponse::V11 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                session_id: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "FetchResponse", version
+            ))),
         }
     }
-    pub mod v1 {
+}
+
+pub mod fetch_response {
+    pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Responses {
             /// Name of topic
@@ -1507,55 +2834,20 @@ pub mod fetch_response {
             pub high_watermark: i64,
         }
     }
+    pub mod v1 {
+        pub use super::v0::PartitionHeader;
+        pub use super::v0::PartitionResponses;
+        pub use super::v0::Responses;
+    }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// null
-            pub partition_header: PartitionHeader,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionHeader {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// Last committed offset.
-            pub high_watermark: i64,
-        }
+        pub use super::v0::PartitionHeader;
+        pub use super::v0::PartitionResponses;
+        pub use super::v0::Responses;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// null
-            pub partition_header: PartitionHeader,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionHeader {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// Last committed offset.
-            pub high_watermark: i64,
-        }
+        pub use super::v0::PartitionHeader;
+        pub use super::v0::PartitionResponses;
+        pub use super::v0::Responses;
     }
     pub mod v4 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -1596,6 +2888,7 @@ pub mod fetch_response {
         }
     }
     pub mod v5 {
+        pub use super::v4::AbortedTransactions;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Responses {
             /// Name of topic
@@ -1627,15 +2920,39 @@ pub mod fetch_response {
             /// null
             pub aborted_transactions: Vec<AbortedTransactions>,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct AbortedTransactions {
-            /// The producer id associated with the aborted transactions
-            pub producer_id: i64,
-            /// The first offset in the aborted transaction
-            pub first_offset: i64,
-        }
     }
     pub mod v6 {
+        pub use super::v4::AbortedTransactions;
+        pub use super::v5::PartitionHeader;
+        pub use super::v5::PartitionResponses;
+        pub use super::v5::Responses;
+    }
+    pub mod v7 {
+        pub use super::v4::AbortedTransactions;
+        pub use super::v5::PartitionHeader;
+        pub use super::v5::PartitionResponses;
+        pub use super::v5::Responses;
+    }
+    pub mod v8 {
+        pub use super::v4::AbortedTransactions;
+        pub use super::v5::PartitionHeader;
+        pub use super::v5::PartitionResponses;
+        pub use super::v5::Responses;
+    }
+    pub mod v9 {
+        pub use super::v4::AbortedTransactions;
+        pub use super::v5::PartitionHeader;
+        pub use super::v5::PartitionResponses;
+        pub use super::v5::Responses;
+    }
+    pub mod v10 {
+        pub use super::v4::AbortedTransactions;
+        pub use super::v5::PartitionHeader;
+        pub use super::v5::PartitionResponses;
+        pub use super::v5::Responses;
+    }
+    pub mod v11 {
+        pub use super::v4::AbortedTransactions;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Responses {
             /// Name of topic
@@ -1666,220 +2983,249 @@ pub mod fetch_response {
             pub log_start_offset: i64,
             /// null
             pub aborted_transactions: Vec<AbortedTransactions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct AbortedTransactions {
-            /// The producer id associated with the aborted transactions
-            pub producer_id: i64,
-            /// The first offset in the aborted transaction
-            pub first_offset: i64,
+            /// The ID of the replica that the consumer should prefer.
+            pub preferred_read_replica: i32,
         }
     }
-    pub mod v7 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// null
-            pub partition_header: PartitionHeader,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionHeader {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// Last committed offset.
-            pub high_watermark: i64,
-            /// The last stable offset (or LSO) of the partition. This is the last
-            /// offset such that the state of all transactional records prior to this
-            /// offset have been decided (ABORTED or COMMITTED)
-            pub last_stable_offset: i64,
-            /// Earliest available offset.
-            pub log_start_offset: i64,
-            /// null
-            pub aborted_transactions: Vec<AbortedTransactions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct AbortedTransactions {
-            /// The producer id associated with the aborted transactions
-            pub producer_id: i64,
-            /// The first offset in the aborted transaction
-            pub first_offset: i64,
-        }
+}
+
+impl crate::codec::Encode for fetch_response::v0::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
     }
-    pub mod v8 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// null
-            pub partition_header: PartitionHeader,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionHeader {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// Last committed offset.
-            pub high_watermark: i64,
-            /// The last stable offset (or LSO) of the partition. This is the last
-            /// offset such that the state of all transactional records prior to this
-            /// offset have been decided (ABORTED or COMMITTED)
-            pub last_stable_offset: i64,
-            /// Earliest available offset.
-            pub log_start_offset: i64,
-            /// null
-            pub aborted_transactions: Vec<AbortedTransactions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct AbortedTransactions {
-            /// The producer id associated with the aborted transactions
-            pub producer_id: i64,
-            /// The first offset in the aborted transaction
-            pub first_offset: i64,
-        }
+}
+
+impl crate::codec::Decode for fetch_response::v0::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v0::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
     }
-    pub mod v9 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// null
-            pub partition_header: PartitionHeader,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionHeader {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// Last committed offset.
-            pub high_watermark: i64,
-            /// The last stable offset (or LSO) of the partition. This is the last
-            /// offset such that the state of all transactional records prior to this
-            /// offset have been decided (ABORTED or COMMITTED)
-            pub last_stable_offset: i64,
-            /// Earliest available offset.
-            pub log_start_offset: i64,
-            /// null
-            pub aborted_transactions: Vec<AbortedTransactions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct AbortedTransactions {
-            /// The producer id associated with the aborted transactions
-            pub producer_id: i64,
-            /// The first offset in the aborted transaction
-            pub first_offset: i64,
-        }
+}
+
+impl crate::codec::Encode for fetch_response::v0::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_header, buf);
+        crate::codec::Encode::encode(&self.record_set, buf);
     }
-    pub mod v10 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// null
-            pub partition_header: PartitionHeader,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionHeader {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// Last committed offset.
-            pub high_watermark: i64,
-            /// The last stable offset (or LSO) of the partition. This is the last
-            /// offset such that the state of all transactional records prior to this
-            /// offset have been decided (ABORTED or COMMITTED)
-            pub last_stable_offset: i64,
-            /// Earliest available offset.
-            pub log_start_offset: i64,
-            /// null
-            pub aborted_transactions: Vec<AbortedTransactions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct AbortedTransactions {
-            /// The producer id associated with the aborted transactions
-            pub producer_id: i64,
-            /// The first offset in the aborted transaction
-            pub first_offset: i64,
-        }
+}
+
+impl crate::codec::Decode for fetch_response::v0::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v0::PartitionResponses {
+            partition_header: crate::codec::Decode::decode(buf, version)?,
+            record_set: crate::codec::Decode::decode(buf, version)?,
+        })
     }
-    pub mod v11 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// null
-            pub partition_header: PartitionHeader,
-            /// null
-            pub record_set: crate::types::NullableBytes,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionHeader {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// Last committed offset.
-            pub high_watermark: i64,
-            /// The last stable offset (or LSO) of the partition. This is the last
-            /// offset such that the state of all transactional records prior to this
-            /// offset have been decided (ABORTED or COMMITTED)
-            pub last_stable_offset: i64,
-            /// Earliest available offset.
-            pub log_start_offset: i64,
-            /// null
-            pub aborted_transactions: Vec<AbortedTransactions>,
-            /// The ID of the replica that the consumer should prefer.
-            pub preferred_read_replica: i32,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct AbortedTransactions {
-            /// The producer id associated with the aborted transactions
-            pub producer_id: i64,
-            /// The first offset in the aborted transaction
-            pub first_offset: i64,
-        }
+}
+
+impl crate::codec::Encode for fetch_response::v0::PartitionHeader {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.high_watermark, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v0::PartitionHeader {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v0::PartitionHeader {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            high_watermark: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v4::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v4::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v4::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v4::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_header, buf);
+        crate::codec::Encode::encode(&self.record_set, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v4::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v4::PartitionResponses {
+            partition_header: crate::codec::Decode::decode(buf, version)?,
+            record_set: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v4::PartitionHeader {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.high_watermark, buf);
+        crate::codec::Encode::encode(&self.last_stable_offset, buf);
+        crate::codec::Encode::encode(&self.aborted_transactions, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v4::PartitionHeader {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v4::PartitionHeader {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            high_watermark: crate::codec::Decode::decode(buf, version)?,
+            last_stable_offset: crate::codec::Decode::decode(buf, version)?,
+            aborted_transactions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v4::AbortedTransactions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.producer_id, buf);
+        crate::codec::Encode::encode(&self.first_offset, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v4::AbortedTransactions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v4::AbortedTransactions {
+            producer_id: crate::codec::Decode::decode(buf, version)?,
+            first_offset: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v5::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v5::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v5::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v5::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_header, buf);
+        crate::codec::Encode::encode(&self.record_set, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v5::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v5::PartitionResponses {
+            partition_header: crate::codec::Decode::decode(buf, version)?,
+            record_set: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v5::PartitionHeader {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.high_watermark, buf);
+        crate::codec::Encode::encode(&self.last_stable_offset, buf);
+        crate::codec::Encode::encode(&self.log_start_offset, buf);
+        crate::codec::Encode::encode(&self.aborted_transactions, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v5::PartitionHeader {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v5::PartitionHeader {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            high_watermark: crate::codec::Decode::decode(buf, version)?,
+            last_stable_offset: crate::codec::Decode::decode(buf, version)?,
+            log_start_offset: crate::codec::Decode::decode(buf, version)?,
+            aborted_transactions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v11::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v11::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v11::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for fetch_response::v11::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_header, buf);
+        crate::codec::Encode::encode(&self.record_set, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v11::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v11::PartitionResponses {
+            partition_header: crate::codec::Decode::decode(buf, version)?,
+            record_set: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for fetch_response::v11::PartitionHeader {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.high_watermark, buf);
+        crate::codec::Encode::encode(&self.last_stable_offset, buf);
+        crate::codec::Encode::encode(&self.log_start_offset, buf);
+        crate::codec::Encode::encode(&self.aborted_transactions, buf);
+        crate::codec::Encode::encode(&self.preferred_read_replica, buf);
+    }
+}
+
+impl crate::codec::Decode for fetch_response::v11::PartitionHeader {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(fetch_response::v11::PartitionHeader {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            high_watermark: crate::codec::Decode::decode(buf, version)?,
+            last_stable_offset: crate::codec::Decode::decode(buf, version)?,
+            log_start_offset: crate::codec::Decode::decode(buf, version)?,
+            aborted_transactions: crate::codec::Decode::decode(buf, version)?,
+            preferred_read_replica: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ListOffsetsRequest {
     V0 {
         /// Broker id of the follower. For normal consumers, use -1.
@@ -1955,6 +3301,130 @@ pub enum ListOffsetsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for ListOffsetsRequest {
+    const NAME: &'static str = "ListOffsetsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 5;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("replica_id", 0..=5),
+        ("topics", 0..=5),
+        ("topics.topic", 0..=5),
+        ("topics.partitions", 0..=5),
+        ("topics.partitions.partition", 0..=5),
+        ("topics.partitions.timestamp", 0..=5),
+        ("topics.partitions.max_num_offsets", 0..=0),
+        ("isolation_level", 2..=5),
+        ("topics.partitions.current_leader_epoch", 4..=5),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ListOffsetsRequest::V0 { .. } => 0,
+            ListOffsetsRequest::V1 { .. } => 1,
+            ListOffsetsRequest::V2 { .. } => 2,
+            ListOffsetsRequest::V3 { .. } => 3,
+            ListOffsetsRequest::V4 { .. } => 4,
+            ListOffsetsRequest::V5 { .. } => 5,
+        }
+    }
+}
+
+impl crate::request::Request for ListOffsetsRequest {
+    const API_KEY: ApiKey = ApiKey::ListOffsets;
+    type Response = ListOffsetsResponse;
+}
+
+impl crate::codec::Encode for ListOffsetsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ListOffsetsRequest::V0 { replica_id, topics } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            ListOffsetsRequest::V1 { replica_id, topics } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            ListOffsetsRequest::V2 {
+                replica_id,
+                isolation_level,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            ListOffsetsRequest::V3 {
+                replica_id,
+                isolation_level,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            ListOffsetsRequest::V4 {
+                replica_id,
+                isolation_level,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            ListOffsetsRequest::V5 {
+                replica_id,
+                isolation_level,
+                topics,
+            } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(isolation_level, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ListOffsetsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ListOffsetsRequest::V0 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ListOffsetsRequest::V1 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(ListOffsetsRequest::V2 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(ListOffsetsRequest::V3 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(ListOffsetsRequest::V4 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(ListOffsetsRequest::V5 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                isolation_level: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ListOffsetsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod list_offsets_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -1991,36 +3461,12 @@ pub mod list_offsets_request {
         }
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to list offsets.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The target timestamp for the partition.
-            pub timestamp: i64,
-        }
+        pub use super::v1::Partitions;
+        pub use super::v1::Topics;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to list offsets.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The target timestamp for the partition.
-            pub timestamp: i64,
-        }
+        pub use super::v1::Partitions;
+        pub use super::v1::Topics;
     }
     pub mod v4 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -2046,31 +3492,114 @@ pub mod list_offsets_request {
         }
     }
     pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to list offsets.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The current leader epoch, if provided, is used to fence consumers/
-            /// replicas with old metadata. If the epoch provided by the client is
-            /// larger than the current epoch known to the broker, then the
-            /// UNKNOWN_LEADER_EPOCH error code will be returned. If the provided
-            /// epoch is smaller, then the FENCED_LEADER_EPOCH error code will be
-            /// returned.
-            pub current_leader_epoch: i32,
-            /// The target timestamp for the partition.
-            pub timestamp: i64,
-        }
+        pub use super::v4::Partitions;
+        pub use super::v4::Topics;
+    }
+}
+
+impl crate::codec::Encode for list_offsets_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_request::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.timestamp, buf);
+        crate::codec::Encode::encode(&self.max_num_offsets, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_request::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_request::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            timestamp: crate::codec::Decode::decode(buf, version)?,
+            max_num_offsets: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_request::v1::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_request::v1::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_request::v1::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_request::v1::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.timestamp, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_request::v1::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_request::v1::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            timestamp: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_request::v4::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_request::v4::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_request::v4::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_request::v4::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.current_leader_epoch, buf);
+        crate::codec::Encode::encode(&self.timestamp, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_request::v4::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_request::v4::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            current_leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            timestamp: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ListOffsetsResponse {
     V0 {
         /// The listed offsets by topic
@@ -2110,6 +3639,110 @@ pub enum ListOffsetsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for ListOffsetsResponse {
+    const NAME: &'static str = "ListOffsetsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 5;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("responses", 0..=5),
+        ("responses.topic", 0..=5),
+        ("responses.partition_responses", 0..=5),
+        ("responses.partition_responses.partition", 0..=5),
+        ("responses.partition_responses.error_code", 0..=5),
+        ("responses.partition_responses.offsets", 0..=0),
+        ("responses.partition_responses.timestamp", 1..=5),
+        ("responses.partition_responses.offset", 1..=5),
+        ("throttle_time_ms", 2..=5),
+        ("responses.partition_responses.leader_epoch", 4..=5),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ListOffsetsResponse::V0 { .. } => 0,
+            ListOffsetsResponse::V1 { .. } => 1,
+            ListOffsetsResponse::V2 { .. } => 2,
+            ListOffsetsResponse::V3 { .. } => 3,
+            ListOffsetsResponse::V4 { .. } => 4,
+            ListOffsetsResponse::V5 { .. } => 5,
+        }
+    }
+}
+
+impl crate::codec::Encode for ListOffsetsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ListOffsetsResponse::V0 { responses } => {
+                crate::codec::Encode::encode(responses, buf);
+            }
+            ListOffsetsResponse::V1 { responses } => {
+                crate::codec::Encode::encode(responses, buf);
+            }
+            ListOffsetsResponse::V2 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            ListOffsetsResponse::V3 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            ListOffsetsResponse::V4 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            ListOffsetsResponse::V5 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ListOffsetsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ListOffsetsResponse::V0 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ListOffsetsResponse::V1 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(ListOffsetsResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(ListOffsetsResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(ListOffsetsResponse::V4 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(ListOffsetsResponse::V5 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ListOffsetsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod list_offsets_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -2150,44 +3783,12 @@ pub mod list_offsets_response {
         }
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// The listed offsets by partition
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// The timestamp associated with the returned offset
-            pub timestamp: i64,
-            /// The offset found
-            pub offset: i64,
-        }
+        pub use super::v1::PartitionResponses;
+        pub use super::v1::Responses;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// The listed offsets by partition
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// The timestamp associated with the returned offset
-            pub timestamp: i64,
-            /// The offset found
-            pub offset: i64,
-        }
+        pub use super::v1::PartitionResponses;
+        pub use super::v1::Responses;
     }
     pub mod v4 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -2212,30 +3813,122 @@ pub mod list_offsets_response {
         }
     }
     pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// The listed offsets by partition
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-            /// The timestamp associated with the returned offset
-            pub timestamp: i64,
-            /// The offset found
-            pub offset: i64,
-            /// The leader epoch
-            pub leader_epoch: i32,
-        }
+        pub use super::v4::PartitionResponses;
+        pub use super::v4::Responses;
+    }
+}
+
+impl crate::codec::Encode for list_offsets_response::v0::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_response::v0::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_response::v0::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_response::v0::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.offsets, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_response::v0::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_response::v0::PartitionResponses {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            offsets: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_response::v1::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_response::v1::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_response::v1::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_response::v1::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.timestamp, buf);
+        crate::codec::Encode::encode(&self.offset, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_response::v1::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_response::v1::PartitionResponses {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            timestamp: crate::codec::Decode::decode(buf, version)?,
+            offset: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_response::v4::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_response::v4::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_response::v4::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for list_offsets_response::v4::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.timestamp, buf);
+        crate::codec::Encode::encode(&self.offset, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+    }
+}
+
+impl crate::codec::Decode for list_offsets_response::v4::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_offsets_response::v4::PartitionResponses {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            timestamp: crate::codec::Decode::decode(buf, version)?,
+            offset: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum MetadataRequest {
     V0 {
         /// The topics to fetch metadata for.
@@ -2294,6 +3987,142 @@ pub enum MetadataRequest {
     },
 }
 
+impl crate::versions::ApiVersions for MetadataRequest {
+    const NAME: &'static str = "MetadataRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 8;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topics", 0..=8),
+        ("topics.name", 0..=8),
+        ("allow_auto_topic_creation", 4..=8),
+        ("include_cluster_authorized_operations", 8..=8),
+        ("include_topic_authorized_operations", 8..=8),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            MetadataRequest::V0 { .. } => 0,
+            MetadataRequest::V1 { .. } => 1,
+            MetadataRequest::V2 { .. } => 2,
+            MetadataRequest::V3 { .. } => 3,
+            MetadataRequest::V4 { .. } => 4,
+            MetadataRequest::V5 { .. } => 5,
+            MetadataRequest::V6 { .. } => 6,
+            MetadataRequest::V7 { .. } => 7,
+            MetadataRequest::V8 { .. } => 8,
+        }
+    }
+}
+
+impl crate::request::Request for MetadataRequest {
+    const API_KEY: ApiKey = ApiKey::Metadata;
+    type Response = MetadataResponse;
+}
+
+impl crate::codec::Encode for MetadataRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            MetadataRequest::V0 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataRequest::V1 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataRequest::V2 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataRequest::V3 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataRequest::V4 {
+                topics,
+                allow_auto_topic_creation,
+            } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(allow_auto_topic_creation, buf);
+            }
+            MetadataRequest::V5 {
+                topics,
+                allow_auto_topic_creation,
+            } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(allow_auto_topic_creation, buf);
+            }
+            MetadataRequest::V6 {
+                topics,
+                allow_auto_topic_creation,
+            } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(allow_auto_topic_creation, buf);
+            }
+            MetadataRequest::V7 {
+                topics,
+                allow_auto_topic_creation,
+            } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(allow_auto_topic_creation, buf);
+            }
+            MetadataRequest::V8 {
+                topics,
+                allow_auto_topic_creation,
+                include_cluster_authorized_operations,
+                include_topic_authorized_operations,
+            } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(allow_auto_topic_creation, buf);
+                crate::codec::Encode::encode(include_cluster_authorized_operations, buf);
+                crate::codec::Encode::encode(include_topic_authorized_operations, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for MetadataRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(MetadataRequest::V0 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(MetadataRequest::V1 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(MetadataRequest::V2 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(MetadataRequest::V3 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(MetadataRequest::V4 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                allow_auto_topic_creation: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(MetadataRequest::V5 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                allow_auto_topic_creation: crate::codec::Decode::decode(buf, version)?,
+            }),
+            6 => Ok(MetadataRequest::V6 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                allow_auto_topic_creation: crate::codec::Decode::decode(buf, version)?,
+            }),
+            7 => Ok(MetadataRequest::V7 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                allow_auto_topic_creation: crate::codec::Decode::decode(buf, version)?,
+            }),
+            8 => Ok(MetadataRequest::V8 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                allow_auto_topic_creation: crate::codec::Decode::decode(buf, version)?,
+                include_cluster_authorized_operations: crate::codec::Decode::decode(buf, version)?,
+                include_topic_authorized_operations: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "MetadataRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod metadata_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -2303,64 +4132,48 @@ pub mod metadata_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-        }
+        pub use super::v0::Topics;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-        }
+        pub use super::v0::Topics;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-        }
+        pub use super::v0::Topics;
     }
     pub mod v4 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-        }
+        pub use super::v0::Topics;
     }
     pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-        }
+        pub use super::v0::Topics;
     }
     pub mod v6 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-        }
+        pub use super::v0::Topics;
     }
     pub mod v7 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-        }
+        pub use super::v0::Topics;
     }
     pub mod v8 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-        }
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for metadata_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for metadata_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_request::v0::Topics {
+            name: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum MetadataResponse {
     V0 {
         /// Each broker in the response.
@@ -2458,125 +4271,243 @@ pub enum MetadataResponse {
         /// Each broker in the response.
         brokers: Vec<metadata_response::v8::Brokers>,
         /// The cluster ID that responding broker belongs to.
-        cluster_id: crate::types::NullableString,
-        /// The ID of the controller broker.
-        controller_id: i32,
-        /// Each topic in the response.
-        topics: Vec<metadata_response::v8::Topics>,
-        /// 32-bit bitfield to represent authorized operations for this cluster.
-        cluster_authorized_operations: i32,
-    },
-}
-
-pub mod metadata_response {
-    pub mod v0 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Brokers {
-            /// The broker ID.
-            pub node_id: i32,
-            /// The broker hostname.
-            pub host: String,
-            /// The broker port.
-            pub port: i32,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The topic name.
-            pub name: String,
-            /// Each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The partition index.
-            pub partition_index: i32,
-            /// The ID of the leader broker.
-            pub leader_id: i32,
-            /// The set of all nodes that host this partition.
-            pub replica_nodes: Vec<i32>,
-            /// The set of nodes that are in sync with the leader for this partition.
-            pub isr_nodes: Vec<i32>,
-        }
-    }
-    pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Brokers {
-            /// The broker ID.
-            pub node_id: i32,
-            /// The broker hostname.
-            pub host: String,
-            /// The broker port.
-            pub port: i32,
-            /// The rack of the broker, or null if it has not been assigned to a rack.
-            pub rack: crate::types::NullableString,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The topic name.
-            pub name: String,
-            /// True if the topic is internal.
-            pub is_internal: bool,
-            /// Each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The partition index.
-            pub partition_index: i32,
-            /// The ID of the leader broker.
-            pub leader_id: i32,
-            /// The set of all nodes that host this partition.
-            pub replica_nodes: Vec<i32>,
-            /// The set of nodes that are in sync with the leader for this partition.
-            pub isr_nodes: Vec<i32>,
+        cluster_id: crate::types::NullableString,
+        /// The ID of the controller broker.
+        controller_id: i32,
+        /// Each topic in the response.
+        topics: Vec<metadata_response::v8::Topics>,
+        /// 32-bit bitfield to represent authorized operations for this cluster.
+        cluster_authorized_operations: i32,
+    },
+}
+
+impl crate::versions::ApiVersions for MetadataResponse {
+    const NAME: &'static str = "MetadataResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 8;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("brokers", 0..=8),
+        ("brokers.node_id", 0..=8),
+        ("brokers.host", 0..=8),
+        ("brokers.port", 0..=8),
+        ("topics", 0..=8),
+        ("topics.error_code", 0..=8),
+        ("topics.name", 0..=8),
+        ("topics.partitions", 0..=8),
+        ("topics.partitions.error_code", 0..=8),
+        ("topics.partitions.partition_index", 0..=8),
+        ("topics.partitions.leader_id", 0..=8),
+        ("topics.partitions.replica_nodes", 0..=8),
+        ("topics.partitions.isr_nodes", 0..=8),
+        ("brokers.rack", 1..=8),
+        ("controller_id", 1..=8),
+        ("topics.is_internal", 1..=8),
+        ("cluster_id", 2..=8),
+        ("throttle_time_ms", 3..=8),
+        ("topics.partitions.offline_replicas", 5..=8),
+        ("topics.partitions.leader_epoch", 7..=8),
+        ("topics.topic_authorized_operations", 8..=8),
+        ("cluster_authorized_operations", 8..=8),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            MetadataResponse::V0 { .. } => 0,
+            MetadataResponse::V1 { .. } => 1,
+            MetadataResponse::V2 { .. } => 2,
+            MetadataResponse::V3 { .. } => 3,
+            MetadataResponse::V4 { .. } => 4,
+            MetadataResponse::V5 { .. } => 5,
+            MetadataResponse::V6 { .. } => 6,
+            MetadataResponse::V7 { .. } => 7,
+            MetadataResponse::V8 { .. } => 8,
         }
     }
-    pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Brokers {
-            /// The broker ID.
-            pub node_id: i32,
-            /// The broker hostname.
-            pub host: String,
-            /// The broker port.
-            pub port: i32,
-            /// The rack of the broker, or null if it has not been assigned to a rack.
-            pub rack: crate::types::NullableString,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The topic name.
-            pub name: String,
-            /// True if the topic is internal.
-            pub is_internal: bool,
-            /// Each partition in the topic.
-            pub partitions: Vec<Partitions>,
+}
+
+impl crate::codec::Encode for MetadataResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            MetadataResponse::V0 { brokers, topics } => {
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataResponse::V1 {
+                brokers,
+                controller_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataResponse::V2 {
+                brokers,
+                cluster_id,
+                controller_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(cluster_id, buf);
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataResponse::V3 {
+                throttle_time_ms,
+                brokers,
+                cluster_id,
+                controller_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(cluster_id, buf);
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataResponse::V4 {
+                throttle_time_ms,
+                brokers,
+                cluster_id,
+                controller_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(cluster_id, buf);
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataResponse::V5 {
+                throttle_time_ms,
+                brokers,
+                cluster_id,
+                controller_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(cluster_id, buf);
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataResponse::V6 {
+                throttle_time_ms,
+                brokers,
+                cluster_id,
+                controller_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(cluster_id, buf);
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataResponse::V7 {
+                throttle_time_ms,
+                brokers,
+                cluster_id,
+                controller_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(cluster_id, buf);
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            MetadataResponse::V8 {
+                throttle_time_ms,
+                brokers,
+                cluster_id,
+                controller_id,
+                topics,
+                cluster_authorized_operations,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(brokers, buf);
+                crate::codec::Encode::encode(cluster_id, buf);
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(cluster_authorized_operations, buf);
+            }
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The partition index.
-            pub partition_index: i32,
-            /// The ID of the leader broker.
-            pub leader_id: i32,
-            /// The set of all nodes that host this partition.
-            pub replica_nodes: Vec<i32>,
-            /// The set of nodes that are in sync with the leader for this partition.
-            pub isr_nodes: Vec<i32>,
+    }
+}
+
+impl crate::codec::Decode for MetadataResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(MetadataResponse::V0 {
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(MetadataResponse::V1 {
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(MetadataResponse::V2 {
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                cluster_id: crate::codec::Decode::decode(buf, version)?,
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(MetadataResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                cluster_id: crate::codec::Decode::decode(buf, version)?,
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(MetadataResponse::V4 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                cluster_id: crate::codec::Decode::decode(buf, version)?,
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(MetadataResponse::V5 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                cluster_id: crate::codec::Decode::decode(buf, version)?,
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            6 => Ok(MetadataResponse::V6 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                cluster_id: crate::codec::Decode::decode(buf, version)?,
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            7 => Ok(MetadataResponse::V7 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                cluster_id: crate::codec::Decode::decode(buf, version)?,
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            8 => Ok(MetadataResponse::V8 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                brokers: crate::codec::Decode::decode(buf, version)?,
+                cluster_id: crate::codec::Decode::decode(buf, version)?,
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+                cluster_authorized_operations: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "MetadataResponse", version
+            ))),
         }
     }
-    pub mod v3 {
+}
+
+pub mod metadata_response {
+    pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Brokers {
             /// The broker ID.
@@ -2585,8 +4516,6 @@ pub mod metadata_response {
             pub host: String,
             /// The broker port.
             pub port: i32,
-            /// The rack of the broker, or null if it has not been assigned to a rack.
-            pub rack: crate::types::NullableString,
         }
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
@@ -2594,8 +4523,6 @@ pub mod metadata_response {
             pub error_code: i16,
             /// The topic name.
             pub name: String,
-            /// True if the topic is internal.
-            pub is_internal: bool,
             /// Each partition in the topic.
             pub partitions: Vec<Partitions>,
         }
@@ -2613,7 +4540,8 @@ pub mod metadata_response {
             pub isr_nodes: Vec<i32>,
         }
     }
-    pub mod v4 {
+    pub mod v1 {
+        pub use super::v0::Partitions;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Brokers {
             /// The broker ID.
@@ -2636,32 +4564,24 @@ pub mod metadata_response {
             /// Each partition in the topic.
             pub partitions: Vec<Partitions>,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The partition index.
-            pub partition_index: i32,
-            /// The ID of the leader broker.
-            pub leader_id: i32,
-            /// The set of all nodes that host this partition.
-            pub replica_nodes: Vec<i32>,
-            /// The set of nodes that are in sync with the leader for this partition.
-            pub isr_nodes: Vec<i32>,
-        }
+    }
+    pub mod v2 {
+        pub use super::v0::Partitions;
+        pub use super::v1::Brokers;
+        pub use super::v1::Topics;
+    }
+    pub mod v3 {
+        pub use super::v0::Partitions;
+        pub use super::v1::Brokers;
+        pub use super::v1::Topics;
+    }
+    pub mod v4 {
+        pub use super::v0::Partitions;
+        pub use super::v1::Brokers;
+        pub use super::v1::Topics;
     }
     pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Brokers {
-            /// The broker ID.
-            pub node_id: i32,
-            /// The broker hostname.
-            pub host: String,
-            /// The broker port.
-            pub port: i32,
-            /// The rack of the broker, or null if it has not been assigned to a rack.
-            pub rack: crate::types::NullableString,
-        }
+        pub use super::v1::Brokers;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
             /// The partition error, or 0 if there was no error.
@@ -2690,56 +4610,12 @@ pub mod metadata_response {
         }
     }
     pub mod v6 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Brokers {
-            /// The broker ID.
-            pub node_id: i32,
-            /// The broker hostname.
-            pub host: String,
-            /// The broker port.
-            pub port: i32,
-            /// The rack of the broker, or null if it has not been assigned to a rack.
-            pub rack: crate::types::NullableString,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The topic name.
-            pub name: String,
-            /// True if the topic is internal.
-            pub is_internal: bool,
-            /// Each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The partition index.
-            pub partition_index: i32,
-            /// The ID of the leader broker.
-            pub leader_id: i32,
-            /// The set of all nodes that host this partition.
-            pub replica_nodes: Vec<i32>,
-            /// The set of nodes that are in sync with the leader for this partition.
-            pub isr_nodes: Vec<i32>,
-            /// The set of offline replicas of this partition.
-            pub offline_replicas: Vec<i32>,
-        }
+        pub use super::v1::Brokers;
+        pub use super::v5::Partitions;
+        pub use super::v5::Topics;
     }
     pub mod v7 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Brokers {
-            /// The broker ID.
-            pub node_id: i32,
-            /// The broker hostname.
-            pub host: String,
-            /// The broker port.
-            pub port: i32,
-            /// The rack of the broker, or null if it has not been assigned to a rack.
-            pub rack: crate::types::NullableString,
-        }
+        pub use super::v1::Brokers;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
             /// The partition error, or 0 if there was no error.
@@ -2770,17 +4646,8 @@ pub mod metadata_response {
         }
     }
     pub mod v8 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Brokers {
-            /// The broker ID.
-            pub node_id: i32,
-            /// The broker hostname.
-            pub host: String,
-            /// The broker port.
-            pub port: i32,
-            /// The rack of the broker, or null if it has not been assigned to a rack.
-            pub rack: crate::types::NullableString,
-        }
+        pub use super::v1::Brokers;
+        pub use super::v7::Partitions;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
             /// The partition error, or 0 if there was no error.
@@ -2794,27 +4661,222 @@ pub mod metadata_response {
             /// 32-bit bitfield to represent authorized operations for this topic.
             pub topic_authorized_operations: i32,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The partition index.
-            pub partition_index: i32,
-            /// The ID of the leader broker.
-            pub leader_id: i32,
-            /// The leader epoch of this partition.
-            pub leader_epoch: i32,
-            /// The set of all nodes that host this partition.
-            pub replica_nodes: Vec<i32>,
-            /// The set of nodes that are in sync with the leader for this partition.
-            pub isr_nodes: Vec<i32>,
-            /// The set of offline replicas of this partition.
-            pub offline_replicas: Vec<i32>,
-        }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for metadata_response::v0::Brokers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.node_id, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.port, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v0::Brokers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v0::Brokers {
+            node_id: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            port: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v0::Topics {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.partition_index, buf);
+        crate::codec::Encode::encode(&self.leader_id, buf);
+        crate::codec::Encode::encode(&self.replica_nodes, buf);
+        crate::codec::Encode::encode(&self.isr_nodes, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v0::Partitions {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+            leader_id: crate::codec::Decode::decode(buf, version)?,
+            replica_nodes: crate::codec::Decode::decode(buf, version)?,
+            isr_nodes: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v1::Brokers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.node_id, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.port, buf);
+        crate::codec::Encode::encode(&self.rack, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v1::Brokers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v1::Brokers {
+            node_id: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            port: crate::codec::Decode::decode(buf, version)?,
+            rack: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v1::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.is_internal, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v1::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v1::Topics {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+            is_internal: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v5::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.is_internal, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v5::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v5::Topics {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+            is_internal: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v5::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.partition_index, buf);
+        crate::codec::Encode::encode(&self.leader_id, buf);
+        crate::codec::Encode::encode(&self.replica_nodes, buf);
+        crate::codec::Encode::encode(&self.isr_nodes, buf);
+        crate::codec::Encode::encode(&self.offline_replicas, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v5::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v5::Partitions {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+            leader_id: crate::codec::Decode::decode(buf, version)?,
+            replica_nodes: crate::codec::Decode::decode(buf, version)?,
+            isr_nodes: crate::codec::Decode::decode(buf, version)?,
+            offline_replicas: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v7::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.is_internal, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v7::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v7::Topics {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+            is_internal: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v7::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.partition_index, buf);
+        crate::codec::Encode::encode(&self.leader_id, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.replica_nodes, buf);
+        crate::codec::Encode::encode(&self.isr_nodes, buf);
+        crate::codec::Encode::encode(&self.offline_replicas, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v7::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v7::Partitions {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+            leader_id: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            replica_nodes: crate::codec::Decode::decode(buf, version)?,
+            isr_nodes: crate::codec::Decode::decode(buf, version)?,
+            offline_replicas: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for metadata_response::v8::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.is_internal, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+        crate::codec::Encode::encode(&self.topic_authorized_operations, buf);
+    }
+}
+
+impl crate::codec::Decode for metadata_response::v8::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(metadata_response::v8::Topics {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+            is_internal: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+            topic_authorized_operations: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum LeaderAndIsrRequest {
     V0 {
         /// The controller id
@@ -2850,6 +4912,128 @@ pub enum LeaderAndIsrRequest {
     },
 }
 
+impl crate::versions::ApiVersions for LeaderAndIsrRequest {
+    const NAME: &'static str = "LeaderAndIsrRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("controller_id", 0..=2),
+        ("controller_epoch", 0..=2),
+        ("partition_states", 0..=1),
+        ("partition_states.topic", 0..=1),
+        ("partition_states.partition", 0..=1),
+        ("partition_states.controller_epoch", 0..=1),
+        ("partition_states.leader", 0..=1),
+        ("partition_states.leader_epoch", 0..=1),
+        ("partition_states.isr", 0..=1),
+        ("partition_states.zk_version", 0..=1),
+        ("partition_states.replicas", 0..=1),
+        ("live_leaders", 0..=2),
+        ("live_leaders.id", 0..=2),
+        ("live_leaders.host", 0..=2),
+        ("live_leaders.port", 0..=2),
+        ("partition_states.is_new", 1..=1),
+        ("broker_epoch", 2..=2),
+        ("topic_states", 2..=2),
+        ("topic_states.topic", 2..=2),
+        ("topic_states.partition_states", 2..=2),
+        ("topic_states.partition_states.partition", 2..=2),
+        ("topic_states.partition_states.controller_epoch", 2..=2),
+        ("topic_states.partition_states.leader", 2..=2),
+        ("topic_states.partition_states.leader_epoch", 2..=2),
+        ("topic_states.partition_states.isr", 2..=2),
+        ("topic_states.partition_states.zk_version", 2..=2),
+        ("topic_states.partition_states.replicas", 2..=2),
+        ("topic_states.partition_states.is_new", 2..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            LeaderAndIsrRequest::V0 { .. } => 0,
+            LeaderAndIsrRequest::V1 { .. } => 1,
+            LeaderAndIsrRequest::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::request::Request for LeaderAndIsrRequest {
+    const API_KEY: ApiKey = ApiKey::LeaderAndIsr;
+    type Response = LeaderAndIsrResponse;
+}
+
+impl crate::codec::Encode for LeaderAndIsrRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            LeaderAndIsrRequest::V0 {
+                controller_id,
+                controller_epoch,
+                partition_states,
+                live_leaders,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(partition_states, buf);
+                crate::codec::Encode::encode(live_leaders, buf);
+            }
+            LeaderAndIsrRequest::V1 {
+                controller_id,
+                controller_epoch,
+                partition_states,
+                live_leaders,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(partition_states, buf);
+                crate::codec::Encode::encode(live_leaders, buf);
+            }
+            LeaderAndIsrRequest::V2 {
+                controller_id,
+                controller_epoch,
+                broker_epoch,
+                topic_states,
+                live_leaders,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(broker_epoch, buf);
+                crate::codec::Encode::encode(topic_states, buf);
+                crate::codec::Encode::encode(live_leaders, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for LeaderAndIsrRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(LeaderAndIsrRequest::V0 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                partition_states: crate::codec::Decode::decode(buf, version)?,
+                live_leaders: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(LeaderAndIsrRequest::V1 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                partition_states: crate::codec::Decode::decode(buf, version)?,
+                live_leaders: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(LeaderAndIsrRequest::V2 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                broker_epoch: crate::codec::Decode::decode(buf, version)?,
+                topic_states: crate::codec::Decode::decode(buf, version)?,
+                live_leaders: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "LeaderAndIsrRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod leader_and_isr_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -2882,6 +5066,7 @@ pub mod leader_and_isr_request {
         }
     }
     pub mod v1 {
+        pub use super::v0::LiveLeaders;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct PartitionStates {
             /// Name of topic
@@ -2899,21 +5084,13 @@ pub mod leader_and_isr_request {
             /// The ZK version.
             pub zk_version: i32,
             /// The replica ids.
-            pub replicas: Vec<i32>,
-            /// Whether the replica should have existed on the broker or not
-            pub is_new: bool,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct LiveLeaders {
-            /// The broker id
-            pub id: i32,
-            /// The hostname of the broker.
-            pub host: String,
-            /// The port on which the broker accepts requests.
-            pub port: i32,
+            pub replicas: Vec<i32>,
+            /// Whether the replica should have existed on the broker or not
+            pub is_new: bool,
         }
     }
     pub mod v2 {
+        pub use super::v0::LiveLeaders;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct TopicStates {
             /// Name of topic
@@ -2922,15 +5099,6 @@ pub mod leader_and_isr_request {
             pub partition_states: Vec<PartitionStates>,
         }
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct LiveLeaders {
-            /// The broker id
-            pub id: i32,
-            /// The hostname of the broker.
-            pub host: String,
-            /// The port on which the broker accepts requests.
-            pub port: i32,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct PartitionStates {
             /// Topic partition id
             pub partition: i32,
@@ -2952,7 +5120,129 @@ pub mod leader_and_isr_request {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for leader_and_isr_request::v0::PartitionStates {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.controller_epoch, buf);
+        crate::codec::Encode::encode(&self.leader, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.isr, buf);
+        crate::codec::Encode::encode(&self.zk_version, buf);
+        crate::codec::Encode::encode(&self.replicas, buf);
+    }
+}
+
+impl crate::codec::Decode for leader_and_isr_request::v0::PartitionStates {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(leader_and_isr_request::v0::PartitionStates {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+            controller_epoch: crate::codec::Decode::decode(buf, version)?,
+            leader: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            isr: crate::codec::Decode::decode(buf, version)?,
+            zk_version: crate::codec::Decode::decode(buf, version)?,
+            replicas: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for leader_and_isr_request::v0::LiveLeaders {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.id, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.port, buf);
+    }
+}
+
+impl crate::codec::Decode for leader_and_isr_request::v0::LiveLeaders {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(leader_and_isr_request::v0::LiveLeaders {
+            id: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            port: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for leader_and_isr_request::v1::PartitionStates {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.controller_epoch, buf);
+        crate::codec::Encode::encode(&self.leader, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.isr, buf);
+        crate::codec::Encode::encode(&self.zk_version, buf);
+        crate::codec::Encode::encode(&self.replicas, buf);
+        crate::codec::Encode::encode(&self.is_new, buf);
+    }
+}
+
+impl crate::codec::Decode for leader_and_isr_request::v1::PartitionStates {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(leader_and_isr_request::v1::PartitionStates {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+            controller_epoch: crate::codec::Decode::decode(buf, version)?,
+            leader: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            isr: crate::codec::Decode::decode(buf, version)?,
+            zk_version: crate::codec::Decode::decode(buf, version)?,
+            replicas: crate::codec::Decode::decode(buf, version)?,
+            is_new: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for leader_and_isr_request::v2::TopicStates {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_states, buf);
+    }
+}
+
+impl crate::codec::Decode for leader_and_isr_request::v2::TopicStates {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(leader_and_isr_request::v2::TopicStates {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_states: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for leader_and_isr_request::v2::PartitionStates {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.controller_epoch, buf);
+        crate::codec::Encode::encode(&self.leader, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.isr, buf);
+        crate::codec::Encode::encode(&self.zk_version, buf);
+        crate::codec::Encode::encode(&self.replicas, buf);
+        crate::codec::Encode::encode(&self.is_new, buf);
+    }
+}
+
+impl crate::codec::Decode for leader_and_isr_request::v2::PartitionStates {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(leader_and_isr_request::v2::PartitionStates {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            controller_epoch: crate::codec::Decode::decode(buf, version)?,
+            leader: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            isr: crate::codec::Decode::decode(buf, version)?,
+            zk_version: crate::codec::Decode::decode(buf, version)?,
+            replicas: crate::codec::Decode::decode(buf, version)?,
+            is_new: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum LeaderAndIsrResponse {
     V0 {
         /// Response error code
@@ -2974,6 +5264,79 @@ pub enum LeaderAndIsrResponse {
     },
 }
 
+impl crate::versions::ApiVersions for LeaderAndIsrResponse {
+    const NAME: &'static str = "LeaderAndIsrResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=2),
+        ("partitions", 0..=2),
+        ("partitions.topic", 0..=2),
+        ("partitions.partition", 0..=2),
+        ("partitions.error_code", 0..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            LeaderAndIsrResponse::V0 { .. } => 0,
+            LeaderAndIsrResponse::V1 { .. } => 1,
+            LeaderAndIsrResponse::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::codec::Encode for LeaderAndIsrResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            LeaderAndIsrResponse::V0 {
+                error_code,
+                partitions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(partitions, buf);
+            }
+            LeaderAndIsrResponse::V1 {
+                error_code,
+                partitions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(partitions, buf);
+            }
+            LeaderAndIsrResponse::V2 {
+                error_code,
+                partitions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(partitions, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for LeaderAndIsrResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(LeaderAndIsrResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(LeaderAndIsrResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(LeaderAndIsrResponse::V2 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "LeaderAndIsrResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod leader_and_isr_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -2987,30 +5350,34 @@ pub mod leader_and_isr_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Name of topic
-            pub topic: String,
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Name of topic
-            pub topic: String,
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+    }
+}
+
+impl crate::codec::Encode for leader_and_isr_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for leader_and_isr_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(leader_and_isr_response::v0::Partitions {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum StopReplicaRequest {
     V0 {
         /// The controller id
@@ -3036,6 +5403,90 @@ pub enum StopReplicaRequest {
     },
 }
 
+impl crate::versions::ApiVersions for StopReplicaRequest {
+    const NAME: &'static str = "StopReplicaRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("controller_id", 0..=1),
+        ("controller_epoch", 0..=1),
+        ("delete_partitions", 0..=1),
+        ("partitions", 0..=1),
+        ("partitions.topic", 0..=1),
+        ("partitions.partition", 0..=0),
+        ("broker_epoch", 1..=1),
+        ("partitions.partition_ids", 1..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            StopReplicaRequest::V0 { .. } => 0,
+            StopReplicaRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for StopReplicaRequest {
+    const API_KEY: ApiKey = ApiKey::StopReplica;
+    type Response = StopReplicaResponse;
+}
+
+impl crate::codec::Encode for StopReplicaRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            StopReplicaRequest::V0 {
+                controller_id,
+                controller_epoch,
+                delete_partitions,
+                partitions,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(delete_partitions, buf);
+                crate::codec::Encode::encode(partitions, buf);
+            }
+            StopReplicaRequest::V1 {
+                controller_id,
+                controller_epoch,
+                broker_epoch,
+                delete_partitions,
+                partitions,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(broker_epoch, buf);
+                crate::codec::Encode::encode(delete_partitions, buf);
+                crate::codec::Encode::encode(partitions, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for StopReplicaRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(StopReplicaRequest::V0 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                delete_partitions: crate::codec::Decode::decode(buf, version)?,
+                partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(StopReplicaRequest::V1 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                broker_epoch: crate::codec::Decode::decode(buf, version)?,
+                delete_partitions: crate::codec::Decode::decode(buf, version)?,
+                partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "StopReplicaRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod stop_replica_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -3057,7 +5508,41 @@ pub mod stop_replica_request {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for stop_replica_request::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+    }
+}
+
+impl crate::codec::Decode for stop_replica_request::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(stop_replica_request::v0::Partitions {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for stop_replica_request::v1::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_ids, buf);
+    }
+}
+
+impl crate::codec::Decode for stop_replica_request::v1::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(stop_replica_request::v1::Partitions {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_ids: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum StopReplicaResponse {
     V0 {
         /// Response error code
@@ -3073,6 +5558,67 @@ pub enum StopReplicaResponse {
     },
 }
 
+impl crate::versions::ApiVersions for StopReplicaResponse {
+    const NAME: &'static str = "StopReplicaResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=1),
+        ("partitions", 0..=1),
+        ("partitions.topic", 0..=1),
+        ("partitions.partition", 0..=1),
+        ("partitions.error_code", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            StopReplicaResponse::V0 { .. } => 0,
+            StopReplicaResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for StopReplicaResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            StopReplicaResponse::V0 {
+                error_code,
+                partitions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(partitions, buf);
+            }
+            StopReplicaResponse::V1 {
+                error_code,
+                partitions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(partitions, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for StopReplicaResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(StopReplicaResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(StopReplicaResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "StopReplicaResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod stop_replica_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -3086,19 +5632,31 @@ pub mod stop_replica_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Name of topic
-            pub topic: String,
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+    }
+}
+
+impl crate::codec::Encode for stop_replica_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for stop_replica_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(stop_replica_response::v0::Partitions {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum UpdateMetadataRequest {
     V0 {
         /// The controller id
@@ -3164,6 +5722,188 @@ pub enum UpdateMetadataRequest {
     },
 }
 
+impl crate::versions::ApiVersions for UpdateMetadataRequest {
+    const NAME: &'static str = "UpdateMetadataRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 5;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("controller_id", 0..=5),
+        ("controller_epoch", 0..=5),
+        ("partition_states", 0..=4),
+        ("partition_states.topic", 0..=4),
+        ("partition_states.partition", 0..=4),
+        ("partition_states.controller_epoch", 0..=4),
+        ("partition_states.leader", 0..=4),
+        ("partition_states.leader_epoch", 0..=4),
+        ("partition_states.isr", 0..=4),
+        ("partition_states.zk_version", 0..=4),
+        ("partition_states.replicas", 0..=4),
+        ("live_brokers", 0..=5),
+        ("live_brokers.id", 0..=5),
+        ("live_brokers.host", 0..=0),
+        ("live_brokers.port", 0..=0),
+        ("live_brokers.end_points", 1..=5),
+        ("live_brokers.end_points.port", 1..=5),
+        ("live_brokers.end_points.host", 1..=5),
+        ("live_brokers.end_points.security_protocol_type", 1..=5),
+        ("live_brokers.rack", 2..=5),
+        ("live_brokers.end_points.listener_name", 3..=5),
+        ("partition_states.offline_replicas", 4..=4),
+        ("broker_epoch", 5..=5),
+        ("topic_states", 5..=5),
+        ("topic_states.topic", 5..=5),
+        ("topic_states.partition_states", 5..=5),
+        ("topic_states.partition_states.partition", 5..=5),
+        ("topic_states.partition_states.controller_epoch", 5..=5),
+        ("topic_states.partition_states.leader", 5..=5),
+        ("topic_states.partition_states.leader_epoch", 5..=5),
+        ("topic_states.partition_states.isr", 5..=5),
+        ("topic_states.partition_states.zk_version", 5..=5),
+        ("topic_states.partition_states.replicas", 5..=5),
+        ("topic_states.partition_states.offline_replicas", 5..=5),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            UpdateMetadataRequest::V0 { .. } => 0,
+            UpdateMetadataRequest::V1 { .. } => 1,
+            UpdateMetadataRequest::V2 { .. } => 2,
+            UpdateMetadataRequest::V3 { .. } => 3,
+            UpdateMetadataRequest::V4 { .. } => 4,
+            UpdateMetadataRequest::V5 { .. } => 5,
+        }
+    }
+}
+
+impl crate::request::Request for UpdateMetadataRequest {
+    const API_KEY: ApiKey = ApiKey::UpdateMetadata;
+    type Response = UpdateMetadataResponse;
+}
+
+impl crate::codec::Encode for UpdateMetadataRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            UpdateMetadataRequest::V0 {
+                controller_id,
+                controller_epoch,
+                partition_states,
+                live_brokers,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(partition_states, buf);
+                crate::codec::Encode::encode(live_brokers, buf);
+            }
+            UpdateMetadataRequest::V1 {
+                controller_id,
+                controller_epoch,
+                partition_states,
+                live_brokers,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(partition_states, buf);
+                crate::codec::Encode::encode(live_brokers, buf);
+            }
+            UpdateMetadataRequest::V2 {
+                controller_id,
+                controller_epoch,
+                partition_states,
+                live_brokers,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(partition_states, buf);
+                crate::codec::Encode::encode(live_brokers, buf);
+            }
+            UpdateMetadataRequest::V3 {
+                controller_id,
+                controller_epoch,
+                partition_states,
+                live_brokers,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(partition_states, buf);
+                crate::codec::Encode::encode(live_brokers, buf);
+            }
+            UpdateMetadataRequest::V4 {
+                controller_id,
+                controller_epoch,
+                partition_states,
+                live_brokers,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(partition_states, buf);
+                crate::codec::Encode::encode(live_brokers, buf);
+            }
+            UpdateMetadataRequest::V5 {
+                controller_id,
+                controller_epoch,
+                broker_epoch,
+                topic_states,
+                live_brokers,
+            } => {
+                crate::codec::Encode::encode(controller_id, buf);
+                crate::codec::Encode::encode(controller_epoch, buf);
+                crate::codec::Encode::encode(broker_epoch, buf);
+                crate::codec::Encode::encode(topic_states, buf);
+                crate::codec::Encode::encode(live_brokers, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for UpdateMetadataRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(UpdateMetadataRequest::V0 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                partition_states: crate::codec::Decode::decode(buf, version)?,
+                live_brokers: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(UpdateMetadataRequest::V1 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                partition_states: crate::codec::Decode::decode(buf, version)?,
+                live_brokers: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(UpdateMetadataRequest::V2 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                partition_states: crate::codec::Decode::decode(buf, version)?,
+                live_brokers: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(UpdateMetadataRequest::V3 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                partition_states: crate::codec::Decode::decode(buf, version)?,
+                live_brokers: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(UpdateMetadataRequest::V4 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                partition_states: crate::codec::Decode::decode(buf, version)?,
+                live_brokers: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(UpdateMetadataRequest::V5 {
+                controller_id: crate::codec::Decode::decode(buf, version)?,
+                controller_epoch: crate::codec::Decode::decode(buf, version)?,
+                broker_epoch: crate::codec::Decode::decode(buf, version)?,
+                topic_states: crate::codec::Decode::decode(buf, version)?,
+                live_brokers: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "UpdateMetadataRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod update_metadata_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -3196,25 +5936,7 @@ pub mod update_metadata_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionStates {
-            /// Name of topic
-            pub topic: String,
-            /// Topic partition id
-            pub partition: i32,
-            /// The controller epoch
-            pub controller_epoch: i32,
-            /// The broker id for the leader.
-            pub leader: i32,
-            /// The leader epoch.
-            pub leader_epoch: i32,
-            /// The in sync replica ids.
-            pub isr: Vec<i32>,
-            /// The ZK version.
-            pub zk_version: i32,
-            /// The replica ids.
-            pub replicas: Vec<i32>,
-        }
+        pub use super::v0::PartitionStates;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct LiveBrokers {
             /// The broker id
@@ -3233,25 +5955,8 @@ pub mod update_metadata_request {
         }
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionStates {
-            /// Name of topic
-            pub topic: String,
-            /// Topic partition id
-            pub partition: i32,
-            /// The controller epoch
-            pub controller_epoch: i32,
-            /// The broker id for the leader.
-            pub leader: i32,
-            /// The leader epoch.
-            pub leader_epoch: i32,
-            /// The in sync replica ids.
-            pub isr: Vec<i32>,
-            /// The ZK version.
-            pub zk_version: i32,
-            /// The replica ids.
-            pub replicas: Vec<i32>,
-        }
+        pub use super::v0::PartitionStates;
+        pub use super::v1::EndPoints;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct LiveBrokers {
             /// The broker id
@@ -3261,36 +5966,9 @@ pub mod update_metadata_request {
             /// The rack
             pub rack: crate::types::NullableString,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct EndPoints {
-            /// The port on which the broker accepts requests.
-            pub port: i32,
-            /// The hostname of the broker.
-            pub host: String,
-            /// The security protocol type.
-            pub security_protocol_type: i16,
-        }
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionStates {
-            /// Name of topic
-            pub topic: String,
-            /// Topic partition id
-            pub partition: i32,
-            /// The controller epoch
-            pub controller_epoch: i32,
-            /// The broker id for the leader.
-            pub leader: i32,
-            /// The leader epoch.
-            pub leader_epoch: i32,
-            /// The in sync replica ids.
-            pub isr: Vec<i32>,
-            /// The ZK version.
-            pub zk_version: i32,
-            /// The replica ids.
-            pub replicas: Vec<i32>,
-        }
+        pub use super::v0::PartitionStates;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct LiveBrokers {
             /// The broker id
@@ -3313,6 +5991,8 @@ pub mod update_metadata_request {
         }
     }
     pub mod v4 {
+        pub use super::v3::EndPoints;
+        pub use super::v3::LiveBrokers;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct PartitionStates {
             /// Name of topic
@@ -3320,42 +6000,24 @@ pub mod update_metadata_request {
             /// Topic partition id
             pub partition: i32,
             /// The controller epoch
-            pub controller_epoch: i32,
-            /// The broker id for the leader.
-            pub leader: i32,
-            /// The leader epoch.
-            pub leader_epoch: i32,
-            /// The in sync replica ids.
-            pub isr: Vec<i32>,
-            /// The ZK version.
-            pub zk_version: i32,
-            /// The replica ids.
-            pub replicas: Vec<i32>,
-            /// The offline replica ids
-            pub offline_replicas: Vec<i32>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct LiveBrokers {
-            /// The broker id
-            pub id: i32,
-            /// The endpoints
-            pub end_points: Vec<EndPoints>,
-            /// The rack
-            pub rack: crate::types::NullableString,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct EndPoints {
-            /// The port on which the broker accepts requests.
-            pub port: i32,
-            /// The hostname of the broker.
-            pub host: String,
-            /// The listener name.
-            pub listener_name: String,
-            /// The security protocol type.
-            pub security_protocol_type: i16,
+            pub controller_epoch: i32,
+            /// The broker id for the leader.
+            pub leader: i32,
+            /// The leader epoch.
+            pub leader_epoch: i32,
+            /// The in sync replica ids.
+            pub isr: Vec<i32>,
+            /// The ZK version.
+            pub zk_version: i32,
+            /// The replica ids.
+            pub replicas: Vec<i32>,
+            /// The offline replica ids
+            pub offline_replicas: Vec<i32>,
         }
     }
     pub mod v5 {
+        pub use super::v3::EndPoints;
+        pub use super::v3::LiveBrokers;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct TopicStates {
             /// Name of topic
@@ -3364,15 +6026,6 @@ pub mod update_metadata_request {
             pub partition_states: Vec<PartitionStates>,
         }
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct LiveBrokers {
-            /// The broker id
-            pub id: i32,
-            /// The endpoints
-            pub end_points: Vec<EndPoints>,
-            /// The rack
-            pub rack: crate::types::NullableString,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct PartitionStates {
             /// Topic partition id
             pub partition: i32,
@@ -3391,21 +6044,222 @@ pub mod update_metadata_request {
             /// The offline replica ids
             pub offline_replicas: Vec<i32>,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct EndPoints {
-            /// The port on which the broker accepts requests.
-            pub port: i32,
-            /// The hostname of the broker.
-            pub host: String,
-            /// The listener name.
-            pub listener_name: String,
-            /// The security protocol type.
-            pub security_protocol_type: i16,
-        }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for update_metadata_request::v0::PartitionStates {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.controller_epoch, buf);
+        crate::codec::Encode::encode(&self.leader, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.isr, buf);
+        crate::codec::Encode::encode(&self.zk_version, buf);
+        crate::codec::Encode::encode(&self.replicas, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v0::PartitionStates {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v0::PartitionStates {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+            controller_epoch: crate::codec::Decode::decode(buf, version)?,
+            leader: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            isr: crate::codec::Decode::decode(buf, version)?,
+            zk_version: crate::codec::Decode::decode(buf, version)?,
+            replicas: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v0::LiveBrokers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.id, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.port, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v0::LiveBrokers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v0::LiveBrokers {
+            id: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            port: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v1::LiveBrokers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.id, buf);
+        crate::codec::Encode::encode(&self.end_points, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v1::LiveBrokers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v1::LiveBrokers {
+            id: crate::codec::Decode::decode(buf, version)?,
+            end_points: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v1::EndPoints {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.port, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.security_protocol_type, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v1::EndPoints {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v1::EndPoints {
+            port: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            security_protocol_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v2::LiveBrokers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.id, buf);
+        crate::codec::Encode::encode(&self.end_points, buf);
+        crate::codec::Encode::encode(&self.rack, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v2::LiveBrokers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v2::LiveBrokers {
+            id: crate::codec::Decode::decode(buf, version)?,
+            end_points: crate::codec::Decode::decode(buf, version)?,
+            rack: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v3::LiveBrokers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.id, buf);
+        crate::codec::Encode::encode(&self.end_points, buf);
+        crate::codec::Encode::encode(&self.rack, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v3::LiveBrokers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v3::LiveBrokers {
+            id: crate::codec::Decode::decode(buf, version)?,
+            end_points: crate::codec::Decode::decode(buf, version)?,
+            rack: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v3::EndPoints {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.port, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.listener_name, buf);
+        crate::codec::Encode::encode(&self.security_protocol_type, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v3::EndPoints {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v3::EndPoints {
+            port: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            listener_name: crate::codec::Decode::decode(buf, version)?,
+            security_protocol_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v4::PartitionStates {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.controller_epoch, buf);
+        crate::codec::Encode::encode(&self.leader, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.isr, buf);
+        crate::codec::Encode::encode(&self.zk_version, buf);
+        crate::codec::Encode::encode(&self.replicas, buf);
+        crate::codec::Encode::encode(&self.offline_replicas, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v4::PartitionStates {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v4::PartitionStates {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+            controller_epoch: crate::codec::Decode::decode(buf, version)?,
+            leader: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            isr: crate::codec::Decode::decode(buf, version)?,
+            zk_version: crate::codec::Decode::decode(buf, version)?,
+            replicas: crate::codec::Decode::decode(buf, version)?,
+            offline_replicas: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v5::TopicStates {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_states, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v5::TopicStates {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v5::TopicStates {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_states: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for update_metadata_request::v5::PartitionStates {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.controller_epoch, buf);
+        crate::codec::Encode::encode(&self.leader, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.isr, buf);
+        crate::codec::Encode::encode(&self.zk_version, buf);
+        crate::codec::Encode::encode(&self.replicas, buf);
+        crate::codec::Encode::encode(&self.offline_replicas, buf);
+    }
+}
+
+impl crate::codec::Decode for update_metadata_request::v5::PartitionStates {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(update_metadata_request::v5::PartitionStates {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            controller_epoch: crate::codec::Decode::decode(buf, version)?,
+            leader: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            isr: crate::codec::Decode::decode(buf, version)?,
+            zk_version: crate::codec::Decode::decode(buf, version)?,
+            replicas: crate::codec::Decode::decode(buf, version)?,
+            offline_replicas: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum UpdateMetadataResponse {
     V0 {
         /// Response error code
@@ -3433,7 +6287,83 @@ pub enum UpdateMetadataResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for UpdateMetadataResponse {
+    const NAME: &'static str = "UpdateMetadataResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 5;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("error_code", 0..=5)];
+
+    fn version(&self) -> i16 {
+        match self {
+            UpdateMetadataResponse::V0 { .. } => 0,
+            UpdateMetadataResponse::V1 { .. } => 1,
+            UpdateMetadataResponse::V2 { .. } => 2,
+            UpdateMetadataResponse::V3 { .. } => 3,
+            UpdateMetadataResponse::V4 { .. } => 4,
+            UpdateMetadataResponse::V5 { .. } => 5,
+        }
+    }
+}
+
+impl crate::codec::Encode for UpdateMetadataResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            UpdateMetadataResponse::V0 { error_code } => {
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            UpdateMetadataResponse::V1 { error_code } => {
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            UpdateMetadataResponse::V2 { error_code } => {
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            UpdateMetadataResponse::V3 { error_code } => {
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            UpdateMetadataResponse::V4 { error_code } => {
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            UpdateMetadataResponse::V5 { error_code } => {
+                crate::codec::Encode::encode(error_code, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for UpdateMetadataResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(UpdateMetadataResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(UpdateMetadataResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(UpdateMetadataResponse::V2 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(UpdateMetadataResponse::V3 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(UpdateMetadataResponse::V4 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(UpdateMetadataResponse::V5 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "UpdateMetadataResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ControlledShutdownRequest {
     V0 {
         /// The id of the broker for which controlled shutdown has been requested.
@@ -3451,7 +6381,72 @@ pub enum ControlledShutdownRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for ControlledShutdownRequest {
+    const NAME: &'static str = "ControlledShutdownRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("broker_id", 0..=2), ("broker_epoch", 2..=2)];
+
+    fn version(&self) -> i16 {
+        match self {
+            ControlledShutdownRequest::V0 { .. } => 0,
+            ControlledShutdownRequest::V1 { .. } => 1,
+            ControlledShutdownRequest::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::request::Request for ControlledShutdownRequest {
+    const API_KEY: ApiKey = ApiKey::ControlledShutdown;
+    type Response = ControlledShutdownResponse;
+}
+
+impl crate::codec::Encode for ControlledShutdownRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ControlledShutdownRequest::V0 { broker_id } => {
+                crate::codec::Encode::encode(broker_id, buf);
+            }
+            ControlledShutdownRequest::V1 { broker_id } => {
+                crate::codec::Encode::encode(broker_id, buf);
+            }
+            ControlledShutdownRequest::V2 {
+                broker_id,
+                broker_epoch,
+            } => {
+                crate::codec::Encode::encode(broker_id, buf);
+                crate::codec::Encode::encode(broker_epoch, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ControlledShutdownRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ControlledShutdownRequest::V0 {
+                broker_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ControlledShutdownRequest::V1 {
+                broker_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(ControlledShutdownRequest::V2 {
+                broker_id: crate::codec::Decode::decode(buf, version)?,
+                broker_epoch: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ControlledShutdownRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ControlledShutdownResponse {
     V0 {
         /// The top-level error code.
@@ -3473,6 +6468,78 @@ pub enum ControlledShutdownResponse {
     },
 }
 
+impl crate::versions::ApiVersions for ControlledShutdownResponse {
+    const NAME: &'static str = "ControlledShutdownResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=2),
+        ("remaining_partitions", 0..=2),
+        ("remaining_partitions.topic_name", 0..=2),
+        ("remaining_partitions.partition_index", 0..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ControlledShutdownResponse::V0 { .. } => 0,
+            ControlledShutdownResponse::V1 { .. } => 1,
+            ControlledShutdownResponse::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::codec::Encode for ControlledShutdownResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ControlledShutdownResponse::V0 {
+                error_code,
+                remaining_partitions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(remaining_partitions, buf);
+            }
+            ControlledShutdownResponse::V1 {
+                error_code,
+                remaining_partitions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(remaining_partitions, buf);
+            }
+            ControlledShutdownResponse::V2 {
+                error_code,
+                remaining_partitions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(remaining_partitions, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ControlledShutdownResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ControlledShutdownResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                remaining_partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ControlledShutdownResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                remaining_partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(ControlledShutdownResponse::V2 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                remaining_partitions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ControlledShutdownResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod controlled_shutdown_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -3484,26 +6551,32 @@ pub mod controlled_shutdown_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct RemainingPartitions {
-            /// The name of the topic.
-            pub topic_name: String,
-            /// The index of the partition.
-            pub partition_index: i32,
-        }
+        pub use super::v0::RemainingPartitions;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct RemainingPartitions {
-            /// The name of the topic.
-            pub topic_name: String,
-            /// The index of the partition.
-            pub partition_index: i32,
-        }
+        pub use super::v0::RemainingPartitions;
+    }
+}
+
+impl crate::codec::Encode for controlled_shutdown_response::v0::RemainingPartitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic_name, buf);
+        crate::codec::Encode::encode(&self.partition_index, buf);
+    }
+}
+
+impl crate::codec::Decode for controlled_shutdown_response::v0::RemainingPartitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(controlled_shutdown_response::v0::RemainingPartitions {
+            topic_name: crate::codec::Decode::decode(buf, version)?,
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum OffsetCommitRequest {
     V0 {
         /// The unique group identifier.
@@ -3591,100 +6664,205 @@ pub enum OffsetCommitRequest {
     },
 }
 
-pub mod offset_commit_request {
-    pub mod v0 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// Each partition to commit offsets for.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The message offset to be committed.
-            pub committed_offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub committed_metadata: crate::types::NullableString,
-        }
-    }
-    pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// Each partition to commit offsets for.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The message offset to be committed.
-            pub committed_offset: i64,
-            /// The timestamp of the commit.
-            pub commit_timestamp: i64,
-            /// Any associated metadata the client wants to keep.
-            pub committed_metadata: crate::types::NullableString,
-        }
-    }
-    pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// Each partition to commit offsets for.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The message offset to be committed.
-            pub committed_offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub committed_metadata: crate::types::NullableString,
+impl crate::versions::ApiVersions for OffsetCommitRequest {
+    const NAME: &'static str = "OffsetCommitRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 7;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("group_id", 0..=7),
+        ("topics", 0..=7),
+        ("topics.name", 0..=7),
+        ("topics.partitions", 0..=7),
+        ("topics.partitions.partition_index", 0..=7),
+        ("topics.partitions.committed_offset", 0..=7),
+        ("topics.partitions.committed_metadata", 0..=7),
+        ("generation_id", 1..=7),
+        ("member_id", 1..=7),
+        ("topics.partitions.commit_timestamp", 1..=1),
+        ("retention_time_ms", 2..=4),
+        ("topics.partitions.committed_leader_epoch", 6..=7),
+        ("group_instance_id", 7..=7),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            OffsetCommitRequest::V0 { .. } => 0,
+            OffsetCommitRequest::V1 { .. } => 1,
+            OffsetCommitRequest::V2 { .. } => 2,
+            OffsetCommitRequest::V3 { .. } => 3,
+            OffsetCommitRequest::V4 { .. } => 4,
+            OffsetCommitRequest::V5 { .. } => 5,
+            OffsetCommitRequest::V6 { .. } => 6,
+            OffsetCommitRequest::V7 { .. } => 7,
         }
     }
-    pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// Each partition to commit offsets for.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The message offset to be committed.
-            pub committed_offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub committed_metadata: crate::types::NullableString,
+}
+
+impl crate::request::Request for OffsetCommitRequest {
+    const API_KEY: ApiKey = ApiKey::OffsetCommit;
+    type Response = OffsetCommitResponse;
+}
+
+impl crate::codec::Encode for OffsetCommitRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            OffsetCommitRequest::V0 { group_id, topics } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitRequest::V1 {
+                group_id,
+                generation_id,
+                member_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitRequest::V2 {
+                group_id,
+                generation_id,
+                member_id,
+                retention_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(retention_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitRequest::V3 {
+                group_id,
+                generation_id,
+                member_id,
+                retention_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(retention_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitRequest::V4 {
+                group_id,
+                generation_id,
+                member_id,
+                retention_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(retention_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitRequest::V5 {
+                group_id,
+                generation_id,
+                member_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitRequest::V6 {
+                group_id,
+                generation_id,
+                member_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitRequest::V7 {
+                group_id,
+                generation_id,
+                member_id,
+                group_instance_id,
+                topics,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(group_instance_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
         }
     }
-    pub mod v4 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// Each partition to commit offsets for.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The message offset to be committed.
-            pub committed_offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub committed_metadata: crate::types::NullableString,
+}
+
+impl crate::codec::Decode for OffsetCommitRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(OffsetCommitRequest::V0 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(OffsetCommitRequest::V1 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(OffsetCommitRequest::V2 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                retention_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(OffsetCommitRequest::V3 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                retention_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(OffsetCommitRequest::V4 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                retention_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(OffsetCommitRequest::V5 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            6 => Ok(OffsetCommitRequest::V6 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            7 => Ok(OffsetCommitRequest::V7 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                group_instance_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "OffsetCommitRequest", version
+            ))),
         }
     }
-    pub mod v5 {
+}
+
+pub mod offset_commit_request {
+    pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
             /// The topic name.
@@ -3702,7 +6880,7 @@ pub mod offset_commit_request {
             pub committed_metadata: crate::types::NullableString,
         }
     }
-    pub mod v6 {
+    pub mod v1 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
             /// The topic name.
@@ -3716,13 +6894,29 @@ pub mod offset_commit_request {
             pub partition_index: i32,
             /// The message offset to be committed.
             pub committed_offset: i64,
-            /// The leader epoch of this partition.
-            pub committed_leader_epoch: i32,
+            /// The timestamp of the commit.
+            pub commit_timestamp: i64,
             /// Any associated metadata the client wants to keep.
             pub committed_metadata: crate::types::NullableString,
         }
     }
-    pub mod v7 {
+    pub mod v2 {
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+    pub mod v3 {
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+    pub mod v4 {
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+    pub mod v5 {
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+    pub mod v6 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
             /// The topic name.
@@ -3742,9 +6936,121 @@ pub mod offset_commit_request {
             pub committed_metadata: crate::types::NullableString,
         }
     }
+    pub mod v7 {
+        pub use super::v6::Partitions;
+        pub use super::v6::Topics;
+    }
+}
+
+impl crate::codec::Encode for offset_commit_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_commit_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_commit_request::v0::Topics {
+            name: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_commit_request::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_index, buf);
+        crate::codec::Encode::encode(&self.committed_offset, buf);
+        crate::codec::Encode::encode(&self.committed_metadata, buf);
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for offset_commit_request::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_commit_request::v0::Partitions {
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+            committed_offset: crate::codec::Decode::decode(buf, version)?,
+            committed_metadata: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_commit_request::v1::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_commit_request::v1::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_commit_request::v1::Topics {
+            name: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_commit_request::v1::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_index, buf);
+        crate::codec::Encode::encode(&self.committed_offset, buf);
+        crate::codec::Encode::encode(&self.commit_timestamp, buf);
+        crate::codec::Encode::encode(&self.committed_metadata, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_commit_request::v1::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_commit_request::v1::Partitions {
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+            committed_offset: crate::codec::Decode::decode(buf, version)?,
+            commit_timestamp: crate::codec::Decode::decode(buf, version)?,
+            committed_metadata: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_commit_request::v6::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_commit_request::v6::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_commit_request::v6::Topics {
+            name: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_commit_request::v6::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_index, buf);
+        crate::codec::Encode::encode(&self.committed_offset, buf);
+        crate::codec::Encode::encode(&self.committed_leader_epoch, buf);
+        crate::codec::Encode::encode(&self.committed_metadata, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_commit_request::v6::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_commit_request::v6::Partitions {
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+            committed_offset: crate::codec::Decode::decode(buf, version)?,
+            committed_leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            committed_metadata: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum OffsetCommitResponse {
     V0 {
         /// The responses for each topic.
@@ -3795,6 +7101,125 @@ pub enum OffsetCommitResponse {
     },
 }
 
+impl crate::versions::ApiVersions for OffsetCommitResponse {
+    const NAME: &'static str = "OffsetCommitResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 7;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topics", 0..=7),
+        ("topics.name", 0..=7),
+        ("topics.partitions", 0..=7),
+        ("topics.partitions.partition_index", 0..=7),
+        ("topics.partitions.error_code", 0..=7),
+        ("throttle_time_ms", 3..=7),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            OffsetCommitResponse::V0 { .. } => 0,
+            OffsetCommitResponse::V1 { .. } => 1,
+            OffsetCommitResponse::V2 { .. } => 2,
+            OffsetCommitResponse::V3 { .. } => 3,
+            OffsetCommitResponse::V4 { .. } => 4,
+            OffsetCommitResponse::V5 { .. } => 5,
+            OffsetCommitResponse::V6 { .. } => 6,
+            OffsetCommitResponse::V7 { .. } => 7,
+        }
+    }
+}
+
+impl crate::codec::Encode for OffsetCommitResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            OffsetCommitResponse::V0 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitResponse::V1 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitResponse::V2 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitResponse::V3 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitResponse::V4 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitResponse::V5 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitResponse::V6 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetCommitResponse::V7 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for OffsetCommitResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(OffsetCommitResponse::V0 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(OffsetCommitResponse::V1 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(OffsetCommitResponse::V2 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(OffsetCommitResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(OffsetCommitResponse::V4 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(OffsetCommitResponse::V5 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            6 => Ok(OffsetCommitResponse::V6 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            7 => Ok(OffsetCommitResponse::V7 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "OffsetCommitResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod offset_commit_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -3813,120 +7238,70 @@ pub mod offset_commit_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The responses for each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The responses for each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The responses for each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v4 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The responses for each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The responses for each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v6 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The responses for each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v7 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The responses for each partition in the topic.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for offset_commit_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_commit_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_commit_response::v0::Topics {
+            name: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_commit_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_index, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_commit_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_commit_response::v0::Partitions {
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum OffsetFetchRequest {
     V0 {
         /// The unique group identifier
@@ -3970,6 +7345,102 @@ pub enum OffsetFetchRequest {
     },
 }
 
+impl crate::versions::ApiVersions for OffsetFetchRequest {
+    const NAME: &'static str = "OffsetFetchRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 5;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("group_id", 0..=5),
+        ("topics", 0..=5),
+        ("topics.topic", 0..=5),
+        ("topics.partitions", 0..=5),
+        ("topics.partitions.partition", 0..=5),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            OffsetFetchRequest::V0 { .. } => 0,
+            OffsetFetchRequest::V1 { .. } => 1,
+            OffsetFetchRequest::V2 { .. } => 2,
+            OffsetFetchRequest::V3 { .. } => 3,
+            OffsetFetchRequest::V4 { .. } => 4,
+            OffsetFetchRequest::V5 { .. } => 5,
+        }
+    }
+}
+
+impl crate::request::Request for OffsetFetchRequest {
+    const API_KEY: ApiKey = ApiKey::OffsetFetch;
+    type Response = OffsetFetchResponse;
+}
+
+impl crate::codec::Encode for OffsetFetchRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            OffsetFetchRequest::V0 { group_id, topics } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetFetchRequest::V1 { group_id, topics } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetFetchRequest::V2 { group_id, topics } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetFetchRequest::V3 { group_id, topics } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetFetchRequest::V4 { group_id, topics } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetFetchRequest::V5 { group_id, topics } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for OffsetFetchRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(OffsetFetchRequest::V0 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(OffsetFetchRequest::V1 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(OffsetFetchRequest::V2 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(OffsetFetchRequest::V3 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(OffsetFetchRequest::V4 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(OffsetFetchRequest::V5 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "OffsetFetchRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod offset_fetch_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -3986,78 +7457,60 @@ pub mod offset_fetch_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch offsets.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch offsets.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch offsets.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v4 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch offsets.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+    pub mod v5 {
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for offset_fetch_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_fetch_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_fetch_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
     }
-    pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to fetch offsets.
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-        }
+}
+
+impl crate::codec::Encode for offset_fetch_request::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for offset_fetch_request::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_fetch_request::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum OffsetFetchResponse {
     V0 {
         /// Responses by topic for fetched offsets
@@ -4102,6 +7555,119 @@ pub enum OffsetFetchResponse {
     },
 }
 
+impl crate::versions::ApiVersions for OffsetFetchResponse {
+    const NAME: &'static str = "OffsetFetchResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 5;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("responses", 0..=5),
+        ("responses.topic", 0..=5),
+        ("responses.partition_responses", 0..=5),
+        ("responses.partition_responses.partition", 0..=5),
+        ("responses.partition_responses.offset", 0..=5),
+        ("responses.partition_responses.metadata", 0..=5),
+        ("responses.partition_responses.error_code", 0..=5),
+        ("error_code", 2..=5),
+        ("throttle_time_ms", 3..=5),
+        ("responses.partition_responses.leader_epoch", 5..=5),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            OffsetFetchResponse::V0 { .. } => 0,
+            OffsetFetchResponse::V1 { .. } => 1,
+            OffsetFetchResponse::V2 { .. } => 2,
+            OffsetFetchResponse::V3 { .. } => 3,
+            OffsetFetchResponse::V4 { .. } => 4,
+            OffsetFetchResponse::V5 { .. } => 5,
+        }
+    }
+}
+
+impl crate::codec::Encode for OffsetFetchResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            OffsetFetchResponse::V0 { responses } => {
+                crate::codec::Encode::encode(responses, buf);
+            }
+            OffsetFetchResponse::V1 { responses } => {
+                crate::codec::Encode::encode(responses, buf);
+            }
+            OffsetFetchResponse::V2 {
+                responses,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            OffsetFetchResponse::V3 {
+                throttle_time_ms,
+                responses,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            OffsetFetchResponse::V4 {
+                throttle_time_ms,
+                responses,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            OffsetFetchResponse::V5 {
+                throttle_time_ms,
+                responses,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for OffsetFetchResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(OffsetFetchResponse::V0 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(OffsetFetchResponse::V1 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(OffsetFetchResponse::V2 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(OffsetFetchResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(OffsetFetchResponse::V4 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(OffsetFetchResponse::V5 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "OffsetFetchResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod offset_fetch_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -4124,84 +7690,20 @@ pub mod offset_fetch_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// Responses by partition for fetched offsets
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset to be committed
-            pub offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub metadata: crate::types::NullableString,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::PartitionResponses;
+        pub use super::v0::Responses;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// Responses by partition for fetched offsets
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset to be committed
-            pub offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub metadata: crate::types::NullableString,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::PartitionResponses;
+        pub use super::v0::Responses;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// Responses by partition for fetched offsets
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset to be committed
-            pub offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub metadata: crate::types::NullableString,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::PartitionResponses;
+        pub use super::v0::Responses;
     }
     pub mod v4 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// Name of topic
-            pub topic: String,
-            /// Responses by partition for fetched offsets
-            pub partition_responses: Vec<PartitionResponses>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionResponses {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset to be committed
-            pub offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub metadata: crate::types::NullableString,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::PartitionResponses;
+        pub use super::v0::Responses;
     }
     pub mod v5 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -4229,7 +7731,83 @@ pub mod offset_fetch_response {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for offset_fetch_response::v0::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_fetch_response::v0::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_fetch_response::v0::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_fetch_response::v0::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.offset, buf);
+        crate::codec::Encode::encode(&self.metadata, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_fetch_response::v0::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_fetch_response::v0::PartitionResponses {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            offset: crate::codec::Decode::decode(buf, version)?,
+            metadata: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_fetch_response::v5::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_responses, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_fetch_response::v5::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_fetch_response::v5::Responses {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_responses: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_fetch_response::v5::PartitionResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.offset, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.metadata, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_fetch_response::v5::PartitionResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_fetch_response::v5::PartitionResponses {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            offset: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            metadata: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum FindCoordinatorRequest {
     V0 {
         /// The coordinator key.
@@ -4249,7 +7827,71 @@ pub enum FindCoordinatorRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for FindCoordinatorRequest {
+    const NAME: &'static str = "FindCoordinatorRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("key", 0..=2), ("key_type", 1..=2)];
+
+    fn version(&self) -> i16 {
+        match self {
+            FindCoordinatorRequest::V0 { .. } => 0,
+            FindCoordinatorRequest::V1 { .. } => 1,
+            FindCoordinatorRequest::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::request::Request for FindCoordinatorRequest {
+    const API_KEY: ApiKey = ApiKey::FindCoordinator;
+    type Response = FindCoordinatorResponse;
+}
+
+impl crate::codec::Encode for FindCoordinatorRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            FindCoordinatorRequest::V0 { key } => {
+                crate::codec::Encode::encode(key, buf);
+            }
+            FindCoordinatorRequest::V1 { key, key_type } => {
+                crate::codec::Encode::encode(key, buf);
+                crate::codec::Encode::encode(key_type, buf);
+            }
+            FindCoordinatorRequest::V2 { key, key_type } => {
+                crate::codec::Encode::encode(key, buf);
+                crate::codec::Encode::encode(key_type, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for FindCoordinatorRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(FindCoordinatorRequest::V0 {
+                key: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(FindCoordinatorRequest::V1 {
+                key: crate::codec::Decode::decode(buf, version)?,
+                key_type: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(FindCoordinatorRequest::V2 {
+                key: crate::codec::Decode::decode(buf, version)?,
+                key_type: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "FindCoordinatorRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum FindCoordinatorResponse {
     V0 {
         /// The error code, or 0 if there was no error.
@@ -4293,7 +7935,113 @@ pub enum FindCoordinatorResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for FindCoordinatorResponse {
+    const NAME: &'static str = "FindCoordinatorResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=2),
+        ("node_id", 0..=2),
+        ("host", 0..=2),
+        ("port", 0..=2),
+        ("throttle_time_ms", 1..=2),
+        ("error_message", 1..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            FindCoordinatorResponse::V0 { .. } => 0,
+            FindCoordinatorResponse::V1 { .. } => 1,
+            FindCoordinatorResponse::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::codec::Encode for FindCoordinatorResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            FindCoordinatorResponse::V0 {
+                error_code,
+                node_id,
+                host,
+                port,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(node_id, buf);
+                crate::codec::Encode::encode(host, buf);
+                crate::codec::Encode::encode(port, buf);
+            }
+            FindCoordinatorResponse::V1 {
+                throttle_time_ms,
+                error_code,
+                error_message,
+                node_id,
+                host,
+                port,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(error_message, buf);
+                crate::codec::Encode::encode(node_id, buf);
+                crate::codec::Encode::encode(host, buf);
+                crate::codec::Encode::encode(port, buf);
+            }
+            FindCoordinatorResponse::V2 {
+                throttle_time_ms,
+                error_code,
+                error_message,
+                node_id,
+                host,
+                port,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(error_message, buf);
+                crate::codec::Encode::encode(node_id, buf);
+                crate::codec::Encode::encode(host, buf);
+                crate::codec::Encode::encode(port, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for FindCoordinatorResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(FindCoordinatorResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                node_id: crate::codec::Decode::decode(buf, version)?,
+                host: crate::codec::Decode::decode(buf, version)?,
+                port: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(FindCoordinatorResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                error_message: crate::codec::Decode::decode(buf, version)?,
+                node_id: crate::codec::Decode::decode(buf, version)?,
+                host: crate::codec::Decode::decode(buf, version)?,
+                port: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(FindCoordinatorResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                error_message: crate::codec::Decode::decode(buf, version)?,
+                node_id: crate::codec::Decode::decode(buf, version)?,
+                host: crate::codec::Decode::decode(buf, version)?,
+                port: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "FindCoordinatorResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum JoinGroupRequest {
     V0 {
         /// The group identifier.
@@ -4398,6 +8146,196 @@ pub enum JoinGroupRequest {
     },
 }
 
+impl crate::versions::ApiVersions for JoinGroupRequest {
+    const NAME: &'static str = "JoinGroupRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 5;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("group_id", 0..=5),
+        ("session_timeout_ms", 0..=5),
+        ("member_id", 0..=5),
+        ("protocol_type", 0..=5),
+        ("protocols", 0..=5),
+        ("protocols.name", 0..=5),
+        ("protocols.metadata", 0..=5),
+        ("rebalance_timeout_ms", 1..=5),
+        ("group_instance_id", 5..=5),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            JoinGroupRequest::V0 { .. } => 0,
+            JoinGroupRequest::V1 { .. } => 1,
+            JoinGroupRequest::V2 { .. } => 2,
+            JoinGroupRequest::V3 { .. } => 3,
+            JoinGroupRequest::V4 { .. } => 4,
+            JoinGroupRequest::V5 { .. } => 5,
+        }
+    }
+}
+
+impl crate::request::Request for JoinGroupRequest {
+    const API_KEY: ApiKey = ApiKey::JoinGroup;
+    type Response = JoinGroupResponse;
+}
+
+impl crate::codec::Encode for JoinGroupRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            JoinGroupRequest::V0 {
+                group_id,
+                session_timeout_ms,
+                member_id,
+                protocol_type,
+                protocols,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(session_timeout_ms, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(protocol_type, buf);
+                crate::codec::Encode::encode(protocols, buf);
+            }
+            JoinGroupRequest::V1 {
+                group_id,
+                session_timeout_ms,
+                rebalance_timeout_ms,
+                member_id,
+                protocol_type,
+                protocols,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(session_timeout_ms, buf);
+                crate::codec::Encode::encode(rebalance_timeout_ms, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(protocol_type, buf);
+                crate::codec::Encode::encode(protocols, buf);
+            }
+            JoinGroupRequest::V2 {
+                group_id,
+                session_timeout_ms,
+                rebalance_timeout_ms,
+                member_id,
+                protocol_type,
+                protocols,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(session_timeout_ms, buf);
+                crate::codec::Encode::encode(rebalance_timeout_ms, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(protocol_type, buf);
+                crate::codec::Encode::encode(protocols, buf);
+            }
+            JoinGroupRequest::V3 {
+                group_id,
+                session_timeout_ms,
+                rebalance_timeout_ms,
+                member_id,
+                protocol_type,
+                protocols,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(session_timeout_ms, buf);
+                crate::codec::Encode::encode(rebalance_timeout_ms, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(protocol_type, buf);
+                crate::codec::Encode::encode(protocols, buf);
+            }
+            JoinGroupRequest::V4 {
+                group_id,
+                session_timeout_ms,
+                rebalance_timeout_ms,
+                member_id,
+                protocol_type,
+                protocols,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(session_timeout_ms, buf);
+                crate::codec::Encode::encode(rebalance_timeout_ms, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(protocol_type, buf);
+                crate::codec::Encode::encode(protocols, buf);
+            }
+            JoinGroupRequest::V5 {
+                group_id,
+                session_timeout_ms,
+                rebalance_timeout_ms,
+                member_id,
+                group_instance_id,
+                protocol_type,
+                protocols,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(session_timeout_ms, buf);
+                crate::codec::Encode::encode(rebalance_timeout_ms, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(group_instance_id, buf);
+                crate::codec::Encode::encode(protocol_type, buf);
+                crate::codec::Encode::encode(protocols, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for JoinGroupRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(JoinGroupRequest::V0 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                session_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_type: crate::codec::Decode::decode(buf, version)?,
+                protocols: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(JoinGroupRequest::V1 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                session_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                rebalance_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_type: crate::codec::Decode::decode(buf, version)?,
+                protocols: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(JoinGroupRequest::V2 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                session_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                rebalance_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_type: crate::codec::Decode::decode(buf, version)?,
+                protocols: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(JoinGroupRequest::V3 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                session_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                rebalance_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_type: crate::codec::Decode::decode(buf, version)?,
+                protocols: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(JoinGroupRequest::V4 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                session_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                rebalance_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_type: crate::codec::Decode::decode(buf, version)?,
+                protocols: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(JoinGroupRequest::V5 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                session_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                rebalance_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                group_instance_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_type: crate::codec::Decode::decode(buf, version)?,
+                protocols: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "JoinGroupRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod join_group_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -4409,53 +8347,41 @@ pub mod join_group_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Protocols {
-            /// The protocol name.
-            pub name: String,
-            /// The protocol metadata.
-            pub metadata: crate::types::Bytes,
-        }
+        pub use super::v0::Protocols;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Protocols {
-            /// The protocol name.
-            pub name: String,
-            /// The protocol metadata.
-            pub metadata: crate::types::Bytes,
-        }
+        pub use super::v0::Protocols;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Protocols {
-            /// The protocol name.
-            pub name: String,
-            /// The protocol metadata.
-            pub metadata: crate::types::Bytes,
-        }
+        pub use super::v0::Protocols;
     }
     pub mod v4 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Protocols {
-            /// The protocol name.
-            pub name: String,
-            /// The protocol metadata.
-            pub metadata: crate::types::Bytes,
-        }
+        pub use super::v0::Protocols;
     }
     pub mod v5 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Protocols {
-            /// The protocol name.
-            pub name: String,
-            /// The protocol metadata.
-            pub metadata: crate::types::Bytes,
-        }
+        pub use super::v0::Protocols;
+    }
+}
+
+impl crate::codec::Encode for join_group_request::v0::Protocols {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.metadata, buf);
+    }
+}
+
+impl crate::codec::Decode for join_group_request::v0::Protocols {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(join_group_request::v0::Protocols {
+            name: crate::codec::Decode::decode(buf, version)?,
+            metadata: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum JoinGroupResponse {
     V0 {
         /// The error code, or 0 if there was no error.
@@ -4555,44 +8481,206 @@ pub enum JoinGroupResponse {
     },
 }
 
-pub mod join_group_response {
-    pub mod v0 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Members {
-            /// The group member ID.
-            pub member_id: String,
-            /// The group member metadata.
-            pub metadata: crate::types::Bytes,
-        }
-    }
-    pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Members {
-            /// The group member ID.
-            pub member_id: String,
-            /// The group member metadata.
-            pub metadata: crate::types::Bytes,
+impl crate::versions::ApiVersions for JoinGroupResponse {
+    const NAME: &'static str = "JoinGroupResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 5;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=5),
+        ("generation_id", 0..=5),
+        ("protocol_name", 0..=5),
+        ("leader", 0..=5),
+        ("member_id", 0..=5),
+        ("members", 0..=5),
+        ("members.member_id", 0..=5),
+        ("members.metadata", 0..=5),
+        ("throttle_time_ms", 2..=5),
+        ("members.group_instance_id", 5..=5),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            JoinGroupResponse::V0 { .. } => 0,
+            JoinGroupResponse::V1 { .. } => 1,
+            JoinGroupResponse::V2 { .. } => 2,
+            JoinGroupResponse::V3 { .. } => 3,
+            JoinGroupResponse::V4 { .. } => 4,
+            JoinGroupResponse::V5 { .. } => 5,
         }
     }
-    pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Members {
-            /// The group member ID.
-            pub member_id: String,
-            /// The group member metadata.
-            pub metadata: crate::types::Bytes,
+}
+
+impl crate::codec::Encode for JoinGroupResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            JoinGroupResponse::V0 {
+                error_code,
+                generation_id,
+                protocol_name,
+                leader,
+                member_id,
+                members,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(protocol_name, buf);
+                crate::codec::Encode::encode(leader, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(members, buf);
+            }
+            JoinGroupResponse::V1 {
+                error_code,
+                generation_id,
+                protocol_name,
+                leader,
+                member_id,
+                members,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(protocol_name, buf);
+                crate::codec::Encode::encode(leader, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(members, buf);
+            }
+            JoinGroupResponse::V2 {
+                throttle_time_ms,
+                error_code,
+                generation_id,
+                protocol_name,
+                leader,
+                member_id,
+                members,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(protocol_name, buf);
+                crate::codec::Encode::encode(leader, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(members, buf);
+            }
+            JoinGroupResponse::V3 {
+                throttle_time_ms,
+                error_code,
+                generation_id,
+                protocol_name,
+                leader,
+                member_id,
+                members,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(protocol_name, buf);
+                crate::codec::Encode::encode(leader, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(members, buf);
+            }
+            JoinGroupResponse::V4 {
+                throttle_time_ms,
+                error_code,
+                generation_id,
+                protocol_name,
+                leader,
+                member_id,
+                members,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(protocol_name, buf);
+                crate::codec::Encode::encode(leader, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(members, buf);
+            }
+            JoinGroupResponse::V5 {
+                throttle_time_ms,
+                error_code,
+                generation_id,
+                protocol_name,
+                leader,
+                member_id,
+                members,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(protocol_name, buf);
+                crate::codec::Encode::encode(leader, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(members, buf);
+            }
         }
     }
-    pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Members {
-            /// The group member ID.
-            pub member_id: String,
-            /// The group member metadata.
-            pub metadata: crate::types::Bytes,
+}
+
+impl crate::codec::Decode for JoinGroupResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(JoinGroupResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_name: crate::codec::Decode::decode(buf, version)?,
+                leader: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                members: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(JoinGroupResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_name: crate::codec::Decode::decode(buf, version)?,
+                leader: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                members: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(JoinGroupResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_name: crate::codec::Decode::decode(buf, version)?,
+                leader: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                members: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(JoinGroupResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_name: crate::codec::Decode::decode(buf, version)?,
+                leader: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                members: crate::codec::Decode::decode(buf, version)?,
+            }),
+            4 => Ok(JoinGroupResponse::V4 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_name: crate::codec::Decode::decode(buf, version)?,
+                leader: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                members: crate::codec::Decode::decode(buf, version)?,
+            }),
+            5 => Ok(JoinGroupResponse::V5 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                protocol_name: crate::codec::Decode::decode(buf, version)?,
+                leader: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                members: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "JoinGroupResponse", version
+            ))),
         }
     }
-    pub mod v4 {
+}
+
+pub mod join_group_response {
+    pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Members {
             /// The group member ID.
@@ -4601,6 +8689,18 @@ pub mod join_group_response {
             pub metadata: crate::types::Bytes,
         }
     }
+    pub mod v1 {
+        pub use super::v0::Members;
+    }
+    pub mod v2 {
+        pub use super::v0::Members;
+    }
+    pub mod v3 {
+        pub use super::v0::Members;
+    }
+    pub mod v4 {
+        pub use super::v0::Members;
+    }
     pub mod v5 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Members {
@@ -4614,7 +8714,43 @@ pub mod join_group_response {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for join_group_response::v0::Members {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.member_id, buf);
+        crate::codec::Encode::encode(&self.metadata, buf);
+    }
+}
+
+impl crate::codec::Decode for join_group_response::v0::Members {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(join_group_response::v0::Members {
+            member_id: crate::codec::Decode::decode(buf, version)?,
+            metadata: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for join_group_response::v5::Members {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.member_id, buf);
+        crate::codec::Encode::encode(&self.group_instance_id, buf);
+        crate::codec::Encode::encode(&self.metadata, buf);
+    }
+}
+
+impl crate::codec::Decode for join_group_response::v5::Members {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(join_group_response::v5::Members {
+            member_id: crate::codec::Decode::decode(buf, version)?,
+            group_instance_id: crate::codec::Decode::decode(buf, version)?,
+            metadata: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum HeartbeatRequest {
     V0 {
         /// The group id.
@@ -4652,7 +8788,113 @@ pub enum HeartbeatRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for HeartbeatRequest {
+    const NAME: &'static str = "HeartbeatRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("group_id", 0..=3),
+        ("generation_id", 0..=3),
+        ("member_id", 0..=3),
+        ("group_instance_id", 3..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            HeartbeatRequest::V0 { .. } => 0,
+            HeartbeatRequest::V1 { .. } => 1,
+            HeartbeatRequest::V2 { .. } => 2,
+            HeartbeatRequest::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::request::Request for HeartbeatRequest {
+    const API_KEY: ApiKey = ApiKey::Heartbeat;
+    type Response = HeartbeatResponse;
+}
+
+impl crate::codec::Encode for HeartbeatRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            HeartbeatRequest::V0 {
+                group_id,
+                generation_id,
+                member_id,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+            }
+            HeartbeatRequest::V1 {
+                group_id,
+                generation_id,
+                member_id,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+            }
+            HeartbeatRequest::V2 {
+                group_id,
+                generation_id,
+                member_id,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+            }
+            HeartbeatRequest::V3 {
+                group_id,
+                generation_id,
+                member_id,
+                group_instance_id,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(group_instance_id, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for HeartbeatRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(HeartbeatRequest::V0 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(HeartbeatRequest::V1 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(HeartbeatRequest::V2 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(HeartbeatRequest::V3 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                group_instance_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "HeartbeatRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum HeartbeatResponse {
     V0 {
         /// The error code, or 0 if there was no error.
@@ -4681,7 +8923,84 @@ pub enum HeartbeatResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for HeartbeatResponse {
+    const NAME: &'static str = "HeartbeatResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("error_code", 0..=3), ("throttle_time_ms", 1..=3)];
+
+    fn version(&self) -> i16 {
+        match self {
+            HeartbeatResponse::V0 { .. } => 0,
+            HeartbeatResponse::V1 { .. } => 1,
+            HeartbeatResponse::V2 { .. } => 2,
+            HeartbeatResponse::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::codec::Encode for HeartbeatResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            HeartbeatResponse::V0 { error_code } => {
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            HeartbeatResponse::V1 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            HeartbeatResponse::V2 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            HeartbeatResponse::V3 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for HeartbeatResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(HeartbeatResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(HeartbeatResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(HeartbeatResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(HeartbeatResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "HeartbeatResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum LeaveGroupRequest {
     V0 {
         /// The ID of the group to leave.
@@ -4703,7 +9022,82 @@ pub enum LeaveGroupRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for LeaveGroupRequest {
+    const NAME: &'static str = "LeaveGroupRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("group_id", 0..=2), ("member_id", 0..=2)];
+
+    fn version(&self) -> i16 {
+        match self {
+            LeaveGroupRequest::V0 { .. } => 0,
+            LeaveGroupRequest::V1 { .. } => 1,
+            LeaveGroupRequest::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::request::Request for LeaveGroupRequest {
+    const API_KEY: ApiKey = ApiKey::LeaveGroup;
+    type Response = LeaveGroupResponse;
+}
+
+impl crate::codec::Encode for LeaveGroupRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            LeaveGroupRequest::V0 {
+                group_id,
+                member_id,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+            }
+            LeaveGroupRequest::V1 {
+                group_id,
+                member_id,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+            }
+            LeaveGroupRequest::V2 {
+                group_id,
+                member_id,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for LeaveGroupRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(LeaveGroupRequest::V0 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(LeaveGroupRequest::V1 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(LeaveGroupRequest::V2 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "LeaveGroupRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum LeaveGroupResponse {
     V0 {
         /// The error code, or 0 if there was no error.
@@ -4725,7 +9119,72 @@ pub enum LeaveGroupResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for LeaveGroupResponse {
+    const NAME: &'static str = "LeaveGroupResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("error_code", 0..=2), ("throttle_time_ms", 1..=2)];
+
+    fn version(&self) -> i16 {
+        match self {
+            LeaveGroupResponse::V0 { .. } => 0,
+            LeaveGroupResponse::V1 { .. } => 1,
+            LeaveGroupResponse::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::codec::Encode for LeaveGroupResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            LeaveGroupResponse::V0 { error_code } => {
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            LeaveGroupResponse::V1 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            LeaveGroupResponse::V2 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for LeaveGroupResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(LeaveGroupResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(LeaveGroupResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(LeaveGroupResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "LeaveGroupResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum SyncGroupRequest {
     V0 {
         /// The unique group identifier.
@@ -4771,6 +9230,125 @@ pub enum SyncGroupRequest {
     },
 }
 
+impl crate::versions::ApiVersions for SyncGroupRequest {
+    const NAME: &'static str = "SyncGroupRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("group_id", 0..=3),
+        ("generation_id", 0..=3),
+        ("member_id", 0..=3),
+        ("assignments", 0..=3),
+        ("assignments.member_id", 0..=3),
+        ("assignments.assignment", 0..=3),
+        ("group_instance_id", 3..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            SyncGroupRequest::V0 { .. } => 0,
+            SyncGroupRequest::V1 { .. } => 1,
+            SyncGroupRequest::V2 { .. } => 2,
+            SyncGroupRequest::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::request::Request for SyncGroupRequest {
+    const API_KEY: ApiKey = ApiKey::SyncGroup;
+    type Response = SyncGroupResponse;
+}
+
+impl crate::codec::Encode for SyncGroupRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            SyncGroupRequest::V0 {
+                group_id,
+                generation_id,
+                member_id,
+                assignments,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(assignments, buf);
+            }
+            SyncGroupRequest::V1 {
+                group_id,
+                generation_id,
+                member_id,
+                assignments,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(assignments, buf);
+            }
+            SyncGroupRequest::V2 {
+                group_id,
+                generation_id,
+                member_id,
+                assignments,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(assignments, buf);
+            }
+            SyncGroupRequest::V3 {
+                group_id,
+                generation_id,
+                member_id,
+                group_instance_id,
+                assignments,
+            } => {
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(generation_id, buf);
+                crate::codec::Encode::encode(member_id, buf);
+                crate::codec::Encode::encode(group_instance_id, buf);
+                crate::codec::Encode::encode(assignments, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for SyncGroupRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(SyncGroupRequest::V0 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                assignments: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(SyncGroupRequest::V1 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                assignments: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(SyncGroupRequest::V2 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                assignments: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(SyncGroupRequest::V3 {
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                generation_id: crate::codec::Decode::decode(buf, version)?,
+                member_id: crate::codec::Decode::decode(buf, version)?,
+                group_instance_id: crate::codec::Decode::decode(buf, version)?,
+                assignments: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "SyncGroupRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod sync_group_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -4782,35 +9360,35 @@ pub mod sync_group_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Assignments {
-            /// The ID of the member to assign.
-            pub member_id: String,
-            /// The member assignment.
-            pub assignment: crate::types::Bytes,
-        }
+        pub use super::v0::Assignments;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Assignments {
-            /// The ID of the member to assign.
-            pub member_id: String,
-            /// The member assignment.
-            pub assignment: crate::types::Bytes,
-        }
+        pub use super::v0::Assignments;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Assignments {
-            /// The ID of the member to assign.
-            pub member_id: String,
-            /// The member assignment.
-            pub assignment: crate::types::Bytes,
-        }
+        pub use super::v0::Assignments;
+    }
+}
+
+impl crate::codec::Encode for sync_group_request::v0::Assignments {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.member_id, buf);
+        crate::codec::Encode::encode(&self.assignment, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for sync_group_request::v0::Assignments {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(sync_group_request::v0::Assignments {
+            member_id: crate::codec::Decode::decode(buf, version)?,
+            assignment: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum SyncGroupResponse {
     V0 {
         /// The error code, or 0 if there was no error.
@@ -4847,7 +9425,101 @@ pub enum SyncGroupResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for SyncGroupResponse {
+    const NAME: &'static str = "SyncGroupResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=3),
+        ("assignment", 0..=3),
+        ("throttle_time_ms", 1..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            SyncGroupResponse::V0 { .. } => 0,
+            SyncGroupResponse::V1 { .. } => 1,
+            SyncGroupResponse::V2 { .. } => 2,
+            SyncGroupResponse::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::codec::Encode for SyncGroupResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            SyncGroupResponse::V0 {
+                error_code,
+                assignment,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(assignment, buf);
+            }
+            SyncGroupResponse::V1 {
+                throttle_time_ms,
+                error_code,
+                assignment,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(assignment, buf);
+            }
+            SyncGroupResponse::V2 {
+                throttle_time_ms,
+                error_code,
+                assignment,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(assignment, buf);
+            }
+            SyncGroupResponse::V3 {
+                throttle_time_ms,
+                error_code,
+                assignment,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(assignment, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for SyncGroupResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(SyncGroupResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                assignment: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(SyncGroupResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                assignment: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(SyncGroupResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                assignment: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(SyncGroupResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                assignment: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "SyncGroupResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeGroupsRequest {
     V0 {
         /// The names of the groups to describe
@@ -4869,7 +9541,79 @@ pub enum DescribeGroupsRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for DescribeGroupsRequest {
+    const NAME: &'static str = "DescribeGroupsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("groups", 0..=3), ("include_authorized_operations", 3..=3)];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeGroupsRequest::V0 { .. } => 0,
+            DescribeGroupsRequest::V1 { .. } => 1,
+            DescribeGroupsRequest::V2 { .. } => 2,
+            DescribeGroupsRequest::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::request::Request for DescribeGroupsRequest {
+    const API_KEY: ApiKey = ApiKey::DescribeGroups;
+    type Response = DescribeGroupsResponse;
+}
+
+impl crate::codec::Encode for DescribeGroupsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeGroupsRequest::V0 { groups } => {
+                crate::codec::Encode::encode(groups, buf);
+            }
+            DescribeGroupsRequest::V1 { groups } => {
+                crate::codec::Encode::encode(groups, buf);
+            }
+            DescribeGroupsRequest::V2 { groups } => {
+                crate::codec::Encode::encode(groups, buf);
+            }
+            DescribeGroupsRequest::V3 {
+                groups,
+                include_authorized_operations,
+            } => {
+                crate::codec::Encode::encode(groups, buf);
+                crate::codec::Encode::encode(include_authorized_operations, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DescribeGroupsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeGroupsRequest::V0 {
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeGroupsRequest::V1 {
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(DescribeGroupsRequest::V2 {
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(DescribeGroupsRequest::V3 {
+                groups: crate::codec::Decode::decode(buf, version)?,
+                include_authorized_operations: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeGroupsRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeGroupsResponse {
     V0 {
         /// Each described group.
@@ -4898,6 +9642,95 @@ pub enum DescribeGroupsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for DescribeGroupsResponse {
+    const NAME: &'static str = "DescribeGroupsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("groups", 0..=3),
+        ("groups.error_code", 0..=3),
+        ("groups.group_id", 0..=3),
+        ("groups.group_state", 0..=3),
+        ("groups.protocol_type", 0..=3),
+        ("groups.protocol_data", 0..=3),
+        ("groups.members", 0..=3),
+        ("groups.members.member_id", 0..=3),
+        ("groups.members.client_id", 0..=3),
+        ("groups.members.client_host", 0..=3),
+        ("groups.members.member_metadata", 0..=3),
+        ("groups.members.member_assignment", 0..=3),
+        ("throttle_time_ms", 1..=3),
+        ("groups.authorized_operations", 3..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeGroupsResponse::V0 { .. } => 0,
+            DescribeGroupsResponse::V1 { .. } => 1,
+            DescribeGroupsResponse::V2 { .. } => 2,
+            DescribeGroupsResponse::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::codec::Encode for DescribeGroupsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeGroupsResponse::V0 { groups } => {
+                crate::codec::Encode::encode(groups, buf);
+            }
+            DescribeGroupsResponse::V1 {
+                throttle_time_ms,
+                groups,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(groups, buf);
+            }
+            DescribeGroupsResponse::V2 {
+                throttle_time_ms,
+                groups,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(groups, buf);
+            }
+            DescribeGroupsResponse::V3 {
+                throttle_time_ms,
+                groups,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(groups, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DescribeGroupsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeGroupsResponse::V0 {
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeGroupsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(DescribeGroupsResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(DescribeGroupsResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeGroupsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod describe_groups_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -4930,66 +9763,15 @@ pub mod describe_groups_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Groups {
-            /// The describe error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The group ID string.
-            pub group_id: String,
-            /// The group state string, or the empty string.
-            pub group_state: String,
-            /// The group protocol type, or the empty string.
-            pub protocol_type: String,
-            /// The group protocol data, or the empty string.
-            pub protocol_data: String,
-            /// The group members.
-            pub members: Vec<Members>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Members {
-            /// The member ID assigned by the group coordinator.
-            pub member_id: String,
-            /// The client ID used in the member's latest join group request.
-            pub client_id: String,
-            /// The client host.
-            pub client_host: String,
-            /// The metadata corresponding to the current group protocol in use.
-            pub member_metadata: crate::types::Bytes,
-            /// The current assignment provided by the group leader.
-            pub member_assignment: crate::types::Bytes,
-        }
+        pub use super::v0::Groups;
+        pub use super::v0::Members;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Groups {
-            /// The describe error, or 0 if there was no error.
-            pub error_code: i16,
-            /// The group ID string.
-            pub group_id: String,
-            /// The group state string, or the empty string.
-            pub group_state: String,
-            /// The group protocol type, or the empty string.
-            pub protocol_type: String,
-            /// The group protocol data, or the empty string.
-            pub protocol_data: String,
-            /// The group members.
-            pub members: Vec<Members>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Members {
-            /// The member ID assigned by the group coordinator.
-            pub member_id: String,
-            /// The client ID used in the member's latest join group request.
-            pub client_id: String,
-            /// The client host.
-            pub client_host: String,
-            /// The metadata corresponding to the current group protocol in use.
-            pub member_metadata: crate::types::Bytes,
-            /// The current assignment provided by the group leader.
-            pub member_assignment: crate::types::Bytes,
-        }
+        pub use super::v0::Groups;
+        pub use super::v0::Members;
     }
     pub mod v3 {
+        pub use super::v0::Members;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Groups {
             /// The describe error, or 0 if there was no error.
@@ -5001,36 +9783,144 @@ pub mod describe_groups_response {
             /// The group protocol type, or the empty string.
             pub protocol_type: String,
             /// The group protocol data, or the empty string.
-            pub protocol_data: String,
-            /// The group members.
-            pub members: Vec<Members>,
-            /// 32-bit bitfield to represent authorized operations for this group.
-            pub authorized_operations: i32,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Members {
-            /// The member ID assigned by the group coordinator.
-            pub member_id: String,
-            /// The client ID used in the member's latest join group request.
-            pub client_id: String,
-            /// The client host.
-            pub client_host: String,
-            /// The metadata corresponding to the current group protocol in use.
-            pub member_metadata: crate::types::Bytes,
-            /// The current assignment provided by the group leader.
-            pub member_assignment: crate::types::Bytes,
+            pub protocol_data: String,
+            /// The group members.
+            pub members: Vec<Members>,
+            /// 32-bit bitfield to represent authorized operations for this group.
+            pub authorized_operations: i32,
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for describe_groups_response::v0::Groups {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.group_id, buf);
+        crate::codec::Encode::encode(&self.group_state, buf);
+        crate::codec::Encode::encode(&self.protocol_type, buf);
+        crate::codec::Encode::encode(&self.protocol_data, buf);
+        crate::codec::Encode::encode(&self.members, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_groups_response::v0::Groups {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_groups_response::v0::Groups {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            group_id: crate::codec::Decode::decode(buf, version)?,
+            group_state: crate::codec::Decode::decode(buf, version)?,
+            protocol_type: crate::codec::Decode::decode(buf, version)?,
+            protocol_data: crate::codec::Decode::decode(buf, version)?,
+            members: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_groups_response::v0::Members {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.member_id, buf);
+        crate::codec::Encode::encode(&self.client_id, buf);
+        crate::codec::Encode::encode(&self.client_host, buf);
+        crate::codec::Encode::encode(&self.member_metadata, buf);
+        crate::codec::Encode::encode(&self.member_assignment, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_groups_response::v0::Members {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_groups_response::v0::Members {
+            member_id: crate::codec::Decode::decode(buf, version)?,
+            client_id: crate::codec::Decode::decode(buf, version)?,
+            client_host: crate::codec::Decode::decode(buf, version)?,
+            member_metadata: crate::codec::Decode::decode(buf, version)?,
+            member_assignment: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_groups_response::v3::Groups {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.group_id, buf);
+        crate::codec::Encode::encode(&self.group_state, buf);
+        crate::codec::Encode::encode(&self.protocol_type, buf);
+        crate::codec::Encode::encode(&self.protocol_data, buf);
+        crate::codec::Encode::encode(&self.members, buf);
+        crate::codec::Encode::encode(&self.authorized_operations, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_groups_response::v3::Groups {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_groups_response::v3::Groups {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            group_id: crate::codec::Decode::decode(buf, version)?,
+            group_state: crate::codec::Decode::decode(buf, version)?,
+            protocol_type: crate::codec::Decode::decode(buf, version)?,
+            protocol_data: crate::codec::Decode::decode(buf, version)?,
+            members: crate::codec::Decode::decode(buf, version)?,
+            authorized_operations: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ListGroupsRequest {
     V0 {},
     V1 {},
     V2 {},
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for ListGroupsRequest {
+    const NAME: &'static str = "ListGroupsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[];
+
+    fn version(&self) -> i16 {
+        match self {
+            ListGroupsRequest::V0 { .. } => 0,
+            ListGroupsRequest::V1 { .. } => 1,
+            ListGroupsRequest::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::request::Request for ListGroupsRequest {
+    const API_KEY: ApiKey = ApiKey::ListGroups;
+    type Response = ListGroupsResponse;
+}
+
+impl crate::codec::Encode for ListGroupsRequest {
+    fn encode(&self, _buf: &mut Vec<u8>) {
+        match self {
+            ListGroupsRequest::V0 {} => {}
+            ListGroupsRequest::V1 {} => {}
+            ListGroupsRequest::V2 {} => {}
+        }
+    }
+}
+
+impl crate::codec::Decode for ListGroupsRequest {
+    fn decode(_buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ListGroupsRequest::V0 {}),
+            1 => Ok(ListGroupsRequest::V1 {}),
+            2 => Ok(ListGroupsRequest::V2 {}),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ListGroupsRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ListGroupsResponse {
     V0 {
         /// Response error code
@@ -5058,6 +9948,82 @@ pub enum ListGroupsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for ListGroupsResponse {
+    const NAME: &'static str = "ListGroupsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=2),
+        ("groups", 0..=2),
+        ("groups.group_id", 0..=2),
+        ("groups.protocol_type", 0..=2),
+        ("throttle_time_ms", 1..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ListGroupsResponse::V0 { .. } => 0,
+            ListGroupsResponse::V1 { .. } => 1,
+            ListGroupsResponse::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::codec::Encode for ListGroupsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ListGroupsResponse::V0 { error_code, groups } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(groups, buf);
+            }
+            ListGroupsResponse::V1 {
+                throttle_time_ms,
+                error_code,
+                groups,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(groups, buf);
+            }
+            ListGroupsResponse::V2 {
+                throttle_time_ms,
+                error_code,
+                groups,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(groups, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ListGroupsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ListGroupsResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ListGroupsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(ListGroupsResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ListGroupsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod list_groups_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5069,26 +10035,32 @@ pub mod list_groups_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Groups {
-            /// The unique group identifier
-            pub group_id: String,
-            /// null
-            pub protocol_type: String,
-        }
+        pub use super::v0::Groups;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Groups {
-            /// The unique group identifier
-            pub group_id: String,
-            /// null
-            pub protocol_type: String,
-        }
+        pub use super::v0::Groups;
+    }
+}
+
+impl crate::codec::Encode for list_groups_response::v0::Groups {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.group_id, buf);
+        crate::codec::Encode::encode(&self.protocol_type, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for list_groups_response::v0::Groups {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(list_groups_response::v0::Groups {
+            group_id: crate::codec::Decode::decode(buf, version)?,
+            protocol_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum SaslHandshakeRequest {
     V0 {
         /// The SASL mechanism chosen by the client.
@@ -5100,7 +10072,60 @@ pub enum SaslHandshakeRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for SaslHandshakeRequest {
+    const NAME: &'static str = "SaslHandshakeRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("mechanism", 0..=1)];
+
+    fn version(&self) -> i16 {
+        match self {
+            SaslHandshakeRequest::V0 { .. } => 0,
+            SaslHandshakeRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for SaslHandshakeRequest {
+    const API_KEY: ApiKey = ApiKey::SaslHandshake;
+    type Response = SaslHandshakeResponse;
+}
+
+impl crate::codec::Encode for SaslHandshakeRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            SaslHandshakeRequest::V0 { mechanism } => {
+                crate::codec::Encode::encode(mechanism, buf);
+            }
+            SaslHandshakeRequest::V1 { mechanism } => {
+                crate::codec::Encode::encode(mechanism, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for SaslHandshakeRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(SaslHandshakeRequest::V0 {
+                mechanism: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(SaslHandshakeRequest::V1 {
+                mechanism: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "SaslHandshakeRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum SaslHandshakeResponse {
     V0 {
         /// The error code, or 0 if there was no error.
@@ -5116,14 +10141,119 @@ pub enum SaslHandshakeResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for SaslHandshakeResponse {
+    const NAME: &'static str = "SaslHandshakeResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("error_code", 0..=1), ("mechanisms", 0..=1)];
+
+    fn version(&self) -> i16 {
+        match self {
+            SaslHandshakeResponse::V0 { .. } => 0,
+            SaslHandshakeResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for SaslHandshakeResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            SaslHandshakeResponse::V0 {
+                error_code,
+                mechanisms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(mechanisms, buf);
+            }
+            SaslHandshakeResponse::V1 {
+                error_code,
+                mechanisms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(mechanisms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for SaslHandshakeResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(SaslHandshakeResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                mechanisms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(SaslHandshakeResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                mechanisms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "SaslHandshakeResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ApiVersionsRequest {
     V0 {},
     V1 {},
     V2 {},
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for ApiVersionsRequest {
+    const NAME: &'static str = "ApiVersionsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[];
+
+    fn version(&self) -> i16 {
+        match self {
+            ApiVersionsRequest::V0 { .. } => 0,
+            ApiVersionsRequest::V1 { .. } => 1,
+            ApiVersionsRequest::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::request::Request for ApiVersionsRequest {
+    const API_KEY: ApiKey = ApiKey::ApiVersions;
+    type Response = ApiVersionsResponse;
+}
+
+impl crate::codec::Encode for ApiVersionsRequest {
+    fn encode(&self, _buf: &mut Vec<u8>) {
+        match self {
+            ApiVersionsRequest::V0 {} => {}
+            ApiVersionsRequest::V1 {} => {}
+            ApiVersionsRequest::V2 {} => {}
+        }
+    }
+}
+
+impl crate::codec::Decode for ApiVersionsRequest {
+    fn decode(_buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ApiVersionsRequest::V0 {}),
+            1 => Ok(ApiVersionsRequest::V1 {}),
+            2 => Ok(ApiVersionsRequest::V2 {}),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ApiVersionsRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ApiVersionsResponse {
     V0 {
         /// Response error code
@@ -5151,12 +10281,114 @@ pub enum ApiVersionsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for ApiVersionsResponse {
+    const NAME: &'static str = "ApiVersionsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=2),
+        ("api_versions", 0..=2),
+        ("api_versions.api_key", 0..=2),
+        ("api_versions.min_version", 0..=2),
+        ("api_versions.max_version", 0..=2),
+        ("throttle_time_ms", 1..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ApiVersionsResponse::V0 { .. } => 0,
+            ApiVersionsResponse::V1 { .. } => 1,
+            ApiVersionsResponse::V2 { .. } => 2,
+        }
+    }
+}
+
+impl ApiVersionsResponse {
+    /// Returns the (api_key, min_version, max_version) of the APIs supported
+    /// by the broker. Api keys unknown to this crate, e.g. of APIs added in a
+    /// more recent broker, are kept as `AnyApiKey::Unknown`.
+    pub fn api_key_versions(&self) -> Vec<(crate::types::AnyApiKey, i16, i16)> {
+        match self {
+            ApiVersionsResponse::V0 { api_versions, .. } => api_versions
+                .iter()
+                .map(|v| (v.api_key, v.min_version, v.max_version))
+                .collect(),
+            ApiVersionsResponse::V1 { api_versions, .. } => api_versions
+                .iter()
+                .map(|v| (v.api_key, v.min_version, v.max_version))
+                .collect(),
+            ApiVersionsResponse::V2 { api_versions, .. } => api_versions
+                .iter()
+                .map(|v| (v.api_key, v.min_version, v.max_version))
+                .collect(),
+        }
+    }
+}
+
+impl crate::codec::Encode for ApiVersionsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ApiVersionsResponse::V0 {
+                error_code,
+                api_versions,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(api_versions, buf);
+            }
+            ApiVersionsResponse::V1 {
+                error_code,
+                api_versions,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(api_versions, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            ApiVersionsResponse::V2 {
+                error_code,
+                api_versions,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(api_versions, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ApiVersionsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ApiVersionsResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                api_versions: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ApiVersionsResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                api_versions: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(ApiVersionsResponse::V2 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                api_versions: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ApiVersionsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod api_versions_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct ApiVersions {
             /// API key.
-            pub api_key: i16,
+            pub api_key: crate::types::AnyApiKey,
             /// Minimum supported version.
             pub min_version: i16,
             /// Maximum supported version.
@@ -5164,30 +10396,34 @@ pub mod api_versions_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ApiVersions {
-            /// API key.
-            pub api_key: i16,
-            /// Minimum supported version.
-            pub min_version: i16,
-            /// Maximum supported version.
-            pub max_version: i16,
-        }
+        pub use super::v0::ApiVersions;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ApiVersions {
-            /// API key.
-            pub api_key: i16,
-            /// Minimum supported version.
-            pub min_version: i16,
-            /// Maximum supported version.
-            pub max_version: i16,
-        }
+        pub use super::v0::ApiVersions;
+    }
+}
+
+impl crate::codec::Encode for api_versions_response::v0::ApiVersions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.api_key, buf);
+        crate::codec::Encode::encode(&self.min_version, buf);
+        crate::codec::Encode::encode(&self.max_version, buf);
+    }
+}
+
+impl crate::codec::Decode for api_versions_response::v0::ApiVersions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(api_versions_response::v0::ApiVersions {
+            api_key: crate::codec::Decode::decode(buf, version)?,
+            min_version: crate::codec::Decode::decode(buf, version)?,
+            max_version: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum CreateTopicsRequest {
     V0 {
         /// The topics to create.
@@ -5216,112 +10452,119 @@ pub enum CreateTopicsRequest {
     V3 {
         /// The topics to create.
         topics: Vec<create_topics_request::v3::Topics>,
-        /// How long to wait in milliseconds before timing out the request.
-        timeout_ms: i32,
-        /// If true, check that the topics can be created as specified, but don't
-        /// create anything.
-        validate_only: bool,
-    },
-}
-
-pub mod create_topics_request {
-    pub mod v0 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The configuration name.
-            pub name: String,
-            /// The number of partitions to create in the topic, or -1 if we are
-            /// specifying a manual partition assignment.
-            pub num_partitions: i32,
-            /// The number of replicas to create for each partition in the topic, or -
-            /// 1 if we are specifying a manual partition assignment.
-            pub replication_factor: i16,
-            /// The manual partition assignment, or the empty array if we are using
-            /// automatic assignment.
-            pub assignments: Vec<Assignments>,
-            /// The custom topic configurations to set.
-            pub configs: Vec<Configs>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Assignments {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The brokers to place the partition on.
-            pub broker_ids: Vec<i32>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Configs {
-            /// The configuration name.
-            pub name: String,
-            /// The configuration value.
-            pub value: crate::types::NullableString,
-        }
-    }
-    pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The configuration name.
-            pub name: String,
-            /// The number of partitions to create in the topic, or -1 if we are
-            /// specifying a manual partition assignment.
-            pub num_partitions: i32,
-            /// The number of replicas to create for each partition in the topic, or -
-            /// 1 if we are specifying a manual partition assignment.
-            pub replication_factor: i16,
-            /// The manual partition assignment, or the empty array if we are using
-            /// automatic assignment.
-            pub assignments: Vec<Assignments>,
-            /// The custom topic configurations to set.
-            pub configs: Vec<Configs>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Assignments {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The brokers to place the partition on.
-            pub broker_ids: Vec<i32>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Configs {
-            /// The configuration name.
-            pub name: String,
-            /// The configuration value.
-            pub value: crate::types::NullableString,
-        }
-    }
-    pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The configuration name.
-            pub name: String,
-            /// The number of partitions to create in the topic, or -1 if we are
-            /// specifying a manual partition assignment.
-            pub num_partitions: i32,
-            /// The number of replicas to create for each partition in the topic, or -
-            /// 1 if we are specifying a manual partition assignment.
-            pub replication_factor: i16,
-            /// The manual partition assignment, or the empty array if we are using
-            /// automatic assignment.
-            pub assignments: Vec<Assignments>,
-            /// The custom topic configurations to set.
-            pub configs: Vec<Configs>,
+        /// How long to wait in milliseconds before timing out the request.
+        timeout_ms: i32,
+        /// If true, check that the topics can be created as specified, but don't
+        /// create anything.
+        validate_only: bool,
+    },
+}
+
+impl crate::versions::ApiVersions for CreateTopicsRequest {
+    const NAME: &'static str = "CreateTopicsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topics", 0..=3),
+        ("topics.name", 0..=3),
+        ("topics.num_partitions", 0..=3),
+        ("topics.replication_factor", 0..=3),
+        ("topics.assignments", 0..=3),
+        ("topics.assignments.partition_index", 0..=3),
+        ("topics.assignments.broker_ids", 0..=3),
+        ("topics.configs", 0..=3),
+        ("topics.configs.name", 0..=3),
+        ("topics.configs.value", 0..=3),
+        ("timeout_ms", 0..=3),
+        ("validate_only", 1..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            CreateTopicsRequest::V0 { .. } => 0,
+            CreateTopicsRequest::V1 { .. } => 1,
+            CreateTopicsRequest::V2 { .. } => 2,
+            CreateTopicsRequest::V3 { .. } => 3,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Assignments {
-            /// The partition index.
-            pub partition_index: i32,
-            /// The brokers to place the partition on.
-            pub broker_ids: Vec<i32>,
+    }
+}
+
+impl crate::request::Request for CreateTopicsRequest {
+    const API_KEY: ApiKey = ApiKey::CreateTopics;
+    type Response = CreateTopicsResponse;
+}
+
+impl crate::codec::Encode for CreateTopicsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CreateTopicsRequest::V0 { topics, timeout_ms } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+            }
+            CreateTopicsRequest::V1 {
+                topics,
+                timeout_ms,
+                validate_only,
+            } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+                crate::codec::Encode::encode(validate_only, buf);
+            }
+            CreateTopicsRequest::V2 {
+                topics,
+                timeout_ms,
+                validate_only,
+            } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+                crate::codec::Encode::encode(validate_only, buf);
+            }
+            CreateTopicsRequest::V3 {
+                topics,
+                timeout_ms,
+                validate_only,
+            } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+                crate::codec::Encode::encode(validate_only, buf);
+            }
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Configs {
-            /// The configuration name.
-            pub name: String,
-            /// The configuration value.
-            pub value: crate::types::NullableString,
+    }
+}
+
+impl crate::codec::Decode for CreateTopicsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(CreateTopicsRequest::V0 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(CreateTopicsRequest::V1 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                validate_only: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(CreateTopicsRequest::V2 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                validate_only: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(CreateTopicsRequest::V3 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+                validate_only: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "CreateTopicsRequest", version
+            ))),
         }
     }
-    pub mod v3 {
+}
+
+pub mod create_topics_request {
+    pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Topics {
             /// The configuration name.
@@ -5353,9 +10596,80 @@ pub mod create_topics_request {
             pub value: crate::types::NullableString,
         }
     }
+    pub mod v1 {
+        pub use super::v0::Assignments;
+        pub use super::v0::Configs;
+        pub use super::v0::Topics;
+    }
+    pub mod v2 {
+        pub use super::v0::Assignments;
+        pub use super::v0::Configs;
+        pub use super::v0::Topics;
+    }
+    pub mod v3 {
+        pub use super::v0::Assignments;
+        pub use super::v0::Configs;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for create_topics_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.num_partitions, buf);
+        crate::codec::Encode::encode(&self.replication_factor, buf);
+        crate::codec::Encode::encode(&self.assignments, buf);
+        crate::codec::Encode::encode(&self.configs, buf);
+    }
+}
+
+impl crate::codec::Decode for create_topics_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_topics_request::v0::Topics {
+            name: crate::codec::Decode::decode(buf, version)?,
+            num_partitions: crate::codec::Decode::decode(buf, version)?,
+            replication_factor: crate::codec::Decode::decode(buf, version)?,
+            assignments: crate::codec::Decode::decode(buf, version)?,
+            configs: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for create_topics_request::v0::Assignments {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_index, buf);
+        crate::codec::Encode::encode(&self.broker_ids, buf);
+    }
+}
+
+impl crate::codec::Decode for create_topics_request::v0::Assignments {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_topics_request::v0::Assignments {
+            partition_index: crate::codec::Decode::decode(buf, version)?,
+            broker_ids: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for create_topics_request::v0::Configs {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.value, buf);
+    }
+}
+
+impl crate::codec::Decode for create_topics_request::v0::Configs {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_topics_request::v0::Configs {
+            name: crate::codec::Decode::decode(buf, version)?,
+            value: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum CreateTopicsResponse {
     V0 {
         /// Results for each topic we tried to create.
@@ -5381,6 +10695,81 @@ pub enum CreateTopicsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for CreateTopicsResponse {
+    const NAME: &'static str = "CreateTopicsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topics", 0..=3),
+        ("topics.name", 0..=3),
+        ("topics.error_code", 0..=3),
+        ("topics.error_message", 1..=3),
+        ("throttle_time_ms", 2..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            CreateTopicsResponse::V0 { .. } => 0,
+            CreateTopicsResponse::V1 { .. } => 1,
+            CreateTopicsResponse::V2 { .. } => 2,
+            CreateTopicsResponse::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::codec::Encode for CreateTopicsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CreateTopicsResponse::V0 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            CreateTopicsResponse::V1 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            CreateTopicsResponse::V2 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            CreateTopicsResponse::V3 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for CreateTopicsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(CreateTopicsResponse::V0 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(CreateTopicsResponse::V1 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(CreateTopicsResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(CreateTopicsResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "CreateTopicsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod create_topics_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5403,30 +10792,50 @@ pub mod create_topics_response {
         }
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-            /// The error message, or null if there was no error.
-            pub error_message: crate::types::NullableString,
-        }
+        pub use super::v1::Topics;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// The topic name.
-            pub name: String,
-            /// The error code, or 0 if there was no error.
-            pub error_code: i16,
-            /// The error message, or null if there was no error.
-            pub error_message: crate::types::NullableString,
-        }
+        pub use super::v1::Topics;
+    }
+}
+
+impl crate::codec::Encode for create_topics_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for create_topics_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_topics_response::v0::Topics {
+            name: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for create_topics_response::v1::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for create_topics_response::v1::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_topics_response::v1::Topics {
+            name: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DeleteTopicsRequest {
     V0 {
         /// The names of the topics to delete
@@ -5458,7 +10867,94 @@ pub enum DeleteTopicsRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for DeleteTopicsRequest {
+    const NAME: &'static str = "DeleteTopicsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("topic_names", 0..=3), ("timeout_ms", 0..=3)];
+
+    fn version(&self) -> i16 {
+        match self {
+            DeleteTopicsRequest::V0 { .. } => 0,
+            DeleteTopicsRequest::V1 { .. } => 1,
+            DeleteTopicsRequest::V2 { .. } => 2,
+            DeleteTopicsRequest::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::request::Request for DeleteTopicsRequest {
+    const API_KEY: ApiKey = ApiKey::DeleteTopics;
+    type Response = DeleteTopicsResponse;
+}
+
+impl crate::codec::Encode for DeleteTopicsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DeleteTopicsRequest::V0 {
+                topic_names,
+                timeout_ms,
+            } => {
+                crate::codec::Encode::encode(topic_names, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+            }
+            DeleteTopicsRequest::V1 {
+                topic_names,
+                timeout_ms,
+            } => {
+                crate::codec::Encode::encode(topic_names, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+            }
+            DeleteTopicsRequest::V2 {
+                topic_names,
+                timeout_ms,
+            } => {
+                crate::codec::Encode::encode(topic_names, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+            }
+            DeleteTopicsRequest::V3 {
+                topic_names,
+                timeout_ms,
+            } => {
+                crate::codec::Encode::encode(topic_names, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DeleteTopicsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DeleteTopicsRequest::V0 {
+                topic_names: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DeleteTopicsRequest::V1 {
+                topic_names: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(DeleteTopicsRequest::V2 {
+                topic_names: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(DeleteTopicsRequest::V3 {
+                topic_names: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DeleteTopicsRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DeleteTopicsResponse {
     V0 {
         /// The results for each topic we tried to delete.
@@ -5487,6 +10983,85 @@ pub enum DeleteTopicsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for DeleteTopicsResponse {
+    const NAME: &'static str = "DeleteTopicsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("responses", 0..=3),
+        ("responses.name", 0..=3),
+        ("responses.error_code", 0..=3),
+        ("throttle_time_ms", 1..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DeleteTopicsResponse::V0 { .. } => 0,
+            DeleteTopicsResponse::V1 { .. } => 1,
+            DeleteTopicsResponse::V2 { .. } => 2,
+            DeleteTopicsResponse::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::codec::Encode for DeleteTopicsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DeleteTopicsResponse::V0 { responses } => {
+                crate::codec::Encode::encode(responses, buf);
+            }
+            DeleteTopicsResponse::V1 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            DeleteTopicsResponse::V2 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+            DeleteTopicsResponse::V3 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DeleteTopicsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DeleteTopicsResponse::V0 {
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DeleteTopicsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(DeleteTopicsResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(DeleteTopicsResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DeleteTopicsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod delete_topics_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5498,35 +11073,35 @@ pub mod delete_topics_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// The topic name
-            pub name: String,
-            /// The deletion error, or 0 if the deletion succeeded.
-            pub error_code: i16,
-        }
+        pub use super::v0::Responses;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// The topic name
-            pub name: String,
-            /// The deletion error, or 0 if the deletion succeeded.
-            pub error_code: i16,
-        }
+        pub use super::v0::Responses;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Responses {
-            /// The topic name
-            pub name: String,
-            /// The deletion error, or 0 if the deletion succeeded.
-            pub error_code: i16,
-        }
+        pub use super::v0::Responses;
+    }
+}
+
+impl crate::codec::Encode for delete_topics_response::v0::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_topics_response::v0::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_topics_response::v0::Responses {
+            name: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DeleteRecordsRequest {
     V0 {
         /// null
@@ -5542,6 +11117,67 @@ pub enum DeleteRecordsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for DeleteRecordsRequest {
+    const NAME: &'static str = "DeleteRecordsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topics", 0..=1),
+        ("topics.topic", 0..=1),
+        ("topics.partitions", 0..=1),
+        ("topics.partitions.partition", 0..=1),
+        ("topics.partitions.offset", 0..=1),
+        ("timeout", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DeleteRecordsRequest::V0 { .. } => 0,
+            DeleteRecordsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for DeleteRecordsRequest {
+    const API_KEY: ApiKey = ApiKey::DeleteRecords;
+    type Response = DeleteRecordsResponse;
+}
+
+impl crate::codec::Encode for DeleteRecordsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DeleteRecordsRequest::V0 { topics, timeout } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(timeout, buf);
+            }
+            DeleteRecordsRequest::V1 { topics, timeout } => {
+                crate::codec::Encode::encode(topics, buf);
+                crate::codec::Encode::encode(timeout, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DeleteRecordsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DeleteRecordsRequest::V0 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DeleteRecordsRequest::V1 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DeleteRecordsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod delete_records_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5561,25 +11197,46 @@ pub mod delete_records_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The offset before which the messages will be deleted. -1 means high-
-            /// watermark for the partition.
-            pub offset: i64,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for delete_records_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_records_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_records_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for delete_records_request::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.offset, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_records_request::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_records_request::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            offset: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DeleteRecordsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -5597,6 +11254,69 @@ pub enum DeleteRecordsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for DeleteRecordsResponse {
+    const NAME: &'static str = "DeleteRecordsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("topics", 0..=1),
+        ("topics.topic", 0..=1),
+        ("topics.partitions", 0..=1),
+        ("topics.partitions.partition", 0..=1),
+        ("topics.partitions.low_watermark", 0..=1),
+        ("topics.partitions.error_code", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DeleteRecordsResponse::V0 { .. } => 0,
+            DeleteRecordsResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for DeleteRecordsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DeleteRecordsResponse::V0 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            DeleteRecordsResponse::V1 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DeleteRecordsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DeleteRecordsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DeleteRecordsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DeleteRecordsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod delete_records_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5617,26 +11337,48 @@ pub mod delete_records_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Smallest available offset of all live replicas
-            pub low_watermark: i64,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for delete_records_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for delete_records_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_records_response::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for delete_records_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.low_watermark, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_records_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_records_response::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            low_watermark: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum InitProducerIdRequest {
     V0 {
         /// The transactional id, or null if the producer is not transactional.
@@ -5656,7 +11398,72 @@ pub enum InitProducerIdRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for InitProducerIdRequest {
+    const NAME: &'static str = "InitProducerIdRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("transactional_id", 0..=1),
+        ("transaction_timeout_ms", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            InitProducerIdRequest::V0 { .. } => 0,
+            InitProducerIdRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for InitProducerIdRequest {
+    const API_KEY: ApiKey = ApiKey::InitProducerId;
+    type Response = InitProducerIdResponse;
+}
+
+impl crate::codec::Encode for InitProducerIdRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            InitProducerIdRequest::V0 {
+                transactional_id,
+                transaction_timeout_ms,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(transaction_timeout_ms, buf);
+            }
+            InitProducerIdRequest::V1 {
+                transactional_id,
+                transaction_timeout_ms,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(transaction_timeout_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for InitProducerIdRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(InitProducerIdRequest::V0 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                transaction_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(InitProducerIdRequest::V1 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                transaction_timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "InitProducerIdRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum InitProducerIdResponse {
     V0 {
         /// The duration in milliseconds for which the request was throttled due
@@ -5682,7 +11489,81 @@ pub enum InitProducerIdResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for InitProducerIdResponse {
+    const NAME: &'static str = "InitProducerIdResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("error_code", 0..=1),
+        ("producer_id", 0..=1),
+        ("producer_epoch", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            InitProducerIdResponse::V0 { .. } => 0,
+            InitProducerIdResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for InitProducerIdResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            InitProducerIdResponse::V0 {
+                throttle_time_ms,
+                error_code,
+                producer_id,
+                producer_epoch,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+            }
+            InitProducerIdResponse::V1 {
+                throttle_time_ms,
+                error_code,
+                producer_id,
+                producer_epoch,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for InitProducerIdResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(InitProducerIdResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(InitProducerIdResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "InitProducerIdResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum OffsetForLeaderEpochRequest {
     V0 {
         /// An array of topics to get epochs for
@@ -5704,6 +11585,80 @@ pub enum OffsetForLeaderEpochRequest {
     },
 }
 
+impl crate::versions::ApiVersions for OffsetForLeaderEpochRequest {
+    const NAME: &'static str = "OffsetForLeaderEpochRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topics", 0..=3),
+        ("topics.topic", 0..=3),
+        ("topics.partitions", 0..=3),
+        ("topics.partitions.partition", 0..=3),
+        ("topics.partitions.leader_epoch", 0..=3),
+        ("topics.partitions.current_leader_epoch", 2..=3),
+        ("replica_id", 3..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            OffsetForLeaderEpochRequest::V0 { .. } => 0,
+            OffsetForLeaderEpochRequest::V1 { .. } => 1,
+            OffsetForLeaderEpochRequest::V2 { .. } => 2,
+            OffsetForLeaderEpochRequest::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::request::Request for OffsetForLeaderEpochRequest {
+    const API_KEY: ApiKey = ApiKey::OffsetForLeaderEpoch;
+    type Response = OffsetForLeaderEpochResponse;
+}
+
+impl crate::codec::Encode for OffsetForLeaderEpochRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            OffsetForLeaderEpochRequest::V0 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetForLeaderEpochRequest::V1 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetForLeaderEpochRequest::V2 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetForLeaderEpochRequest::V3 { replica_id, topics } => {
+                crate::codec::Encode::encode(replica_id, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for OffsetForLeaderEpochRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(OffsetForLeaderEpochRequest::V0 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(OffsetForLeaderEpochRequest::V1 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(OffsetForLeaderEpochRequest::V2 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(OffsetForLeaderEpochRequest::V3 {
+                replica_id: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "OffsetForLeaderEpochRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod offset_for_leader_epoch_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5722,20 +11677,8 @@ pub mod offset_for_leader_epoch_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// An array of partitions to get epochs for
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The epoch to lookup an offset for.
-            pub leader_epoch: i32,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v2 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5761,31 +11704,80 @@ pub mod offset_for_leader_epoch_request {
         }
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// An array of partitions to get epochs for
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The current leader epoch, if provided, is used to fence consumers/
-            /// replicas with old metadata. If the epoch provided by the client is
-            /// larger than the current epoch known to the broker, then the
-            /// UNKNOWN_LEADER_EPOCH error code will be returned. If the provided
-            /// epoch is smaller, then the FENCED_LEADER_EPOCH error code will be
-            /// returned.
-            pub current_leader_epoch: i32,
-            /// The epoch to lookup an offset for.
-            pub leader_epoch: i32,
-        }
+        pub use super::v2::Partitions;
+        pub use super::v2::Topics;
+    }
+}
+
+impl crate::codec::Encode for offset_for_leader_epoch_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_for_leader_epoch_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_for_leader_epoch_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_for_leader_epoch_request::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_for_leader_epoch_request::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_for_leader_epoch_request::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_for_leader_epoch_request::v2::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_for_leader_epoch_request::v2::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_for_leader_epoch_request::v2::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_for_leader_epoch_request::v2::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.current_leader_epoch, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_for_leader_epoch_request::v2::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_for_leader_epoch_request::v2::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            current_leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum OffsetForLeaderEpochResponse {
     V0 {
         /// An array of topics for which we have leader offsets for some requested
@@ -5815,6 +11807,84 @@ pub enum OffsetForLeaderEpochResponse {
     },
 }
 
+impl crate::versions::ApiVersions for OffsetForLeaderEpochResponse {
+    const NAME: &'static str = "OffsetForLeaderEpochResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 3;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topics", 0..=3),
+        ("topics.topic", 0..=3),
+        ("topics.partitions", 0..=3),
+        ("topics.partitions.error_code", 0..=3),
+        ("topics.partitions.partition", 0..=3),
+        ("topics.partitions.end_offset", 0..=3),
+        ("topics.partitions.leader_epoch", 1..=3),
+        ("throttle_time_ms", 2..=3),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            OffsetForLeaderEpochResponse::V0 { .. } => 0,
+            OffsetForLeaderEpochResponse::V1 { .. } => 1,
+            OffsetForLeaderEpochResponse::V2 { .. } => 2,
+            OffsetForLeaderEpochResponse::V3 { .. } => 3,
+        }
+    }
+}
+
+impl crate::codec::Encode for OffsetForLeaderEpochResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            OffsetForLeaderEpochResponse::V0 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetForLeaderEpochResponse::V1 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetForLeaderEpochResponse::V2 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            OffsetForLeaderEpochResponse::V3 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for OffsetForLeaderEpochResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(OffsetForLeaderEpochResponse::V0 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(OffsetForLeaderEpochResponse::V1 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(OffsetForLeaderEpochResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            3 => Ok(OffsetForLeaderEpochResponse::V3 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "OffsetForLeaderEpochResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod offset_for_leader_epoch_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5855,48 +11925,88 @@ pub mod offset_for_leader_epoch_response {
         }
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// An array of offsets by partition
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Response error code
-            pub error_code: i16,
-            /// Topic partition id
-            pub partition: i32,
-            /// The leader epoch
-            pub leader_epoch: i32,
-            /// The end offset
-            pub end_offset: i64,
-        }
+        pub use super::v1::Partitions;
+        pub use super::v1::Topics;
     }
     pub mod v3 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// An array of offsets by partition
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Response error code
-            pub error_code: i16,
-            /// Topic partition id
-            pub partition: i32,
-            /// The leader epoch
-            pub leader_epoch: i32,
-            /// The end offset
-            pub end_offset: i64,
-        }
+        pub use super::v1::Partitions;
+        pub use super::v1::Topics;
+    }
+}
+
+impl crate::codec::Encode for offset_for_leader_epoch_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_for_leader_epoch_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_for_leader_epoch_response::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_for_leader_epoch_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.end_offset, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_for_leader_epoch_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_for_leader_epoch_response::v0::Partitions {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+            end_offset: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_for_leader_epoch_response::v1::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_for_leader_epoch_response::v1::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_for_leader_epoch_response::v1::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for offset_for_leader_epoch_response::v1::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.end_offset, buf);
+    }
+}
+
+impl crate::codec::Decode for offset_for_leader_epoch_response::v1::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(offset_for_leader_epoch_response::v1::Partitions {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            partition: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            end_offset: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum AddPartitionsToTxnRequest {
     V0 {
         /// The transactional id corresponding to the transaction.
@@ -5920,6 +12030,85 @@ pub enum AddPartitionsToTxnRequest {
     },
 }
 
+impl crate::versions::ApiVersions for AddPartitionsToTxnRequest {
+    const NAME: &'static str = "AddPartitionsToTxnRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("transactional_id", 0..=1),
+        ("producer_id", 0..=1),
+        ("producer_epoch", 0..=1),
+        ("topics", 0..=1),
+        ("topics.topic", 0..=1),
+        ("topics.partitions", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            AddPartitionsToTxnRequest::V0 { .. } => 0,
+            AddPartitionsToTxnRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for AddPartitionsToTxnRequest {
+    const API_KEY: ApiKey = ApiKey::AddPartitionsToTxn;
+    type Response = AddPartitionsToTxnResponse;
+}
+
+impl crate::codec::Encode for AddPartitionsToTxnRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            AddPartitionsToTxnRequest::V0 {
+                transactional_id,
+                producer_id,
+                producer_epoch,
+                topics,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            AddPartitionsToTxnRequest::V1 {
+                transactional_id,
+                producer_id,
+                producer_epoch,
+                topics,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for AddPartitionsToTxnRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(AddPartitionsToTxnRequest::V0 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(AddPartitionsToTxnRequest::V1 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "AddPartitionsToTxnRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod add_partitions_to_txn_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5931,17 +12120,29 @@ pub mod add_partitions_to_txn_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partitions: Vec<i32>,
-        }
+        pub use super::v0::Topics;
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for add_partitions_to_txn_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for add_partitions_to_txn_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(add_partitions_to_txn_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum AddPartitionsToTxnResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -5959,6 +12160,68 @@ pub enum AddPartitionsToTxnResponse {
     },
 }
 
+impl crate::versions::ApiVersions for AddPartitionsToTxnResponse {
+    const NAME: &'static str = "AddPartitionsToTxnResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("errors", 0..=1),
+        ("errors.topic", 0..=1),
+        ("errors.partition_errors", 0..=1),
+        ("errors.partition_errors.partition", 0..=1),
+        ("errors.partition_errors.error_code", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            AddPartitionsToTxnResponse::V0 { .. } => 0,
+            AddPartitionsToTxnResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for AddPartitionsToTxnResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            AddPartitionsToTxnResponse::V0 {
+                throttle_time_ms,
+                errors,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(errors, buf);
+            }
+            AddPartitionsToTxnResponse::V1 {
+                throttle_time_ms,
+                errors,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(errors, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for AddPartitionsToTxnResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(AddPartitionsToTxnResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                errors: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(AddPartitionsToTxnResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                errors: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "AddPartitionsToTxnResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod add_partitions_to_txn_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -5977,24 +12240,46 @@ pub mod add_partitions_to_txn_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Errors {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partition_errors: Vec<PartitionErrors>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct PartitionErrors {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::Errors;
+        pub use super::v0::PartitionErrors;
+    }
+}
+
+impl crate::codec::Encode for add_partitions_to_txn_response::v0::Errors {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_errors, buf);
+    }
+}
+
+impl crate::codec::Decode for add_partitions_to_txn_response::v0::Errors {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(add_partitions_to_txn_response::v0::Errors {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_errors: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for add_partitions_to_txn_response::v0::PartitionErrors {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for add_partitions_to_txn_response::v0::PartitionErrors {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(add_partitions_to_txn_response::v0::PartitionErrors {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum AddOffsetsToTxnRequest {
     V0 {
         /// The transactional id corresponding to the transaction.
@@ -6018,7 +12303,86 @@ pub enum AddOffsetsToTxnRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for AddOffsetsToTxnRequest {
+    const NAME: &'static str = "AddOffsetsToTxnRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("transactional_id", 0..=1),
+        ("producer_id", 0..=1),
+        ("producer_epoch", 0..=1),
+        ("group_id", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            AddOffsetsToTxnRequest::V0 { .. } => 0,
+            AddOffsetsToTxnRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for AddOffsetsToTxnRequest {
+    const API_KEY: ApiKey = ApiKey::AddOffsetsToTxn;
+    type Response = AddOffsetsToTxnResponse;
+}
+
+impl crate::codec::Encode for AddOffsetsToTxnRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            AddOffsetsToTxnRequest::V0 {
+                transactional_id,
+                producer_id,
+                producer_epoch,
+                group_id,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(group_id, buf);
+            }
+            AddOffsetsToTxnRequest::V1 {
+                transactional_id,
+                producer_id,
+                producer_epoch,
+                group_id,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(group_id, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for AddOffsetsToTxnRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(AddOffsetsToTxnRequest::V0 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                group_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(AddOffsetsToTxnRequest::V1 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                group_id: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "AddOffsetsToTxnRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum AddOffsetsToTxnResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -6036,7 +12400,65 @@ pub enum AddOffsetsToTxnResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for AddOffsetsToTxnResponse {
+    const NAME: &'static str = "AddOffsetsToTxnResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("throttle_time_ms", 0..=1), ("error_code", 0..=1)];
+
+    fn version(&self) -> i16 {
+        match self {
+            AddOffsetsToTxnResponse::V0 { .. } => 0,
+            AddOffsetsToTxnResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for AddOffsetsToTxnResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            AddOffsetsToTxnResponse::V0 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            AddOffsetsToTxnResponse::V1 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for AddOffsetsToTxnResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(AddOffsetsToTxnResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(AddOffsetsToTxnResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "AddOffsetsToTxnResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum EndTxnRequest {
     V0 {
         /// The transactional id corresponding to the transaction.
@@ -6060,7 +12482,86 @@ pub enum EndTxnRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for EndTxnRequest {
+    const NAME: &'static str = "EndTxnRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("transactional_id", 0..=1),
+        ("producer_id", 0..=1),
+        ("producer_epoch", 0..=1),
+        ("transaction_result", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            EndTxnRequest::V0 { .. } => 0,
+            EndTxnRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for EndTxnRequest {
+    const API_KEY: ApiKey = ApiKey::EndTxn;
+    type Response = EndTxnResponse;
+}
+
+impl crate::codec::Encode for EndTxnRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            EndTxnRequest::V0 {
+                transactional_id,
+                producer_id,
+                producer_epoch,
+                transaction_result,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(transaction_result, buf);
+            }
+            EndTxnRequest::V1 {
+                transactional_id,
+                producer_id,
+                producer_epoch,
+                transaction_result,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(transaction_result, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for EndTxnRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(EndTxnRequest::V0 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                transaction_result: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(EndTxnRequest::V1 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                transaction_result: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "EndTxnRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum EndTxnResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -6078,7 +12579,65 @@ pub enum EndTxnResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for EndTxnResponse {
+    const NAME: &'static str = "EndTxnResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("throttle_time_ms", 0..=1), ("error_code", 0..=1)];
+
+    fn version(&self) -> i16 {
+        match self {
+            EndTxnResponse::V0 { .. } => 0,
+            EndTxnResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for EndTxnResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            EndTxnResponse::V0 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+            EndTxnResponse::V1 {
+                throttle_time_ms,
+                error_code,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for EndTxnResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(EndTxnResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(EndTxnResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "EndTxnResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum WriteTxnMarkersRequest {
     V0 {
         /// The transaction markers to be written.
@@ -6086,6 +12645,60 @@ pub enum WriteTxnMarkersRequest {
     },
 }
 
+impl crate::versions::ApiVersions for WriteTxnMarkersRequest {
+    const NAME: &'static str = "WriteTxnMarkersRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 0;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("transaction_markers", 0..=0),
+        ("transaction_markers.producer_id", 0..=0),
+        ("transaction_markers.producer_epoch", 0..=0),
+        ("transaction_markers.transaction_result", 0..=0),
+        ("transaction_markers.topics", 0..=0),
+        ("transaction_markers.topics.topic", 0..=0),
+        ("transaction_markers.topics.partitions", 0..=0),
+        ("transaction_markers.coordinator_epoch", 0..=0),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            WriteTxnMarkersRequest::V0 { .. } => 0,
+        }
+    }
+}
+
+impl crate::request::Request for WriteTxnMarkersRequest {
+    const API_KEY: ApiKey = ApiKey::WriteTxnMarkers;
+    type Response = WriteTxnMarkersResponse;
+}
+
+impl crate::codec::Encode for WriteTxnMarkersRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            WriteTxnMarkersRequest::V0 {
+                transaction_markers,
+            } => {
+                crate::codec::Encode::encode(transaction_markers, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for WriteTxnMarkersRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(WriteTxnMarkersRequest::V0 {
+                transaction_markers: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "WriteTxnMarkersRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod write_txn_markers_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6113,7 +12726,47 @@ pub mod write_txn_markers_request {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for write_txn_markers_request::v0::TransactionMarkers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.producer_id, buf);
+        crate::codec::Encode::encode(&self.producer_epoch, buf);
+        crate::codec::Encode::encode(&self.transaction_result, buf);
+        crate::codec::Encode::encode(&self.topics, buf);
+        crate::codec::Encode::encode(&self.coordinator_epoch, buf);
+    }
+}
+
+impl crate::codec::Decode for write_txn_markers_request::v0::TransactionMarkers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(write_txn_markers_request::v0::TransactionMarkers {
+            producer_id: crate::codec::Decode::decode(buf, version)?,
+            producer_epoch: crate::codec::Decode::decode(buf, version)?,
+            transaction_result: crate::codec::Decode::decode(buf, version)?,
+            topics: crate::codec::Decode::decode(buf, version)?,
+            coordinator_epoch: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for write_txn_markers_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for write_txn_markers_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(write_txn_markers_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum WriteTxnMarkersResponse {
     V0 {
         /// Errors per partition from writing markers.
@@ -6121,6 +12774,54 @@ pub enum WriteTxnMarkersResponse {
     },
 }
 
+impl crate::versions::ApiVersions for WriteTxnMarkersResponse {
+    const NAME: &'static str = "WriteTxnMarkersResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 0;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("transaction_markers", 0..=0),
+        ("transaction_markers.producer_id", 0..=0),
+        ("transaction_markers.topics", 0..=0),
+        ("transaction_markers.topics.topic", 0..=0),
+        ("transaction_markers.topics.partitions", 0..=0),
+        ("transaction_markers.topics.partitions.partition", 0..=0),
+        ("transaction_markers.topics.partitions.error_code", 0..=0),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            WriteTxnMarkersResponse::V0 { .. } => 0,
+        }
+    }
+}
+
+impl crate::codec::Encode for WriteTxnMarkersResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            WriteTxnMarkersResponse::V0 {
+                transaction_markers,
+            } => {
+                crate::codec::Encode::encode(transaction_markers, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for WriteTxnMarkersResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(WriteTxnMarkersResponse::V0 {
+                transaction_markers: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "WriteTxnMarkersResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod write_txn_markers_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6147,7 +12848,57 @@ pub mod write_txn_markers_response {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for write_txn_markers_response::v0::TransactionMarkers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.producer_id, buf);
+        crate::codec::Encode::encode(&self.topics, buf);
+    }
+}
+
+impl crate::codec::Decode for write_txn_markers_response::v0::TransactionMarkers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(write_txn_markers_response::v0::TransactionMarkers {
+            producer_id: crate::codec::Decode::decode(buf, version)?,
+            topics: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for write_txn_markers_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for write_txn_markers_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(write_txn_markers_response::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for write_txn_markers_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for write_txn_markers_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(write_txn_markers_response::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum TxnOffsetCommitRequest {
     V0 {
         /// The transactional id corresponding to the transaction.
@@ -6187,6 +12938,117 @@ pub enum TxnOffsetCommitRequest {
     },
 }
 
+impl crate::versions::ApiVersions for TxnOffsetCommitRequest {
+    const NAME: &'static str = "TxnOffsetCommitRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("transactional_id", 0..=2),
+        ("group_id", 0..=2),
+        ("producer_id", 0..=2),
+        ("producer_epoch", 0..=2),
+        ("topics", 0..=2),
+        ("topics.topic", 0..=2),
+        ("topics.partitions", 0..=2),
+        ("topics.partitions.partition", 0..=2),
+        ("topics.partitions.offset", 0..=2),
+        ("topics.partitions.metadata", 0..=2),
+        ("topics.partitions.leader_epoch", 2..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            TxnOffsetCommitRequest::V0 { .. } => 0,
+            TxnOffsetCommitRequest::V1 { .. } => 1,
+            TxnOffsetCommitRequest::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::request::Request for TxnOffsetCommitRequest {
+    const API_KEY: ApiKey = ApiKey::TxnOffsetCommit;
+    type Response = TxnOffsetCommitResponse;
+}
+
+impl crate::codec::Encode for TxnOffsetCommitRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            TxnOffsetCommitRequest::V0 {
+                transactional_id,
+                group_id,
+                producer_id,
+                producer_epoch,
+                topics,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            TxnOffsetCommitRequest::V1 {
+                transactional_id,
+                group_id,
+                producer_id,
+                producer_epoch,
+                topics,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            TxnOffsetCommitRequest::V2 {
+                transactional_id,
+                group_id,
+                producer_id,
+                producer_epoch,
+                topics,
+            } => {
+                crate::codec::Encode::encode(transactional_id, buf);
+                crate::codec::Encode::encode(group_id, buf);
+                crate::codec::Encode::encode(producer_id, buf);
+                crate::codec::Encode::encode(producer_epoch, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for TxnOffsetCommitRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(TxnOffsetCommitRequest::V0 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(TxnOffsetCommitRequest::V1 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(TxnOffsetCommitRequest::V2 {
+                transactional_id: crate::codec::Decode::decode(buf, version)?,
+                group_id: crate::codec::Decode::decode(buf, version)?,
+                producer_id: crate::codec::Decode::decode(buf, version)?,
+                producer_epoch: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "TxnOffsetCommitRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod txn_offset_commit_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6207,22 +13069,8 @@ pub mod txn_offset_commit_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Partitions to commit offsets
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Message offset to be committed
-            pub offset: i64,
-            /// Any associated metadata the client wants to keep.
-            pub metadata: crate::types::NullableString,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v2 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6248,7 +13096,79 @@ pub mod txn_offset_commit_request {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for txn_offset_commit_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for txn_offset_commit_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(txn_offset_commit_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for txn_offset_commit_request::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.offset, buf);
+        crate::codec::Encode::encode(&self.metadata, buf);
+    }
+}
+
+impl crate::codec::Decode for txn_offset_commit_request::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(txn_offset_commit_request::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            offset: crate::codec::Decode::decode(buf, version)?,
+            metadata: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for txn_offset_commit_request::v2::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for txn_offset_commit_request::v2::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(txn_offset_commit_request::v2::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for txn_offset_commit_request::v2::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.offset, buf);
+        crate::codec::Encode::encode(&self.leader_epoch, buf);
+        crate::codec::Encode::encode(&self.metadata, buf);
+    }
+}
+
+impl crate::codec::Decode for txn_offset_commit_request::v2::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(txn_offset_commit_request::v2::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            offset: crate::codec::Decode::decode(buf, version)?,
+            leader_epoch: crate::codec::Decode::decode(buf, version)?,
+            metadata: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum TxnOffsetCommitResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -6273,6 +13193,80 @@ pub enum TxnOffsetCommitResponse {
     },
 }
 
+impl crate::versions::ApiVersions for TxnOffsetCommitResponse {
+    const NAME: &'static str = "TxnOffsetCommitResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=2),
+        ("topics", 0..=2),
+        ("topics.topic", 0..=2),
+        ("topics.partitions", 0..=2),
+        ("topics.partitions.partition", 0..=2),
+        ("topics.partitions.error_code", 0..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            TxnOffsetCommitResponse::V0 { .. } => 0,
+            TxnOffsetCommitResponse::V1 { .. } => 1,
+            TxnOffsetCommitResponse::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::codec::Encode for TxnOffsetCommitResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            TxnOffsetCommitResponse::V0 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            TxnOffsetCommitResponse::V1 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            TxnOffsetCommitResponse::V2 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for TxnOffsetCommitResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(TxnOffsetCommitResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(TxnOffsetCommitResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(TxnOffsetCommitResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "TxnOffsetCommitResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod txn_offset_commit_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6291,40 +13285,50 @@ pub mod txn_offset_commit_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Responses by partition for committed offsets
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// Responses by partition for committed offsets
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for txn_offset_commit_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for txn_offset_commit_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(txn_offset_commit_response::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for txn_offset_commit_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for txn_offset_commit_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(txn_offset_commit_response::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeAclsRequest {
     V0 {
         /// The resource type
@@ -6358,7 +13362,104 @@ pub enum DescribeAclsRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for DescribeAclsRequest {
+    const NAME: &'static str = "DescribeAclsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("resource_type", 0..=1),
+        ("resource_name", 0..=1),
+        ("principal", 0..=1),
+        ("host", 0..=1),
+        ("operation", 0..=1),
+        ("permission_type", 0..=1),
+        ("resource_pattern_type_filter", 1..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeAclsRequest::V0 { .. } => 0,
+            DescribeAclsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for DescribeAclsRequest {
+    const API_KEY: ApiKey = ApiKey::DescribeAcls;
+    type Response = DescribeAclsResponse;
+}
+
+impl crate::codec::Encode for DescribeAclsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeAclsRequest::V0 {
+                resource_type,
+                resource_name,
+                principal,
+                host,
+                operation,
+                permission_type,
+            } => {
+                crate::codec::Encode::encode(resource_type, buf);
+                crate::codec::Encode::encode(resource_name, buf);
+                crate::codec::Encode::encode(principal, buf);
+                crate::codec::Encode::encode(host, buf);
+                crate::codec::Encode::encode(operation, buf);
+                crate::codec::Encode::encode(permission_type, buf);
+            }
+            DescribeAclsRequest::V1 {
+                resource_type,
+                resource_name,
+                resource_pattern_type_filter,
+                principal,
+                host,
+                operation,
+                permission_type,
+            } => {
+                crate::codec::Encode::encode(resource_type, buf);
+                crate::codec::Encode::encode(resource_name, buf);
+                crate::codec::Encode::encode(resource_pattern_type_filter, buf);
+                crate::codec::Encode::encode(principal, buf);
+                crate::codec::Encode::encode(host, buf);
+                crate::codec::Encode::encode(operation, buf);
+                crate::codec::Encode::encode(permission_type, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DescribeAclsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeAclsRequest::V0 {
+                resource_type: crate::codec::Decode::decode(buf, version)?,
+                resource_name: crate::codec::Decode::decode(buf, version)?,
+                principal: crate::codec::Decode::decode(buf, version)?,
+                host: crate::codec::Decode::decode(buf, version)?,
+                operation: crate::codec::Decode::decode(buf, version)?,
+                permission_type: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeAclsRequest::V1 {
+                resource_type: crate::codec::Decode::decode(buf, version)?,
+                resource_name: crate::codec::Decode::decode(buf, version)?,
+                resource_pattern_type_filter: crate::codec::Decode::decode(buf, version)?,
+                principal: crate::codec::Decode::decode(buf, version)?,
+                host: crate::codec::Decode::decode(buf, version)?,
+                operation: crate::codec::Decode::decode(buf, version)?,
+                permission_type: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeAclsRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeAclsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -6384,6 +13485,86 @@ pub enum DescribeAclsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for DescribeAclsResponse {
+    const NAME: &'static str = "DescribeAclsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("error_code", 0..=1),
+        ("error_message", 0..=1),
+        ("resources", 0..=1),
+        ("resources.resource_type", 0..=1),
+        ("resources.resource_name", 0..=1),
+        ("resources.acls", 0..=1),
+        ("resources.acls.principal", 0..=1),
+        ("resources.acls.host", 0..=1),
+        ("resources.acls.operation", 0..=1),
+        ("resources.acls.permission_type", 0..=1),
+        ("resources.resource_pattern_type", 1..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeAclsResponse::V0 { .. } => 0,
+            DescribeAclsResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for DescribeAclsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeAclsResponse::V0 {
+                throttle_time_ms,
+                error_code,
+                error_message,
+                resources,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(error_message, buf);
+                crate::codec::Encode::encode(resources, buf);
+            }
+            DescribeAclsResponse::V1 {
+                throttle_time_ms,
+                error_code,
+                error_message,
+                resources,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(error_message, buf);
+                crate::codec::Encode::encode(resources, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DescribeAclsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeAclsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                error_message: crate::codec::Decode::decode(buf, version)?,
+                resources: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeAclsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                error_message: crate::codec::Decode::decode(buf, version)?,
+                resources: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeAclsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod describe_acls_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6408,6 +13589,7 @@ pub mod describe_acls_response {
         }
     }
     pub mod v1 {
+        pub use super::v0::Acls;
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Resources {
             /// The resource type
@@ -6419,21 +13601,70 @@ pub mod describe_acls_response {
             /// null
             pub acls: Vec<Acls>,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Acls {
-            /// The ACL principal
-            pub principal: String,
-            /// The ACL host
-            pub host: String,
-            /// The ACL operation
-            pub operation: i8,
-            /// The ACL permission type
-            pub permission_type: i8,
-        }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for describe_acls_response::v0::Resources {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.acls, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_acls_response::v0::Resources {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_acls_response::v0::Resources {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            acls: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_acls_response::v0::Acls {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.principal, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.operation, buf);
+        crate::codec::Encode::encode(&self.permission_type, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_acls_response::v0::Acls {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_acls_response::v0::Acls {
+            principal: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            operation: crate::codec::Decode::decode(buf, version)?,
+            permission_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_acls_response::v1::Resources {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.resource_pattern_type, buf);
+        crate::codec::Encode::encode(&self.acls, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_acls_response::v1::Resources {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_acls_response::v1::Resources {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            resource_pattern_type: crate::codec::Decode::decode(buf, version)?,
+            acls: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum CreateAclsRequest {
     V0 {
         /// null
@@ -6445,6 +13676,65 @@ pub enum CreateAclsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for CreateAclsRequest {
+    const NAME: &'static str = "CreateAclsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("creations", 0..=1),
+        ("creations.resource_type", 0..=1),
+        ("creations.resource_name", 0..=1),
+        ("creations.principal", 0..=1),
+        ("creations.host", 0..=1),
+        ("creations.operation", 0..=1),
+        ("creations.permission_type", 0..=1),
+        ("creations.resource_pattern_type", 1..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            CreateAclsRequest::V0 { .. } => 0,
+            CreateAclsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for CreateAclsRequest {
+    const API_KEY: ApiKey = ApiKey::CreateAcls;
+    type Response = CreateAclsResponse;
+}
+
+impl crate::codec::Encode for CreateAclsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CreateAclsRequest::V0 { creations } => {
+                crate::codec::Encode::encode(creations, buf);
+            }
+            CreateAclsRequest::V1 { creations } => {
+                crate::codec::Encode::encode(creations, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for CreateAclsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(CreateAclsRequest::V0 {
+                creations: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(CreateAclsRequest::V1 {
+                creations: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "CreateAclsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod create_acls_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6484,7 +13774,59 @@ pub mod create_acls_request {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for create_acls_request::v0::Creations {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.principal, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.operation, buf);
+        crate::codec::Encode::encode(&self.permission_type, buf);
+    }
+}
+
+impl crate::codec::Decode for create_acls_request::v0::Creations {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_acls_request::v0::Creations {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            principal: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            operation: crate::codec::Decode::decode(buf, version)?,
+            permission_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for create_acls_request::v1::Creations {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.resource_pattern_type, buf);
+        crate::codec::Encode::encode(&self.principal, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.operation, buf);
+        crate::codec::Encode::encode(&self.permission_type, buf);
+    }
+}
+
+impl crate::codec::Decode for create_acls_request::v1::Creations {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_acls_request::v1::Creations {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            resource_pattern_type: crate::codec::Decode::decode(buf, version)?,
+            principal: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            operation: crate::codec::Decode::decode(buf, version)?,
+            permission_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum CreateAclsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -6502,6 +13844,66 @@ pub enum CreateAclsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for CreateAclsResponse {
+    const NAME: &'static str = "CreateAclsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("creation_responses", 0..=1),
+        ("creation_responses.error_code", 0..=1),
+        ("creation_responses.error_message", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            CreateAclsResponse::V0 { .. } => 0,
+            CreateAclsResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for CreateAclsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CreateAclsResponse::V0 {
+                throttle_time_ms,
+                creation_responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(creation_responses, buf);
+            }
+            CreateAclsResponse::V1 {
+                throttle_time_ms,
+                creation_responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(creation_responses, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for CreateAclsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(CreateAclsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                creation_responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(CreateAclsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                creation_responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "CreateAclsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod create_acls_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6513,17 +13915,29 @@ pub mod create_acls_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct CreationResponses {
-            /// Response error code
-            pub error_code: i16,
-            /// Response error message
-            pub error_message: crate::types::NullableString,
-        }
+        pub use super::v0::CreationResponses;
+    }
+}
+
+impl crate::codec::Encode for create_acls_response::v0::CreationResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for create_acls_response::v0::CreationResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_acls_response::v0::CreationResponses {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DeleteAclsRequest {
     V0 {
         /// null
@@ -6535,6 +13949,65 @@ pub enum DeleteAclsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for DeleteAclsRequest {
+    const NAME: &'static str = "DeleteAclsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("filters", 0..=1),
+        ("filters.resource_type", 0..=1),
+        ("filters.resource_name", 0..=1),
+        ("filters.principal", 0..=1),
+        ("filters.host", 0..=1),
+        ("filters.operation", 0..=1),
+        ("filters.permission_type", 0..=1),
+        ("filters.resource_pattern_type_filter", 1..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DeleteAclsRequest::V0 { .. } => 0,
+            DeleteAclsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for DeleteAclsRequest {
+    const API_KEY: ApiKey = ApiKey::DeleteAcls;
+    type Response = DeleteAclsResponse;
+}
+
+impl crate::codec::Encode for DeleteAclsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DeleteAclsRequest::V0 { filters } => {
+                crate::codec::Encode::encode(filters, buf);
+            }
+            DeleteAclsRequest::V1 { filters } => {
+                crate::codec::Encode::encode(filters, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DeleteAclsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DeleteAclsRequest::V0 {
+                filters: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DeleteAclsRequest::V1 {
+                filters: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DeleteAclsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod delete_acls_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6574,7 +14047,59 @@ pub mod delete_acls_request {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for delete_acls_request::v0::Filters {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.principal, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.operation, buf);
+        crate::codec::Encode::encode(&self.permission_type, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_acls_request::v0::Filters {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_acls_request::v0::Filters {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            principal: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            operation: crate::codec::Decode::decode(buf, version)?,
+            permission_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for delete_acls_request::v1::Filters {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.resource_pattern_type_filter, buf);
+        crate::codec::Encode::encode(&self.principal, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.operation, buf);
+        crate::codec::Encode::encode(&self.permission_type, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_acls_request::v1::Filters {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_acls_request::v1::Filters {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            resource_pattern_type_filter: crate::codec::Decode::decode(buf, version)?,
+            principal: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            operation: crate::codec::Decode::decode(buf, version)?,
+            permission_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DeleteAclsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -6592,6 +14117,79 @@ pub enum DeleteAclsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for DeleteAclsResponse {
+    const NAME: &'static str = "DeleteAclsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("filter_responses", 0..=1),
+        ("filter_responses.error_code", 0..=1),
+        ("filter_responses.error_message", 0..=1),
+        ("filter_responses.matching_acls", 0..=1),
+        ("filter_responses.matching_acls.error_code", 0..=1),
+        ("filter_responses.matching_acls.error_message", 0..=1),
+        ("filter_responses.matching_acls.resource_type", 0..=1),
+        ("filter_responses.matching_acls.resource_name", 0..=1),
+        ("filter_responses.matching_acls.principal", 0..=1),
+        ("filter_responses.matching_acls.host", 0..=1),
+        ("filter_responses.matching_acls.operation", 0..=1),
+        ("filter_responses.matching_acls.permission_type", 0..=1),
+        (
+            "filter_responses.matching_acls.resource_pattern_type",
+            1..=1,
+        ),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DeleteAclsResponse::V0 { .. } => 0,
+            DeleteAclsResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for DeleteAclsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DeleteAclsResponse::V0 {
+                throttle_time_ms,
+                filter_responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(filter_responses, buf);
+            }
+            DeleteAclsResponse::V1 {
+                throttle_time_ms,
+                filter_responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(filter_responses, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DeleteAclsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DeleteAclsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                filter_responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DeleteAclsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                filter_responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DeleteAclsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod delete_acls_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6657,7 +14255,103 @@ pub mod delete_acls_response {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for delete_acls_response::v0::FilterResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+        crate::codec::Encode::encode(&self.matching_acls, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_acls_response::v0::FilterResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_acls_response::v0::FilterResponses {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+            matching_acls: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for delete_acls_response::v0::MatchingAcls {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.principal, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.operation, buf);
+        crate::codec::Encode::encode(&self.permission_type, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_acls_response::v0::MatchingAcls {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_acls_response::v0::MatchingAcls {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            principal: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            operation: crate::codec::Decode::decode(buf, version)?,
+            permission_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for delete_acls_response::v1::FilterResponses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+        crate::codec::Encode::encode(&self.matching_acls, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_acls_response::v1::FilterResponses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_acls_response::v1::FilterResponses {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+            matching_acls: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for delete_acls_response::v1::MatchingAcls {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.resource_pattern_type, buf);
+        crate::codec::Encode::encode(&self.principal, buf);
+        crate::codec::Encode::encode(&self.host, buf);
+        crate::codec::Encode::encode(&self.operation, buf);
+        crate::codec::Encode::encode(&self.permission_type, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_acls_response::v1::MatchingAcls {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_acls_response::v1::MatchingAcls {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            resource_pattern_type: crate::codec::Decode::decode(buf, version)?,
+            principal: crate::codec::Decode::decode(buf, version)?,
+            host: crate::codec::Decode::decode(buf, version)?,
+            operation: crate::codec::Decode::decode(buf, version)?,
+            permission_type: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeConfigsRequest {
     V0 {
         /// An array of config resources to be returned.
@@ -6677,6 +14371,79 @@ pub enum DescribeConfigsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for DescribeConfigsRequest {
+    const NAME: &'static str = "DescribeConfigsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("resources", 0..=2),
+        ("resources.resource_type", 0..=2),
+        ("resources.resource_name", 0..=2),
+        ("resources.config_names", 0..=2),
+        ("include_synonyms", 1..=2),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeConfigsRequest::V0 { .. } => 0,
+            DescribeConfigsRequest::V1 { .. } => 1,
+            DescribeConfigsRequest::V2 { .. } => 2,
+        }
+    }
+}
+
+impl crate::request::Request for DescribeConfigsRequest {
+    const API_KEY: ApiKey = ApiKey::DescribeConfigs;
+    type Response = DescribeConfigsResponse;
+}
+
+impl crate::codec::Encode for DescribeConfigsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeConfigsRequest::V0 { resources } => {
+                crate::codec::Encode::encode(resources, buf);
+            }
+            DescribeConfigsRequest::V1 {
+                resources,
+                include_synonyms,
+            } => {
+                crate::codec::Encode::encode(resources, buf);
+                crate::codec::Encode::encode(include_synonyms, buf);
+            }
+            DescribeConfigsRequest::V2 {
+                resources,
+                include_synonyms,
+            } => {
+                crate::codec::Encode::encode(resources, buf);
+                crate::codec::Encode::encode(include_synonyms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DescribeConfigsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeConfigsRequest::V0 {
+                resources: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeConfigsRequest::V1 {
+                resources: crate::codec::Decode::decode(buf, version)?,
+                include_synonyms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(DescribeConfigsRequest::V2 {
+                resources: crate::codec::Decode::decode(buf, version)?,
+                include_synonyms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeConfigsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod describe_configs_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6690,30 +14457,34 @@ pub mod describe_configs_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Resources {
-            /// null
-            pub resource_type: i8,
-            /// null
-            pub resource_name: String,
-            /// null
-            pub config_names: Vec<String>,
-        }
+        pub use super::v0::Resources;
     }
     pub mod v2 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Resources {
-            /// null
-            pub resource_type: i8,
-            /// null
-            pub resource_name: String,
-            /// null
-            pub config_names: Vec<String>,
-        }
+        pub use super::v0::Resources;
+    }
+}
+
+impl crate::codec::Encode for describe_configs_request::v0::Resources {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.config_names, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_configs_request::v0::Resources {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_configs_request::v0::Resources {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            config_names: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeConfigsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -6738,36 +14509,102 @@ pub enum DescribeConfigsResponse {
     },
 }
 
-pub mod describe_configs_response {
-    pub mod v0 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Resources {
-            /// Response error code
-            pub error_code: i16,
-            /// Response error message
-            pub error_message: crate::types::NullableString,
-            /// null
-            pub resource_type: i8,
-            /// null
-            pub resource_name: String,
-            /// null
-            pub config_entries: Vec<ConfigEntries>,
+impl crate::versions::ApiVersions for DescribeConfigsResponse {
+    const NAME: &'static str = "DescribeConfigsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 2;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=2),
+        ("resources", 0..=2),
+        ("resources.error_code", 0..=2),
+        ("resources.error_message", 0..=2),
+        ("resources.resource_type", 0..=2),
+        ("resources.resource_name", 0..=2),
+        ("resources.config_entries", 0..=2),
+        ("resources.config_entries.config_name", 0..=2),
+        ("resources.config_entries.config_value", 0..=2),
+        ("resources.config_entries.read_only", 0..=2),
+        ("resources.config_entries.is_default", 0..=0),
+        ("resources.config_entries.is_sensitive", 0..=2),
+        ("resources.config_entries.config_source", 1..=2),
+        ("resources.config_entries.config_synonyms", 1..=2),
+        (
+            "resources.config_entries.config_synonyms.config_name",
+            1..=2,
+        ),
+        (
+            "resources.config_entries.config_synonyms.config_value",
+            1..=2,
+        ),
+        (
+            "resources.config_entries.config_synonyms.config_source",
+            1..=2,
+        ),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeConfigsResponse::V0 { .. } => 0,
+            DescribeConfigsResponse::V1 { .. } => 1,
+            DescribeConfigsResponse::V2 { .. } => 2,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ConfigEntries {
-            /// null
-            pub config_name: String,
-            /// null
-            pub config_value: crate::types::NullableString,
-            /// null
-            pub read_only: bool,
-            /// null
-            pub is_default: bool,
-            /// null
-            pub is_sensitive: bool,
+    }
+}
+
+impl crate::codec::Encode for DescribeConfigsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeConfigsResponse::V0 {
+                throttle_time_ms,
+                resources,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(resources, buf);
+            }
+            DescribeConfigsResponse::V1 {
+                throttle_time_ms,
+                resources,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(resources, buf);
+            }
+            DescribeConfigsResponse::V2 {
+                throttle_time_ms,
+                resources,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(resources, buf);
+            }
         }
     }
-    pub mod v1 {
+}
+
+impl crate::codec::Decode for DescribeConfigsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeConfigsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                resources: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeConfigsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                resources: crate::codec::Decode::decode(buf, version)?,
+            }),
+            2 => Ok(DescribeConfigsResponse::V2 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                resources: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeConfigsResponse", version
+            ))),
+        }
+    }
+}
+
+pub mod describe_configs_response {
+    pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Resources {
             /// Response error code
@@ -6777,36 +14614,25 @@ pub mod describe_configs_response {
             /// null
             pub resource_type: i8,
             /// null
-            pub resource_name: String,
-            /// null
-            pub config_entries: Vec<ConfigEntries>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ConfigEntries {
-            /// null
-            pub config_name: String,
-            /// null
-            pub config_value: crate::types::NullableString,
-            /// null
-            pub read_only: bool,
-            /// null
-            pub config_source: i8,
-            /// null
-            pub is_sensitive: bool,
+            pub resource_name: String,
             /// null
-            pub config_synonyms: Vec<ConfigSynonyms>,
+            pub config_entries: Vec<ConfigEntries>,
         }
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ConfigSynonyms {
+        pub struct ConfigEntries {
             /// null
             pub config_name: String,
             /// null
             pub config_value: crate::types::NullableString,
             /// null
-            pub config_source: i8,
+            pub read_only: bool,
+            /// null
+            pub is_default: bool,
+            /// null
+            pub is_sensitive: bool,
         }
     }
-    pub mod v2 {
+    pub mod v1 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct Resources {
             /// Response error code
@@ -6845,9 +14671,124 @@ pub mod describe_configs_response {
             pub config_source: i8,
         }
     }
+    pub mod v2 {
+        pub use super::v1::ConfigEntries;
+        pub use super::v1::ConfigSynonyms;
+        pub use super::v1::Resources;
+    }
+}
+
+impl crate::codec::Encode for describe_configs_response::v0::Resources {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.config_entries, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_configs_response::v0::Resources {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_configs_response::v0::Resources {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            config_entries: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_configs_response::v0::ConfigEntries {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.config_name, buf);
+        crate::codec::Encode::encode(&self.config_value, buf);
+        crate::codec::Encode::encode(&self.read_only, buf);
+        crate::codec::Encode::encode(&self.is_default, buf);
+        crate::codec::Encode::encode(&self.is_sensitive, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_configs_response::v0::ConfigEntries {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_configs_response::v0::ConfigEntries {
+            config_name: crate::codec::Decode::decode(buf, version)?,
+            config_value: crate::codec::Decode::decode(buf, version)?,
+            read_only: crate::codec::Decode::decode(buf, version)?,
+            is_default: crate::codec::Decode::decode(buf, version)?,
+            is_sensitive: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_configs_response::v1::Resources {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.config_entries, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_configs_response::v1::Resources {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_configs_response::v1::Resources {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            config_entries: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_configs_response::v1::ConfigEntries {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.config_name, buf);
+        crate::codec::Encode::encode(&self.config_value, buf);
+        crate::codec::Encode::encode(&self.read_only, buf);
+        crate::codec::Encode::encode(&self.config_source, buf);
+        crate::codec::Encode::encode(&self.is_sensitive, buf);
+        crate::codec::Encode::encode(&self.config_synonyms, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_configs_response::v1::ConfigEntries {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_configs_response::v1::ConfigEntries {
+            config_name: crate::codec::Decode::decode(buf, version)?,
+            config_value: crate::codec::Decode::decode(buf, version)?,
+            read_only: crate::codec::Decode::decode(buf, version)?,
+            config_source: crate::codec::Decode::decode(buf, version)?,
+            is_sensitive: crate::codec::Decode::decode(buf, version)?,
+            config_synonyms: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for describe_configs_response::v1::ConfigSynonyms {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.config_name, buf);
+        crate::codec::Encode::encode(&self.config_value, buf);
+        crate::codec::Encode::encode(&self.config_source, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_configs_response::v1::ConfigSynonyms {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_configs_response::v1::ConfigSynonyms {
+            config_name: crate::codec::Decode::decode(buf, version)?,
+            config_value: crate::codec::Decode::decode(buf, version)?,
+            config_source: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum AlterConfigsRequest {
     V0 {
         /// An array of resources to update with the provided configs.
@@ -6863,6 +14804,74 @@ pub enum AlterConfigsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for AlterConfigsRequest {
+    const NAME: &'static str = "AlterConfigsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("resources", 0..=1),
+        ("resources.resource_type", 0..=1),
+        ("resources.resource_name", 0..=1),
+        ("resources.config_entries", 0..=1),
+        ("resources.config_entries.config_name", 0..=1),
+        ("resources.config_entries.config_value", 0..=1),
+        ("validate_only", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            AlterConfigsRequest::V0 { .. } => 0,
+            AlterConfigsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for AlterConfigsRequest {
+    const API_KEY: ApiKey = ApiKey::AlterConfigs;
+    type Response = AlterConfigsResponse;
+}
+
+impl crate::codec::Encode for AlterConfigsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            AlterConfigsRequest::V0 {
+                resources,
+                validate_only,
+            } => {
+                crate::codec::Encode::encode(resources, buf);
+                crate::codec::Encode::encode(validate_only, buf);
+            }
+            AlterConfigsRequest::V1 {
+                resources,
+                validate_only,
+            } => {
+                crate::codec::Encode::encode(resources, buf);
+                crate::codec::Encode::encode(validate_only, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for AlterConfigsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(AlterConfigsRequest::V0 {
+                resources: crate::codec::Decode::decode(buf, version)?,
+                validate_only: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(AlterConfigsRequest::V1 {
+                resources: crate::codec::Decode::decode(buf, version)?,
+                validate_only: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "AlterConfigsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod alter_configs_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6883,26 +14892,48 @@ pub mod alter_configs_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Resources {
-            /// null
-            pub resource_type: i8,
-            /// null
-            pub resource_name: String,
-            /// null
-            pub config_entries: Vec<ConfigEntries>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct ConfigEntries {
-            /// Configuration name
-            pub config_name: String,
-            /// Configuration value
-            pub config_value: crate::types::NullableString,
-        }
+        pub use super::v0::ConfigEntries;
+        pub use super::v0::Resources;
+    }
+}
+
+impl crate::codec::Encode for alter_configs_request::v0::Resources {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.config_entries, buf);
+    }
+}
+
+impl crate::codec::Decode for alter_configs_request::v0::Resources {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(alter_configs_request::v0::Resources {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            config_entries: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for alter_configs_request::v0::ConfigEntries {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.config_name, buf);
+        crate::codec::Encode::encode(&self.config_value, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for alter_configs_request::v0::ConfigEntries {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(alter_configs_request::v0::ConfigEntries {
+            config_name: crate::codec::Decode::decode(buf, version)?,
+            config_value: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum AlterConfigsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -6920,6 +14951,68 @@ pub enum AlterConfigsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for AlterConfigsResponse {
+    const NAME: &'static str = "AlterConfigsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("resources", 0..=1),
+        ("resources.error_code", 0..=1),
+        ("resources.error_message", 0..=1),
+        ("resources.resource_type", 0..=1),
+        ("resources.resource_name", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            AlterConfigsResponse::V0 { .. } => 0,
+            AlterConfigsResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for AlterConfigsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            AlterConfigsResponse::V0 {
+                throttle_time_ms,
+                resources,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(resources, buf);
+            }
+            AlterConfigsResponse::V1 {
+                throttle_time_ms,
+                resources,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(resources, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for AlterConfigsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(AlterConfigsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                resources: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(AlterConfigsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                resources: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "AlterConfigsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod alter_configs_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6935,21 +15028,33 @@ pub mod alter_configs_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Resources {
-            /// Response error code
-            pub error_code: i16,
-            /// Response error message
-            pub error_message: crate::types::NullableString,
-            /// null
-            pub resource_type: i8,
-            /// null
-            pub resource_name: String,
-        }
+        pub use super::v0::Resources;
+    }
+}
+
+impl crate::codec::Encode for alter_configs_response::v0::Resources {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for alter_configs_response::v0::Resources {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(alter_configs_response::v0::Resources {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum AlterReplicaLogDirsRequest {
     V0 {
         /// null
@@ -6961,6 +15066,62 @@ pub enum AlterReplicaLogDirsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for AlterReplicaLogDirsRequest {
+    const NAME: &'static str = "AlterReplicaLogDirsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("log_dirs", 0..=1),
+        ("log_dirs.log_dir", 0..=1),
+        ("log_dirs.topics", 0..=1),
+        ("log_dirs.topics.topic", 0..=1),
+        ("log_dirs.topics.partitions", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            AlterReplicaLogDirsRequest::V0 { .. } => 0,
+            AlterReplicaLogDirsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for AlterReplicaLogDirsRequest {
+    const API_KEY: ApiKey = ApiKey::AlterReplicaLogDirs;
+    type Response = AlterReplicaLogDirsResponse;
+}
+
+impl crate::codec::Encode for AlterReplicaLogDirsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            AlterReplicaLogDirsRequest::V0 { log_dirs } => {
+                crate::codec::Encode::encode(log_dirs, buf);
+            }
+            AlterReplicaLogDirsRequest::V1 { log_dirs } => {
+                crate::codec::Encode::encode(log_dirs, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for AlterReplicaLogDirsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(AlterReplicaLogDirsRequest::V0 {
+                log_dirs: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(AlterReplicaLogDirsRequest::V1 {
+                log_dirs: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "AlterReplicaLogDirsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod alter_replica_log_dirs_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -6979,24 +15140,46 @@ pub mod alter_replica_log_dirs_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct LogDirs {
-            /// The absolute log directory path.
-            pub log_dir: String,
-            /// null
-            pub topics: Vec<Topics>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// List of partition ids of the topic.
-            pub partitions: Vec<i32>,
-        }
+        pub use super::v0::LogDirs;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for alter_replica_log_dirs_request::v0::LogDirs {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.log_dir, buf);
+        crate::codec::Encode::encode(&self.topics, buf);
+    }
+}
+
+impl crate::codec::Decode for alter_replica_log_dirs_request::v0::LogDirs {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(alter_replica_log_dirs_request::v0::LogDirs {
+            log_dir: crate::codec::Decode::decode(buf, version)?,
+            topics: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for alter_replica_log_dirs_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for alter_replica_log_dirs_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(alter_replica_log_dirs_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum AlterReplicaLogDirsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -7014,6 +15197,68 @@ pub enum AlterReplicaLogDirsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for AlterReplicaLogDirsResponse {
+    const NAME: &'static str = "AlterReplicaLogDirsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("topics", 0..=1),
+        ("topics.topic", 0..=1),
+        ("topics.partitions", 0..=1),
+        ("topics.partitions.partition", 0..=1),
+        ("topics.partitions.error_code", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            AlterReplicaLogDirsResponse::V0 { .. } => 0,
+            AlterReplicaLogDirsResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for AlterReplicaLogDirsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            AlterReplicaLogDirsResponse::V0 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+            AlterReplicaLogDirsResponse::V1 {
+                throttle_time_ms,
+                topics,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for AlterReplicaLogDirsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(AlterReplicaLogDirsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(AlterReplicaLogDirsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "AlterReplicaLogDirsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod alter_replica_log_dirs_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7032,24 +15277,46 @@ pub mod alter_replica_log_dirs_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partitions: Vec<Partitions>,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for alter_replica_log_dirs_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for alter_replica_log_dirs_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(alter_replica_log_dirs_response::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for alter_replica_log_dirs_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for alter_replica_log_dirs_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(alter_replica_log_dirs_response::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeLogDirsRequest {
     V0 {
         /// null
@@ -7061,6 +15328,60 @@ pub enum DescribeLogDirsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for DescribeLogDirsRequest {
+    const NAME: &'static str = "DescribeLogDirsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topics", 0..=1),
+        ("topics.topic", 0..=1),
+        ("topics.partitions", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeLogDirsRequest::V0 { .. } => 0,
+            DescribeLogDirsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for DescribeLogDirsRequest {
+    const API_KEY: ApiKey = ApiKey::DescribeLogDirs;
+    type Response = DescribeLogDirsResponse;
+}
+
+impl crate::codec::Encode for DescribeLogDirsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeLogDirsRequest::V0 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+            DescribeLogDirsRequest::V1 { topics } => {
+                crate::codec::Encode::encode(topics, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DescribeLogDirsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeLogDirsRequest::V0 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeLogDirsRequest::V1 {
+                topics: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeLogDirsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod describe_log_dirs_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7072,17 +15393,29 @@ pub mod describe_log_dirs_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// List of partition ids of the topic.
-            pub partitions: Vec<i32>,
-        }
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for describe_log_dirs_request::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_log_dirs_request::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_log_dirs_request::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeLogDirsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -7100,40 +15433,75 @@ pub enum DescribeLogDirsResponse {
     },
 }
 
-pub mod describe_log_dirs_response {
-    pub mod v0 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct LogDirs {
-            /// Response error code
-            pub error_code: i16,
-            /// The absolute log directory path.
-            pub log_dir: String,
-            /// null
-            pub topics: Vec<Topics>,
+impl crate::versions::ApiVersions for DescribeLogDirsResponse {
+    const NAME: &'static str = "DescribeLogDirsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("log_dirs", 0..=1),
+        ("log_dirs.error_code", 0..=1),
+        ("log_dirs.log_dir", 0..=1),
+        ("log_dirs.topics", 0..=1),
+        ("log_dirs.topics.topic", 0..=1),
+        ("log_dirs.topics.partitions", 0..=1),
+        ("log_dirs.topics.partitions.partition", 0..=1),
+        ("log_dirs.topics.partitions.size", 0..=1),
+        ("log_dirs.topics.partitions.offset_lag", 0..=1),
+        ("log_dirs.topics.partitions.is_future", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeLogDirsResponse::V0 { .. } => 0,
+            DescribeLogDirsResponse::V1 { .. } => 1,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Topics {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub partitions: Vec<Partitions>,
+    }
+}
+
+impl crate::codec::Encode for DescribeLogDirsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeLogDirsResponse::V0 {
+                throttle_time_ms,
+                log_dirs,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(log_dirs, buf);
+            }
+            DescribeLogDirsResponse::V1 {
+                throttle_time_ms,
+                log_dirs,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(log_dirs, buf);
+            }
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Partitions {
-            /// Topic partition id
-            pub partition: i32,
-            /// The size of the log segments of the partition in bytes.
-            pub size: i64,
-            /// The lag of the log's LEO w.r.t. partition's HW (if it is the current
-            /// log for the partition) or current replica's LEO (if it is the future
-            /// log for the partition)
-            pub offset_lag: i64,
-            /// True if this log is created by AlterReplicaLogDirsRequest and will
-            /// replace the current log of the replica in the future.
-            pub is_future: bool,
+    }
+}
+
+impl crate::codec::Decode for DescribeLogDirsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeLogDirsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                log_dirs: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeLogDirsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                log_dirs: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeLogDirsResponse", version
+            ))),
         }
     }
-    pub mod v1 {
+}
+
+pub mod describe_log_dirs_response {
+    pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct LogDirs {
             /// Response error code
@@ -7165,9 +15533,70 @@ pub mod describe_log_dirs_response {
             pub is_future: bool,
         }
     }
+    pub mod v1 {
+        pub use super::v0::LogDirs;
+        pub use super::v0::Partitions;
+        pub use super::v0::Topics;
+    }
+}
+
+impl crate::codec::Encode for describe_log_dirs_response::v0::LogDirs {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.log_dir, buf);
+        crate::codec::Encode::encode(&self.topics, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_log_dirs_response::v0::LogDirs {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_log_dirs_response::v0::LogDirs {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            log_dir: crate::codec::Decode::decode(buf, version)?,
+            topics: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for describe_log_dirs_response::v0::Topics {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_log_dirs_response::v0::Topics {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_log_dirs_response::v0::Topics {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_log_dirs_response::v0::Partitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition, buf);
+        crate::codec::Encode::encode(&self.size, buf);
+        crate::codec::Encode::encode(&self.offset_lag, buf);
+        crate::codec::Encode::encode(&self.is_future, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_log_dirs_response::v0::Partitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_log_dirs_response::v0::Partitions {
+            partition: crate::codec::Decode::decode(buf, version)?,
+            size: crate::codec::Decode::decode(buf, version)?,
+            offset_lag: crate::codec::Decode::decode(buf, version)?,
+            is_future: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum SaslAuthenticateRequest {
     V0 {
         /// The SASL authentication bytes from the client, as defined by the SASL
@@ -7181,7 +15610,60 @@ pub enum SaslAuthenticateRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for SaslAuthenticateRequest {
+    const NAME: &'static str = "SaslAuthenticateRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("auth_bytes", 0..=1)];
+
+    fn version(&self) -> i16 {
+        match self {
+            SaslAuthenticateRequest::V0 { .. } => 0,
+            SaslAuthenticateRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for SaslAuthenticateRequest {
+    const API_KEY: ApiKey = ApiKey::SaslAuthenticate;
+    type Response = SaslAuthenticateResponse;
+}
+
+impl crate::codec::Encode for SaslAuthenticateRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            SaslAuthenticateRequest::V0 { auth_bytes } => {
+                crate::codec::Encode::encode(auth_bytes, buf);
+            }
+            SaslAuthenticateRequest::V1 { auth_bytes } => {
+                crate::codec::Encode::encode(auth_bytes, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for SaslAuthenticateRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(SaslAuthenticateRequest::V0 {
+                auth_bytes: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(SaslAuthenticateRequest::V1 {
+                auth_bytes: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "SaslAuthenticateRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum SaslAuthenticateResponse {
     V0 {
         /// The error code, or 0 if there was no error.
@@ -7206,7 +15688,78 @@ pub enum SaslAuthenticateResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for SaslAuthenticateResponse {
+    const NAME: &'static str = "SaslAuthenticateResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=1),
+        ("error_message", 0..=1),
+        ("auth_bytes", 0..=1),
+        ("session_lifetime_ms", 1..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            SaslAuthenticateResponse::V0 { .. } => 0,
+            SaslAuthenticateResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for SaslAuthenticateResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            SaslAuthenticateResponse::V0 {
+                error_code,
+                error_message,
+                auth_bytes,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(error_message, buf);
+                crate::codec::Encode::encode(auth_bytes, buf);
+            }
+            SaslAuthenticateResponse::V1 {
+                error_code,
+                error_message,
+                auth_bytes,
+                session_lifetime_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(error_message, buf);
+                crate::codec::Encode::encode(auth_bytes, buf);
+                crate::codec::Encode::encode(session_lifetime_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for SaslAuthenticateResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(SaslAuthenticateResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                error_message: crate::codec::Decode::decode(buf, version)?,
+                auth_bytes: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(SaslAuthenticateResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                error_message: crate::codec::Decode::decode(buf, version)?,
+                auth_bytes: crate::codec::Decode::decode(buf, version)?,
+                session_lifetime_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "SaslAuthenticateResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum CreatePartitionsRequest {
     V0 {
         /// List of topic and the corresponding new partitions.
@@ -7228,6 +15781,80 @@ pub enum CreatePartitionsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for CreatePartitionsRequest {
+    const NAME: &'static str = "CreatePartitionsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topic_partitions", 0..=1),
+        ("topic_partitions.topic", 0..=1),
+        ("topic_partitions.new_partitions", 0..=1),
+        ("topic_partitions.new_partitions.count", 0..=1),
+        ("topic_partitions.new_partitions.assignment", 0..=1),
+        ("timeout", 0..=1),
+        ("validate_only", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            CreatePartitionsRequest::V0 { .. } => 0,
+            CreatePartitionsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for CreatePartitionsRequest {
+    const API_KEY: ApiKey = ApiKey::CreatePartitions;
+    type Response = CreatePartitionsResponse;
+}
+
+impl crate::codec::Encode for CreatePartitionsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CreatePartitionsRequest::V0 {
+                topic_partitions,
+                timeout,
+                validate_only,
+            } => {
+                crate::codec::Encode::encode(topic_partitions, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(validate_only, buf);
+            }
+            CreatePartitionsRequest::V1 {
+                topic_partitions,
+                timeout,
+                validate_only,
+            } => {
+                crate::codec::Encode::encode(topic_partitions, buf);
+                crate::codec::Encode::encode(timeout, buf);
+                crate::codec::Encode::encode(validate_only, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for CreatePartitionsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(CreatePartitionsRequest::V0 {
+                topic_partitions: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                validate_only: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(CreatePartitionsRequest::V1 {
+                topic_partitions: crate::codec::Decode::decode(buf, version)?,
+                timeout: crate::codec::Decode::decode(buf, version)?,
+                validate_only: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "CreatePartitionsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod create_partitions_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7246,24 +15873,46 @@ pub mod create_partitions_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicPartitions {
-            /// Name of topic
-            pub topic: String,
-            /// null
-            pub new_partitions: NewPartitions,
-        }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct NewPartitions {
-            /// The new partition count.
-            pub count: i32,
-            /// The assigned brokers.
-            pub assignment: Vec<Vec<i32>>,
-        }
+        pub use super::v0::NewPartitions;
+        pub use super::v0::TopicPartitions;
+    }
+}
+
+impl crate::codec::Encode for create_partitions_request::v0::TopicPartitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.new_partitions, buf);
+    }
+}
+
+impl crate::codec::Decode for create_partitions_request::v0::TopicPartitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_partitions_request::v0::TopicPartitions {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            new_partitions: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for create_partitions_request::v0::NewPartitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.count, buf);
+        crate::codec::Encode::encode(&self.assignment, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for create_partitions_request::v0::NewPartitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_partitions_request::v0::NewPartitions {
+            count: crate::codec::Decode::decode(buf, version)?,
+            assignment: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum CreatePartitionsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -7281,6 +15930,67 @@ pub enum CreatePartitionsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for CreatePartitionsResponse {
+    const NAME: &'static str = "CreatePartitionsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("topic_errors", 0..=1),
+        ("topic_errors.topic", 0..=1),
+        ("topic_errors.error_code", 0..=1),
+        ("topic_errors.error_message", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            CreatePartitionsResponse::V0 { .. } => 0,
+            CreatePartitionsResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for CreatePartitionsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CreatePartitionsResponse::V0 {
+                throttle_time_ms,
+                topic_errors,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topic_errors, buf);
+            }
+            CreatePartitionsResponse::V1 {
+                throttle_time_ms,
+                topic_errors,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(topic_errors, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for CreatePartitionsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(CreatePartitionsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topic_errors: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(CreatePartitionsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                topic_errors: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "CreatePartitionsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod create_partitions_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7294,19 +16004,31 @@ pub mod create_partitions_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TopicErrors {
-            /// Name of topic
-            pub topic: String,
-            /// Response error code
-            pub error_code: i16,
-            /// Response error message
-            pub error_message: crate::types::NullableString,
-        }
+        pub use super::v0::TopicErrors;
+    }
+}
+
+impl crate::codec::Encode for create_partitions_response::v0::TopicErrors {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+    }
+}
+
+impl crate::codec::Decode for create_partitions_response::v0::TopicErrors {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_partitions_response::v0::TopicErrors {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum CreateDelegationTokenRequest {
     V0 {
         /// An array of token renewers. Renewer is an Kafka PrincipalType and name
@@ -7328,6 +16050,71 @@ pub enum CreateDelegationTokenRequest {
     },
 }
 
+impl crate::versions::ApiVersions for CreateDelegationTokenRequest {
+    const NAME: &'static str = "CreateDelegationTokenRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("renewers", 0..=1),
+        ("renewers.principal_type", 0..=1),
+        ("renewers.name", 0..=1),
+        ("max_life_time", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            CreateDelegationTokenRequest::V0 { .. } => 0,
+            CreateDelegationTokenRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for CreateDelegationTokenRequest {
+    const API_KEY: ApiKey = ApiKey::CreateDelegationToken;
+    type Response = CreateDelegationTokenResponse;
+}
+
+impl crate::codec::Encode for CreateDelegationTokenRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CreateDelegationTokenRequest::V0 {
+                renewers,
+                max_life_time,
+            } => {
+                crate::codec::Encode::encode(renewers, buf);
+                crate::codec::Encode::encode(max_life_time, buf);
+            }
+            CreateDelegationTokenRequest::V1 {
+                renewers,
+                max_life_time,
+            } => {
+                crate::codec::Encode::encode(renewers, buf);
+                crate::codec::Encode::encode(max_life_time, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for CreateDelegationTokenRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(CreateDelegationTokenRequest::V0 {
+                renewers: crate::codec::Decode::decode(buf, version)?,
+                max_life_time: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(CreateDelegationTokenRequest::V1 {
+                renewers: crate::codec::Decode::decode(buf, version)?,
+                max_life_time: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "CreateDelegationTokenRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod create_delegation_token_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7339,17 +16126,29 @@ pub mod create_delegation_token_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Renewers {
-            /// principalType of the Kafka principal
-            pub principal_type: String,
-            /// name of the Kafka principal
-            pub name: String,
-        }
+        pub use super::v0::Renewers;
+    }
+}
+
+impl crate::codec::Encode for create_delegation_token_request::v0::Renewers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.principal_type, buf);
+        crate::codec::Encode::encode(&self.name, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for create_delegation_token_request::v0::Renewers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_delegation_token_request::v0::Renewers {
+            principal_type: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum CreateDelegationTokenResponse {
     V0 {
         /// Response error code
@@ -7391,6 +16190,108 @@ pub enum CreateDelegationTokenResponse {
     },
 }
 
+impl crate::versions::ApiVersions for CreateDelegationTokenResponse {
+    const NAME: &'static str = "CreateDelegationTokenResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=1),
+        ("owner", 0..=1),
+        ("owner.principal_type", 0..=1),
+        ("owner.name", 0..=1),
+        ("issue_timestamp", 0..=1),
+        ("expiry_timestamp", 0..=1),
+        ("max_timestamp", 0..=1),
+        ("token_id", 0..=1),
+        ("hmac", 0..=1),
+        ("throttle_time_ms", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            CreateDelegationTokenResponse::V0 { .. } => 0,
+            CreateDelegationTokenResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for CreateDelegationTokenResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CreateDelegationTokenResponse::V0 {
+                error_code,
+                owner,
+                issue_timestamp,
+                expiry_timestamp,
+                max_timestamp,
+                token_id,
+                hmac,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(owner, buf);
+                crate::codec::Encode::encode(issue_timestamp, buf);
+                crate::codec::Encode::encode(expiry_timestamp, buf);
+                crate::codec::Encode::encode(max_timestamp, buf);
+                crate::codec::Encode::encode(token_id, buf);
+                crate::codec::Encode::encode(hmac, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            CreateDelegationTokenResponse::V1 {
+                error_code,
+                owner,
+                issue_timestamp,
+                expiry_timestamp,
+                max_timestamp,
+                token_id,
+                hmac,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(owner, buf);
+                crate::codec::Encode::encode(issue_timestamp, buf);
+                crate::codec::Encode::encode(expiry_timestamp, buf);
+                crate::codec::Encode::encode(max_timestamp, buf);
+                crate::codec::Encode::encode(token_id, buf);
+                crate::codec::Encode::encode(hmac, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for CreateDelegationTokenResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(CreateDelegationTokenResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                owner: crate::codec::Decode::decode(buf, version)?,
+                issue_timestamp: crate::codec::Decode::decode(buf, version)?,
+                expiry_timestamp: crate::codec::Decode::decode(buf, version)?,
+                max_timestamp: crate::codec::Decode::decode(buf, version)?,
+                token_id: crate::codec::Decode::decode(buf, version)?,
+                hmac: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(CreateDelegationTokenResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                owner: crate::codec::Decode::decode(buf, version)?,
+                issue_timestamp: crate::codec::Decode::decode(buf, version)?,
+                expiry_timestamp: crate::codec::Decode::decode(buf, version)?,
+                max_timestamp: crate::codec::Decode::decode(buf, version)?,
+                token_id: crate::codec::Decode::decode(buf, version)?,
+                hmac: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "CreateDelegationTokenResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod create_delegation_token_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7402,17 +16303,29 @@ pub mod create_delegation_token_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Owner {
-            /// principalType of the Kafka principal
-            pub principal_type: String,
-            /// name of the Kafka principal
-            pub name: String,
-        }
+        pub use super::v0::Owner;
+    }
+}
+
+impl crate::codec::Encode for create_delegation_token_response::v0::Owner {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.principal_type, buf);
+        crate::codec::Encode::encode(&self.name, buf);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for create_delegation_token_response::v0::Owner {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(create_delegation_token_response::v0::Owner {
+            principal_type: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum RenewDelegationTokenRequest {
     V0 {
         /// HMAC of the delegation token to be renewed.
@@ -7428,7 +16341,70 @@ pub enum RenewDelegationTokenRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for RenewDelegationTokenRequest {
+    const NAME: &'static str = "RenewDelegationTokenRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("hmac", 0..=1), ("renew_time_period", 0..=1)];
+
+    fn version(&self) -> i16 {
+        match self {
+            RenewDelegationTokenRequest::V0 { .. } => 0,
+            RenewDelegationTokenRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for RenewDelegationTokenRequest {
+    const API_KEY: ApiKey = ApiKey::RenewDelegationToken;
+    type Response = RenewDelegationTokenResponse;
+}
+
+impl crate::codec::Encode for RenewDelegationTokenRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            RenewDelegationTokenRequest::V0 {
+                hmac,
+                renew_time_period,
+            } => {
+                crate::codec::Encode::encode(hmac, buf);
+                crate::codec::Encode::encode(renew_time_period, buf);
+            }
+            RenewDelegationTokenRequest::V1 {
+                hmac,
+                renew_time_period,
+            } => {
+                crate::codec::Encode::encode(hmac, buf);
+                crate::codec::Encode::encode(renew_time_period, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for RenewDelegationTokenRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(RenewDelegationTokenRequest::V0 {
+                hmac: crate::codec::Decode::decode(buf, version)?,
+                renew_time_period: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(RenewDelegationTokenRequest::V1 {
+                hmac: crate::codec::Decode::decode(buf, version)?,
+                renew_time_period: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "RenewDelegationTokenRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum RenewDelegationTokenResponse {
     V0 {
         /// Response error code
@@ -7450,7 +16426,74 @@ pub enum RenewDelegationTokenResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for RenewDelegationTokenResponse {
+    const NAME: &'static str = "RenewDelegationTokenResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=1),
+        ("expiry_timestamp", 0..=1),
+        ("throttle_time_ms", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            RenewDelegationTokenResponse::V0 { .. } => 0,
+            RenewDelegationTokenResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for RenewDelegationTokenResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            RenewDelegationTokenResponse::V0 {
+                error_code,
+                expiry_timestamp,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(expiry_timestamp, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            RenewDelegationTokenResponse::V1 {
+                error_code,
+                expiry_timestamp,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(expiry_timestamp, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for RenewDelegationTokenResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(RenewDelegationTokenResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                expiry_timestamp: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(RenewDelegationTokenResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                expiry_timestamp: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "RenewDelegationTokenResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ExpireDelegationTokenRequest {
     V0 {
         /// HMAC of the delegation token to be expired.
@@ -7466,7 +16509,70 @@ pub enum ExpireDelegationTokenRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for ExpireDelegationTokenRequest {
+    const NAME: &'static str = "ExpireDelegationTokenRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("hmac", 0..=1), ("expiry_time_period", 0..=1)];
+
+    fn version(&self) -> i16 {
+        match self {
+            ExpireDelegationTokenRequest::V0 { .. } => 0,
+            ExpireDelegationTokenRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for ExpireDelegationTokenRequest {
+    const API_KEY: ApiKey = ApiKey::ExpireDelegationToken;
+    type Response = ExpireDelegationTokenResponse;
+}
+
+impl crate::codec::Encode for ExpireDelegationTokenRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ExpireDelegationTokenRequest::V0 {
+                hmac,
+                expiry_time_period,
+            } => {
+                crate::codec::Encode::encode(hmac, buf);
+                crate::codec::Encode::encode(expiry_time_period, buf);
+            }
+            ExpireDelegationTokenRequest::V1 {
+                hmac,
+                expiry_time_period,
+            } => {
+                crate::codec::Encode::encode(hmac, buf);
+                crate::codec::Encode::encode(expiry_time_period, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ExpireDelegationTokenRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ExpireDelegationTokenRequest::V0 {
+                hmac: crate::codec::Decode::decode(buf, version)?,
+                expiry_time_period: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ExpireDelegationTokenRequest::V1 {
+                hmac: crate::codec::Decode::decode(buf, version)?,
+                expiry_time_period: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ExpireDelegationTokenRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ExpireDelegationTokenResponse {
     V0 {
         /// Response error code
@@ -7488,7 +16594,74 @@ pub enum ExpireDelegationTokenResponse {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for ExpireDelegationTokenResponse {
+    const NAME: &'static str = "ExpireDelegationTokenResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=1),
+        ("expiry_timestamp", 0..=1),
+        ("throttle_time_ms", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ExpireDelegationTokenResponse::V0 { .. } => 0,
+            ExpireDelegationTokenResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for ExpireDelegationTokenResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ExpireDelegationTokenResponse::V0 {
+                error_code,
+                expiry_timestamp,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(expiry_timestamp, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            ExpireDelegationTokenResponse::V1 {
+                error_code,
+                expiry_timestamp,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(expiry_timestamp, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ExpireDelegationTokenResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ExpireDelegationTokenResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                expiry_timestamp: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(ExpireDelegationTokenResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                expiry_timestamp: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ExpireDelegationTokenResponse", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeDelegationTokenRequest {
     V0 {
         /// An array of token owners.
@@ -7500,6 +16673,60 @@ pub enum DescribeDelegationTokenRequest {
     },
 }
 
+impl crate::versions::ApiVersions for DescribeDelegationTokenRequest {
+    const NAME: &'static str = "DescribeDelegationTokenRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("owners", 0..=1),
+        ("owners.principal_type", 0..=1),
+        ("owners.name", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeDelegationTokenRequest::V0 { .. } => 0,
+            DescribeDelegationTokenRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for DescribeDelegationTokenRequest {
+    const API_KEY: ApiKey = ApiKey::DescribeDelegationToken;
+    type Response = DescribeDelegationTokenResponse;
+}
+
+impl crate::codec::Encode for DescribeDelegationTokenRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeDelegationTokenRequest::V0 { owners } => {
+                crate::codec::Encode::encode(owners, buf);
+            }
+            DescribeDelegationTokenRequest::V1 { owners } => {
+                crate::codec::Encode::encode(owners, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DescribeDelegationTokenRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeDelegationTokenRequest::V0 {
+                owners: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeDelegationTokenRequest::V1 {
+                owners: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeDelegationTokenRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod describe_delegation_token_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7511,17 +16738,29 @@ pub mod describe_delegation_token_request {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Owners {
-            /// principalType of the Kafka principal
-            pub principal_type: String,
-            /// name of the Kafka principal
-            pub name: String,
-        }
+        pub use super::v0::Owners;
+    }
+}
+
+impl crate::codec::Encode for describe_delegation_token_request::v0::Owners {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.principal_type, buf);
+        crate::codec::Encode::encode(&self.name, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_delegation_token_request::v0::Owners {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_delegation_token_request::v0::Owners {
+            principal_type: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DescribeDelegationTokenResponse {
     V0 {
         /// Response error code
@@ -7543,43 +16782,84 @@ pub enum DescribeDelegationTokenResponse {
     },
 }
 
-pub mod describe_delegation_token_response {
-    pub mod v0 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct TokenDetails {
-            /// token owner.
-            pub owner: Owner,
-            /// timestamp (in msec) when this token was generated.
-            pub issue_timestamp: i64,
-            /// timestamp (in msec) at which this token expires.
-            pub expiry_timestamp: i64,
-            /// max life time of this token.
-            pub max_timestamp: i64,
-            /// UUID to ensure uniqueness.
-            pub token_id: String,
-            /// HMAC of the delegation token to be expired.
-            pub hmac: crate::types::Bytes,
-            /// An array of token renewers. Renewer is an Kafka PrincipalType and name
-            /// string, who is allowed to renew this token before the max lifetime
-            /// expires.
-            pub renewers: Vec<Renewers>,
+impl crate::versions::ApiVersions for DescribeDelegationTokenResponse {
+    const NAME: &'static str = "DescribeDelegationTokenResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("error_code", 0..=1),
+        ("token_details", 0..=1),
+        ("token_details.owner", 0..=1),
+        ("token_details.owner.principal_type", 0..=1),
+        ("token_details.owner.name", 0..=1),
+        ("token_details.issue_timestamp", 0..=1),
+        ("token_details.expiry_timestamp", 0..=1),
+        ("token_details.max_timestamp", 0..=1),
+        ("token_details.token_id", 0..=1),
+        ("token_details.hmac", 0..=1),
+        ("token_details.renewers", 0..=1),
+        ("token_details.renewers.principal_type", 0..=1),
+        ("token_details.renewers.name", 0..=1),
+        ("throttle_time_ms", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DescribeDelegationTokenResponse::V0 { .. } => 0,
+            DescribeDelegationTokenResponse::V1 { .. } => 1,
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Owner {
-            /// principalType of the Kafka principal
-            pub principal_type: String,
-            /// name of the Kafka principal
-            pub name: String,
+    }
+}
+
+impl crate::codec::Encode for DescribeDelegationTokenResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DescribeDelegationTokenResponse::V0 {
+                error_code,
+                token_details,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(token_details, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
+            DescribeDelegationTokenResponse::V1 {
+                error_code,
+                token_details,
+                throttle_time_ms,
+            } => {
+                crate::codec::Encode::encode(error_code, buf);
+                crate::codec::Encode::encode(token_details, buf);
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+            }
         }
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct Renewers {
-            /// principalType of the Kafka principal
-            pub principal_type: String,
-            /// name of the Kafka principal
-            pub name: String,
+    }
+}
+
+impl crate::codec::Decode for DescribeDelegationTokenResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DescribeDelegationTokenResponse::V0 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                token_details: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DescribeDelegationTokenResponse::V1 {
+                error_code: crate::codec::Decode::decode(buf, version)?,
+                token_details: crate::codec::Decode::decode(buf, version)?,
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DescribeDelegationTokenResponse", version
+            ))),
         }
     }
-    pub mod v1 {
+}
+
+pub mod describe_delegation_token_response {
+    pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
         pub struct TokenDetails {
             /// token owner.
@@ -7614,9 +16894,74 @@ pub mod describe_delegation_token_response {
             pub name: String,
         }
     }
+    pub mod v1 {
+        pub use super::v0::Owner;
+        pub use super::v0::Renewers;
+        pub use super::v0::TokenDetails;
+    }
+}
+
+impl crate::codec::Encode for describe_delegation_token_response::v0::TokenDetails {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.owner, buf);
+        crate::codec::Encode::encode(&self.issue_timestamp, buf);
+        crate::codec::Encode::encode(&self.expiry_timestamp, buf);
+        crate::codec::Encode::encode(&self.max_timestamp, buf);
+        crate::codec::Encode::encode(&self.token_id, buf);
+        crate::codec::Encode::encode(&self.hmac, buf);
+        crate::codec::Encode::encode(&self.renewers, buf);
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Decode for describe_delegation_token_response::v0::TokenDetails {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_delegation_token_response::v0::TokenDetails {
+            owner: crate::codec::Decode::decode(buf, version)?,
+            issue_timestamp: crate::codec::Decode::decode(buf, version)?,
+            expiry_timestamp: crate::codec::Decode::decode(buf, version)?,
+            max_timestamp: crate::codec::Decode::decode(buf, version)?,
+            token_id: crate::codec::Decode::decode(buf, version)?,
+            hmac: crate::codec::Decode::decode(buf, version)?,
+            renewers: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_delegation_token_response::v0::Owner {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.principal_type, buf);
+        crate::codec::Encode::encode(&self.name, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_delegation_token_response::v0::Owner {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_delegation_token_response::v0::Owner {
+            principal_type: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for describe_delegation_token_response::v0::Renewers {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.principal_type, buf);
+        crate::codec::Encode::encode(&self.name, buf);
+    }
+}
+
+impl crate::codec::Decode for describe_delegation_token_response::v0::Renewers {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(describe_delegation_token_response::v0::Renewers {
+            principal_type: crate::codec::Decode::decode(buf, version)?,
+            name: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DeleteGroupsRequest {
     V0 {
         /// An array of groups to be deleted.
@@ -7628,7 +16973,60 @@ pub enum DeleteGroupsRequest {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::versions::ApiVersions for DeleteGroupsRequest {
+    const NAME: &'static str = "DeleteGroupsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] =
+        &[("groups", 0..=1)];
+
+    fn version(&self) -> i16 {
+        match self {
+            DeleteGroupsRequest::V0 { .. } => 0,
+            DeleteGroupsRequest::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::request::Request for DeleteGroupsRequest {
+    const API_KEY: ApiKey = ApiKey::DeleteGroups;
+    type Response = DeleteGroupsResponse;
+}
+
+impl crate::codec::Encode for DeleteGroupsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DeleteGroupsRequest::V0 { groups } => {
+                crate::codec::Encode::encode(groups, buf);
+            }
+            DeleteGroupsRequest::V1 { groups } => {
+                crate::codec::Encode::encode(groups, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DeleteGroupsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DeleteGroupsRequest::V0 {
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DeleteGroupsRequest::V1 {
+                groups: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DeleteGroupsRequest", version
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum DeleteGroupsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to
@@ -7646,6 +17044,66 @@ pub enum DeleteGroupsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for DeleteGroupsResponse {
+    const NAME: &'static str = "DeleteGroupsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 1;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=1),
+        ("group_error_codes", 0..=1),
+        ("group_error_codes.group_id", 0..=1),
+        ("group_error_codes.error_code", 0..=1),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            DeleteGroupsResponse::V0 { .. } => 0,
+            DeleteGroupsResponse::V1 { .. } => 1,
+        }
+    }
+}
+
+impl crate::codec::Encode for DeleteGroupsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            DeleteGroupsResponse::V0 {
+                throttle_time_ms,
+                group_error_codes,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(group_error_codes, buf);
+            }
+            DeleteGroupsResponse::V1 {
+                throttle_time_ms,
+                group_error_codes,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(group_error_codes, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for DeleteGroupsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(DeleteGroupsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                group_error_codes: crate::codec::Decode::decode(buf, version)?,
+            }),
+            1 => Ok(DeleteGroupsResponse::V1 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                group_error_codes: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "DeleteGroupsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod delete_groups_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7657,17 +17115,29 @@ pub mod delete_groups_response {
         }
     }
     pub mod v1 {
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-        pub struct GroupErrorCodes {
-            /// The unique group identifier
-            pub group_id: String,
-            /// Response error code
-            pub error_code: i16,
-        }
+        pub use super::v0::GroupErrorCodes;
+    }
+}
+
+impl crate::codec::Encode for delete_groups_response::v0::GroupErrorCodes {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.group_id, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+    }
+}
+
+impl crate::codec::Decode for delete_groups_response::v0::GroupErrorCodes {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(delete_groups_response::v0::GroupErrorCodes {
+            group_id: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+        })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ElectPreferredLeadersRequest {
     V0 {
         /// The topic partitions to elect the preferred leader of.
@@ -7677,6 +17147,59 @@ pub enum ElectPreferredLeadersRequest {
     },
 }
 
+impl crate::versions::ApiVersions for ElectPreferredLeadersRequest {
+    const NAME: &'static str = "ElectPreferredLeadersRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 0;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("topic_partitions", 0..=0),
+        ("topic_partitions.topic", 0..=0),
+        ("topic_partitions.partition_id", 0..=0),
+        ("timeout_ms", 0..=0),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ElectPreferredLeadersRequest::V0 { .. } => 0,
+        }
+    }
+}
+
+impl crate::request::Request for ElectPreferredLeadersRequest {
+    const API_KEY: ApiKey = ApiKey::ElectPreferredLeaders;
+    type Response = ElectPreferredLeadersResponse;
+}
+
+impl crate::codec::Encode for ElectPreferredLeadersRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ElectPreferredLeadersRequest::V0 {
+                topic_partitions,
+                timeout_ms,
+            } => {
+                crate::codec::Encode::encode(topic_partitions, buf);
+                crate::codec::Encode::encode(timeout_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ElectPreferredLeadersRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ElectPreferredLeadersRequest::V0 {
+                topic_partitions: crate::codec::Decode::decode(buf, version)?,
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ElectPreferredLeadersRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod elect_preferred_leaders_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7689,7 +17212,25 @@ pub mod elect_preferred_leaders_request {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for elect_preferred_leaders_request::v0::TopicPartitions {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_id, buf);
+    }
+}
+
+impl crate::codec::Decode for elect_preferred_leaders_request::v0::TopicPartitions {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(elect_preferred_leaders_request::v0::TopicPartitions {
+            topic: crate::codec::Decode::decode(buf, version)?,
+            partition_id: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum ElectPreferredLeadersResponse {
     V0 {
         /// The duration in milliseconds for which the request was throttled due
@@ -7701,6 +17242,66 @@ pub enum ElectPreferredLeadersResponse {
     },
 }
 
+impl crate::versions::ApiVersions for ElectPreferredLeadersResponse {
+    const NAME: &'static str = "ElectPreferredLeadersResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 0;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=0),
+        ("replica_election_results", 0..=0),
+        ("replica_election_results.topic", 0..=0),
+        ("replica_election_results.partition_result", 0..=0),
+        (
+            "replica_election_results.partition_result.partition_id",
+            0..=0,
+        ),
+        (
+            "replica_election_results.partition_result.error_code",
+            0..=0,
+        ),
+        (
+            "replica_election_results.partition_result.error_message",
+            0..=0,
+        ),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            ElectPreferredLeadersResponse::V0 { .. } => 0,
+        }
+    }
+}
+
+impl crate::codec::Encode for ElectPreferredLeadersResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ElectPreferredLeadersResponse::V0 {
+                throttle_time_ms,
+                replica_election_results,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(replica_election_results, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for ElectPreferredLeadersResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(ElectPreferredLeadersResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                replica_election_results: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "ElectPreferredLeadersResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod elect_preferred_leaders_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7722,7 +17323,45 @@ pub mod elect_preferred_leaders_response {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for elect_preferred_leaders_response::v0::ReplicaElectionResults {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.topic, buf);
+        crate::codec::Encode::encode(&self.partition_result, buf);
+    }
+}
+
+impl crate::codec::Decode for elect_preferred_leaders_response::v0::ReplicaElectionResults {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(
+            elect_preferred_leaders_response::v0::ReplicaElectionResults {
+                topic: crate::codec::Decode::decode(buf, version)?,
+                partition_result: crate::codec::Decode::decode(buf, version)?,
+            },
+        )
+    }
+}
+
+impl crate::codec::Encode for elect_preferred_leaders_response::v0::PartitionResult {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.partition_id, buf);
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+    }
+}
+
+impl crate::codec::Decode for elect_preferred_leaders_response::v0::PartitionResult {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(elect_preferred_leaders_response::v0::PartitionResult {
+            partition_id: crate::codec::Decode::decode(buf, version)?,
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum IncrementalAlterConfigsRequest {
     V0 {
         /// The incremental updates for each resource.
@@ -7733,6 +17372,63 @@ pub enum IncrementalAlterConfigsRequest {
     },
 }
 
+impl crate::versions::ApiVersions for IncrementalAlterConfigsRequest {
+    const NAME: &'static str = "IncrementalAlterConfigsRequest";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 0;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("resources", 0..=0),
+        ("resources.resource_type", 0..=0),
+        ("resources.resource_name", 0..=0),
+        ("resources.configs", 0..=0),
+        ("resources.configs.name", 0..=0),
+        ("resources.configs.config_operation", 0..=0),
+        ("resources.configs.value", 0..=0),
+        ("validate_only", 0..=0),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            IncrementalAlterConfigsRequest::V0 { .. } => 0,
+        }
+    }
+}
+
+impl crate::request::Request for IncrementalAlterConfigsRequest {
+    const API_KEY: ApiKey = ApiKey::IncrementalAlterConfigs;
+    type Response = IncrementalAlterConfigsResponse;
+}
+
+impl crate::codec::Encode for IncrementalAlterConfigsRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            IncrementalAlterConfigsRequest::V0 {
+                resources,
+                validate_only,
+            } => {
+                crate::codec::Encode::encode(resources, buf);
+                crate::codec::Encode::encode(validate_only, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for IncrementalAlterConfigsRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(IncrementalAlterConfigsRequest::V0 {
+                resources: crate::codec::Decode::decode(buf, version)?,
+                validate_only: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "IncrementalAlterConfigsRequest", version
+            ))),
+        }
+    }
+}
+
 pub mod incremental_alter_configs_request {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7756,7 +17452,45 @@ pub mod incremental_alter_configs_request {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+impl crate::codec::Encode for incremental_alter_configs_request::v0::Resources {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+        crate::codec::Encode::encode(&self.configs, buf);
+    }
+}
+
+impl crate::codec::Decode for incremental_alter_configs_request::v0::Resources {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(incremental_alter_configs_request::v0::Resources {
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+            configs: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+impl crate::codec::Encode for incremental_alter_configs_request::v0::Configs {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.name, buf);
+        crate::codec::Encode::encode(&self.config_operation, buf);
+        crate::codec::Encode::encode(&self.value, buf);
+    }
+}
+
+impl crate::codec::Decode for incremental_alter_configs_request::v0::Configs {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(incremental_alter_configs_request::v0::Configs {
+            name: crate::codec::Decode::decode(buf, version)?,
+            config_operation: crate::codec::Decode::decode(buf, version)?,
+            value: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount,
+)]
 pub enum IncrementalAlterConfigsResponse {
     V0 {
         /// Duration in milliseconds for which the request was throttled due to a
@@ -7767,6 +17501,56 @@ pub enum IncrementalAlterConfigsResponse {
     },
 }
 
+impl crate::versions::ApiVersions for IncrementalAlterConfigsResponse {
+    const NAME: &'static str = "IncrementalAlterConfigsResponse";
+    const FIRST_VERSION: i16 = 0;
+    const LAST_VERSION: i16 = 0;
+    const DEPRECATED_VERSIONS: &'static [i16] = &[];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        ("throttle_time_ms", 0..=0),
+        ("responses", 0..=0),
+        ("responses.error_code", 0..=0),
+        ("responses.error_message", 0..=0),
+        ("responses.resource_type", 0..=0),
+        ("responses.resource_name", 0..=0),
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            IncrementalAlterConfigsResponse::V0 { .. } => 0,
+        }
+    }
+}
+
+impl crate::codec::Encode for IncrementalAlterConfigsResponse {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            IncrementalAlterConfigsResponse::V0 {
+                throttle_time_ms,
+                responses,
+            } => {
+                crate::codec::Encode::encode(throttle_time_ms, buf);
+                crate::codec::Encode::encode(responses, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for IncrementalAlterConfigsResponse {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            0 => Ok(IncrementalAlterConfigsResponse::V0 {
+                throttle_time_ms: crate::codec::Decode::decode(buf, version)?,
+                responses: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!(
+                "{} has no version {}",
+                "IncrementalAlterConfigsResponse", version
+            ))),
+        }
+    }
+}
+
 pub mod incremental_alter_configs_response {
     pub mod v0 {
         #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -7782,3 +17566,23 @@ pub mod incremental_alter_configs_response {
         }
     }
 }
+
+impl crate::codec::Encode for incremental_alter_configs_response::v0::Responses {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        crate::codec::Encode::encode(&self.error_code, buf);
+        crate::codec::Encode::encode(&self.error_message, buf);
+        crate::codec::Encode::encode(&self.resource_type, buf);
+        crate::codec::Encode::encode(&self.resource_name, buf);
+    }
+}
+
+impl crate::codec::Decode for incremental_alter_configs_response::v0::Responses {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        Ok(incremental_alter_configs_response::v0::Responses {
+            error_code: crate::codec::Decode::decode(buf, version)?,
+            error_message: crate::codec::Decode::decode(buf, version)?,
+            resource_type: crate::codec::Decode::decode(buf, version)?,
+            resource_name: crate::codec::Decode::decode(buf, version)?,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::model::{ApiKey, RequestHeader};
+use crate::types::NullableString;
+use crate::versions::ApiVersions;
+
+/// A request of the protocol, linked to the api key it is sent with and to
+/// the type of its responses. Implemented by all generated requests.
+pub trait Request: ApiVersions + Serialize {
+    /// Api key requests of this type are sent with.
+    const API_KEY: ApiKey;
+
+    /// Type of the responses to requests of this type.
+    type Response: ApiVersions + DeserializeOwned;
+
+    /// Returns the version the request is sent with, the one of its variant.
+    fn api_version(&self) -> i16 {
+        self.version()
+    }
+
+    /// Returns the header to send the request with.
+    fn header(&self, correlation_id: i32, client_id: NullableString) -> RequestHeader {
+        RequestHeader::new(Self::API_KEY, self.api_version(), correlation_id, client_id)
+    }
+}
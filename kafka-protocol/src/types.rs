@@ -1,7 +1,16 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::io::Read;
 use std::ops::{Deref, DerefMut};
 
+use serde::Deserialize;
+
+use crate::codec::compression;
+use crate::codec::crc32::crc32c;
+use crate::codec::de::zag_i32;
 use crate::codec::ser::{ser_option_bytes, ser_raw_string};
-use crate::codec::Compression;
+use crate::codec::{Compression, Deserializer, DEFAULT_MAX_DECOMPRESSED_SIZE};
+use crate::model::ApiKey;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct NullableString(pub Option<String>);
@@ -115,6 +124,185 @@ impl Deref for NullableBytes {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Uuid(pub [u8; 16]);
+
+impl Deref for Uuid {
+    type Target = [u8; 16];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct UnsignedVarint(pub u32);
+
+impl UnsignedVarint {
+    pub const MAX_SIZE: usize = 5;
+
+    pub fn size_of(val: u32) -> usize {
+        let mut v = val;
+        let mut bytes = 1;
+        while (v & 0xffffff80) != 0 {
+            bytes += 1;
+            v >>= 7;
+        }
+        bytes
+    }
+
+    pub fn size(&self) -> usize {
+        UnsignedVarint::size_of(self.0)
+    }
+}
+
+impl Deref for UnsignedVarint {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for UnsignedVarint {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CompactString(pub String);
+
+impl CompactString {
+    pub fn from(s: &str) -> Self {
+        CompactString(s.to_string())
+    }
+}
+
+impl Deref for CompactString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CompactNullableString(pub Option<String>);
+
+impl CompactNullableString {
+    pub fn from(s: &str) -> Self {
+        CompactNullableString(Some(s.to_string()))
+    }
+}
+
+impl Deref for CompactNullableString {
+    type Target = Option<String>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CompactBytes(pub Vec<u8>);
+
+impl Deref for CompactBytes {
+    type Target = Vec<u8>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CompactNullableBytes(pub Option<Vec<u8>>);
+
+impl CompactNullableBytes {
+    pub fn from(b: Vec<u8>) -> Self {
+        CompactNullableBytes(Some(b))
+    }
+}
+
+impl Deref for CompactNullableBytes {
+    type Target = Option<Vec<u8>>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An array whose length N + 1 is given as an `UnsignedVarint`, a null
+/// array, of length 0, being decoded as empty.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CompactArray<T>(pub Vec<T>);
+
+impl<T> Deref for CompactArray<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for CompactArray<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Tagged fields of flexible versions, as (tag, data). Their count is given
+/// as an `UnsignedVarint`, then each field as its tag and the size of its
+/// data, both `UnsignedVarint`s, followed by its data.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TaggedFields(pub Vec<(u32, Vec<u8>)>);
+
+impl Deref for TaggedFields {
+    type Target = Vec<(u32, Vec<u8>)>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for TaggedFields {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// An api key as sent on the wire, possibly unknown to this crate, e.g. one
+/// listed by the `ApiVersionsResponse` of a more recent broker. Encoded as
+/// an `i16` like `ApiKey`, whose decoding fails on unknown keys instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnyApiKey {
+    Known(ApiKey),
+    Unknown(i16),
+}
+
+impl AnyApiKey {
+    /// Returns the api key if known to this crate.
+    pub fn known(self) -> Option<ApiKey> {
+        match self {
+            AnyApiKey::Known(api_key) => Some(api_key),
+            AnyApiKey::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<i16> for AnyApiKey {
+    fn from(key: i16) -> Self {
+        ApiKey::try_from(key).map_or(AnyApiKey::Unknown(key), AnyApiKey::Known)
+    }
+}
+
+impl From<ApiKey> for AnyApiKey {
+    fn from(api_key: ApiKey) -> Self {
+        AnyApiKey::Known(api_key)
+    }
+}
+
+impl From<AnyApiKey> for i16 {
+    fn from(api_key: AnyApiKey) -> Self {
+        match api_key {
+            AnyApiKey::Known(api_key) => api_key.into(),
+            AnyApiKey::Unknown(key) => key,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, serde::Deserialize)]
 pub struct RecordBatch {
     /// Denotes the first offset in the RecordBatch. The `offset_delta`
@@ -271,6 +459,272 @@ impl RecordBatch {
             }
         })
     }
+
+    /// Checks the fields of the batch against its records, returns every
+    /// issue found.
+    ///
+    /// Log compaction may legitimately remove the last records of a batch,
+    /// in which case `last_offset_delta` is ahead of the last record.
+    pub fn validate(&self) -> Vec<BatchFinding> {
+        let mut findings = vec![];
+
+        if self.magic != 2 {
+            findings.push(BatchFinding::InvalidMagic { magic: self.magic });
+        }
+
+        if self.records_len != self.records.len() as i32 {
+            findings.push(BatchFinding::RecordsLenMismatch {
+                records_len: self.records_len,
+                actual: self.records.len(),
+            });
+        }
+
+        let mut previous = -1;
+        let mut max_timestamp = None;
+        for (index, rec) in self.records.iter().enumerate() {
+            let rec = match rec {
+                Record::Data(rec) => rec,
+                Record::Control(_) => continue,
+            };
+
+            let offset_delta = rec.offset_delta();
+            if offset_delta <= previous {
+                findings.push(BatchFinding::OffsetDeltaNotIncreasing {
+                    index,
+                    previous,
+                    offset_delta,
+                });
+            }
+            previous = offset_delta;
+
+            let timestamp = self.first_timestamp + rec.timestamp_delta();
+            max_timestamp = max_timestamp.max(Some(timestamp));
+        }
+
+        if let Some(Record::Data(rec)) = self.records.last() {
+            if rec.offset_delta() > self.last_offset_delta {
+                findings.push(BatchFinding::LastOffsetDeltaMismatch {
+                    last_offset_delta: self.last_offset_delta,
+                    actual: rec.offset_delta(),
+                });
+            }
+        }
+
+        // With LogAppendTime, `max_timestamp` is set by the broker
+        if let (TimestampType::CreateTime, Some(actual)) = (self.timestamp_type(), max_timestamp) {
+            if actual != self.max_timestamp {
+                findings.push(BatchFinding::MaxTimestampMismatch {
+                    max_timestamp: self.max_timestamp,
+                    actual,
+                });
+            }
+        }
+
+        findings
+    }
+
+    /// Checks the encoded batch at the start of `input`, returns every
+    /// issue found.
+    ///
+    /// Unlike decoding, a CRC mismatch doesn't prevent the other checks
+    /// from running, which makes it suitable to audit data coming from
+    /// third-party producers. Compressed records may inflate up to
+    /// `DEFAULT_MAX_DECOMPRESSED_SIZE` bytes.
+    pub fn validate_bytes(input: &[u8]) -> Vec<BatchFinding> {
+        RecordBatch::validate_bytes_with_limit(input, DEFAULT_MAX_DECOMPRESSED_SIZE)
+    }
+
+    /// Checks the encoded batch at the start of `input` as `validate_bytes`
+    /// does, with compressed records allowed to inflate up to
+    /// `max_decompressed_size` bytes.
+    pub fn validate_bytes_with_limit(
+        input: &[u8],
+        max_decompressed_size: usize,
+    ) -> Vec<BatchFinding> {
+        fn be_bytes<A: Default + AsMut<[u8]>>(src: &[u8]) -> A {
+            let mut bytes = A::default();
+            bytes.as_mut().copy_from_slice(src);
+            bytes
+        }
+
+        let mut findings = vec![];
+
+        if input.len() < RecordBatch::HEADING_SIZE {
+            findings.push(BatchFinding::Truncated {
+                expected: RecordBatch::HEADING_SIZE,
+                available: input.len(),
+            });
+            return findings;
+        }
+
+        let batch_length = i32::from_be_bytes(be_bytes(&input[8..12]));
+        if batch_length < RecordBatch::INNER_SIZE as i32 {
+            findings.push(BatchFinding::BatchLengthTooSmall { batch_length });
+            return findings;
+        }
+
+        let size = RecordBatch::HEADING_SIZE + batch_length as usize;
+        if input.len() < size {
+            findings.push(BatchFinding::Truncated {
+                expected: size,
+                available: input.len(),
+            });
+            return findings;
+        }
+        let batch = &input[..size];
+
+        // Other message formats don't share the layout of the fields below
+        let magic = batch[16] as i8;
+        if magic != 2 {
+            findings.push(BatchFinding::InvalidMagic { magic });
+            return findings;
+        }
+
+        let crc = u32::from_be_bytes(be_bytes(&batch[17..21]));
+        let computed = crc32c(&batch[21..]);
+        if crc != computed {
+            findings.push(BatchFinding::CrcMismatch { crc, computed });
+        }
+
+        let attributes = i16::from_be_bytes(be_bytes(&batch[21..23]));
+        let records_len = i32::from_be_bytes(be_bytes(&batch[57..61]));
+        let is_control = ((attributes >> 5) & 1) == 1;
+
+        // Compressed records are inflated once, to be counted and decoded
+        let records = &batch[RecordBatch::OVERHEAD_SIZE..];
+        let inflated = match Compression::from_attr(attributes) {
+            Compression::None => None,
+            compression => {
+                let mut buf = vec![];
+                let inflate = compression::decoder(&compression, records, max_decompressed_size)
+                    .and_then(|mut rdr| rdr.read_to_end(&mut buf));
+                if let Err(err) = inflate {
+                    findings.push(BatchFinding::Malformed {
+                        reason: err.to_string(),
+                    });
+                    return findings;
+                }
+                Some(buf)
+            }
+        };
+
+        match count_records(inflated.as_deref().unwrap_or(records), is_control) {
+            Ok(actual) if actual != records_len as usize => {
+                findings.push(BatchFinding::RecordsLenMismatch {
+                    records_len,
+                    actual,
+                });
+            }
+            Ok(_) => (),
+            Err(reason) => findings.push(BatchFinding::Malformed { reason }),
+        }
+
+        // Decoding fails on CRC mismatch, which has already been reported.
+        // An inflated batch is decoded as its uncompressed equivalent.
+        let batch = match inflated {
+            None if crc == computed => Cow::Borrowed(batch),
+            None => {
+                let mut patched = batch.to_vec();
+                patched[17..21].copy_from_slice(&computed.to_be_bytes());
+                Cow::Owned(patched)
+            }
+            Some(records) => {
+                let mut patched = batch[..RecordBatch::OVERHEAD_SIZE].to_vec();
+                patched.extend_from_slice(&records);
+                let batch_length = (patched.len() - RecordBatch::HEADING_SIZE) as i32;
+                patched[8..12].copy_from_slice(&batch_length.to_be_bytes());
+                patched[21..23].copy_from_slice(&(attributes & !7).to_be_bytes());
+                let crc = crc32c(&patched[21..]);
+                patched[17..21].copy_from_slice(&crc.to_be_bytes());
+                Cow::Owned(patched)
+            }
+        };
+
+        let mut deserializer = Deserializer::from_bytes(&batch, 0);
+        match RecordBatch::deserialize(&mut deserializer) {
+            Ok(rec_batch) => {
+                for (index, rec) in rec_batch.records.iter().enumerate() {
+                    if let Record::Data(rec) = rec {
+                        if *rec.length != rec.size() as i32 {
+                            findings.push(BatchFinding::RecordLengthMismatch {
+                                index,
+                                length: *rec.length,
+                                actual: rec.size(),
+                            });
+                        }
+                    }
+                }
+                findings.extend(rec_batch.validate());
+            }
+            Err(err) => findings.push(BatchFinding::Malformed {
+                reason: err.to_string(),
+            }),
+        }
+
+        findings
+    }
+}
+
+/// Counts the records of an uncompressed records payload, relying on their
+/// length prefix only.
+fn count_records(mut bytes: &[u8], is_control: bool) -> Result<usize, String> {
+    let mut count = 0;
+    while !bytes.is_empty() {
+        let length = if is_control {
+            4
+        } else {
+            let (length, _) = zag_i32(&mut bytes).map_err(|err| err.to_string())?;
+            if length < 0 {
+                return Err(format!("Invalid record length: {}", length));
+            }
+            length as usize
+        };
+
+        if bytes.len() < length {
+            return Err(format!(
+                "Record {} of length {} overflows the batch",
+                count, length
+            ));
+        }
+        bytes = &bytes[length..];
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// An integrity issue found by `RecordBatch::validate` or
+/// `RecordBatch::validate_bytes`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BatchFinding {
+    /// The input ends before the end of the batch.
+    Truncated { expected: usize, available: usize },
+    /// `batch_length` is smaller than the size of the fixed batch fields.
+    BatchLengthTooSmall { batch_length: i32 },
+    /// `magic` isn't 2, the only RecordBatch format version.
+    InvalidMagic { magic: i8 },
+    /// The stored CRC doesn't match the one computed over the batch.
+    CrcMismatch { crc: u32, computed: u32 },
+    /// `records_len` doesn't match the number of records in the batch.
+    RecordsLenMismatch { records_len: i32, actual: usize },
+    /// The `length` of the record at `index` doesn't match its content.
+    RecordLengthMismatch {
+        index: usize,
+        length: i32,
+        actual: usize,
+    },
+    /// The record at `index` doesn't have a greater `offset_delta` than
+    /// the previous one.
+    OffsetDeltaNotIncreasing {
+        index: usize,
+        previous: i32,
+        offset_delta: i32,
+    },
+    /// The last record is past `last_offset_delta`.
+    LastOffsetDeltaMismatch { last_offset_delta: i32, actual: i32 },
+    /// `max_timestamp` isn't the greatest timestamp of the records.
+    MaxTimestampMismatch { max_timestamp: i64, actual: i64 },
+    /// The records can't be decoded.
+    Malformed { reason: String },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
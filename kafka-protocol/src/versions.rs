@@ -0,0 +1,119 @@
+use std::ops::RangeInclusive;
+
+use serde::de;
+
+use crate::codec::Result;
+use crate::model::ApiVersionsResponse;
+use crate::request::Request;
+
+/// Versions a request/response of the protocol exists in, and versions each
+/// of its fields exists in. Implemented by all generated requests/responses.
+///
+/// Allows to fail early, with a clear message, when a feature requires a
+/// version more recent than the one negotiated with a broker.
+pub trait ApiVersions {
+    /// Name of the request/response, e.g. `FetchRequest`.
+    const NAME: &'static str;
+    /// First version of the request/response.
+    const FIRST_VERSION: i16;
+    /// Last version of the request/response known to this crate.
+    const LAST_VERSION: i16;
+    /// Versions of the request/response the spec marks as deprecated.
+    const DEPRECATED_VERSIONS: &'static [i16];
+    /// (field_path, versions) of all fields, inner struct fields included,
+    /// e.g. `topics.partitions.fetch_offset`. A field has an element per
+    /// contiguous range of versions it exists in.
+    const FIELD_VERSIONS: &'static [(&'static str, RangeInclusive<i16>)];
+
+    /// Returns the version of this request/response.
+    fn version(&self) -> i16;
+
+    /// Returns whether the request/response exists in `version`.
+    fn has_version(version: i16) -> bool {
+        Self::FIRST_VERSION <= version && version <= Self::LAST_VERSION
+    }
+
+    /// Returns the versions of the request/response the spec marks as
+    /// deprecated, e.g. to warn before they get removed from brokers.
+    fn deprecated_versions() -> &'static [i16] {
+        Self::DEPRECATED_VERSIONS
+    }
+
+    /// Returns whether `version` is marked as deprecated.
+    fn is_deprecated(version: i16) -> bool {
+        Self::DEPRECATED_VERSIONS.contains(&version)
+    }
+
+    /// Returns whether `field` exists in `version`.
+    fn has_field(field: &str, version: i16) -> bool {
+        Self::FIELD_VERSIONS
+            .iter()
+            .any(|(path, versions)| *path == field && versions.contains(&version))
+    }
+
+    /// Returns the first version `field` exists in, if any.
+    fn field_since(field: &str) -> Option<i16> {
+        Self::FIELD_VERSIONS
+            .iter()
+            .filter(|(path, _)| *path == field)
+            .map(|(_, versions)| *versions.start())
+            .min()
+    }
+
+    /// Fails unless the request/response exists in `version`.
+    fn check_version(version: i16) -> Result<()> {
+        if Self::has_version(version) {
+            Ok(())
+        } else {
+            Err(de::Error::custom(format!(
+                "{} version {} is not supported, expected {} to {}",
+                Self::NAME,
+                version,
+                Self::FIRST_VERSION,
+                Self::LAST_VERSION
+            )))
+        }
+    }
+
+    /// Fails unless `field` exists in `version`.
+    fn check_field(field: &str, version: i16) -> Result<()> {
+        if Self::has_field(field, version) {
+            return Ok(());
+        }
+
+        match Self::field_since(field) {
+            Some(since) if since > version => Err(de::Error::custom(format!(
+                "{}.{} requires version {} or later, got version {}",
+                Self::NAME,
+                field,
+                since,
+                version
+            ))),
+            Some(_) => Err(de::Error::custom(format!(
+                "{}.{} is not available in version {}",
+                Self::NAME,
+                field,
+                version
+            ))),
+            None => Err(de::Error::custom(format!(
+                "{} has no field {}",
+                Self::NAME,
+                field
+            ))),
+        }
+    }
+}
+
+impl ApiVersionsResponse {
+    /// Returns the most recent version of `R` supported by both the broker
+    /// and this crate, if any, skipping the APIs unknown to this crate.
+    pub fn negotiate_version<R: Request>(&self) -> Option<i16> {
+        self.api_key_versions()
+            .into_iter()
+            .filter(|(api_key, _, _)| api_key.known() == Some(R::API_KEY))
+            .map(|(_, min, max)| (min.max(R::FIRST_VERSION), max.min(R::LAST_VERSION)))
+            .filter(|(min, max)| min <= max)
+            .map(|(_, max)| max)
+            .max()
+    }
+}
@@ -1,7 +1,9 @@
 use std::io::prelude::*;
 use std::net::TcpStream;
 
-use kafka_protocol::codec::{self, decode_resp, encode_req, Compression, Deserializer, Serializer};
+use kafka_protocol::codec::{
+    encode_request, Compression, FrameReader, RecordSet, RecordSetEntry, Serializer,
+};
 use kafka_protocol::model::*;
 use kafka_protocol::types::*;
 
@@ -50,21 +52,9 @@ impl RecAcc {
     }
 }
 
-pub fn read_resp<R, T>(rdr: &mut R, version: usize) -> codec::Result<(HeaderResponse, T)>
-where
-    R: Read,
-    T: serde::de::DeserializeOwned,
-{
-    let mut buf = [0u8; 4];
-    rdr.read_exact(&mut buf)?;
-    let size = i32::from_be_bytes(buf);
-    let mut bytes = vec![0; size as usize];
-    rdr.read_exact(&mut bytes)?;
-    decode_resp::<T>(&bytes, version)
-}
-
 fn wip_requests() -> std::io::Result<()> {
     let mut stream = TcpStream::connect("127.0.0.1:9092")?;
+    let mut reader = FrameReader::new(stream.try_clone()?);
 
     // let header = HeaderRequest {
     //     api_key: ApiKey::ApiVersions,
@@ -75,7 +65,7 @@ fn wip_requests() -> std::io::Result<()> {
     // let bytes = encode_req(&header, &ApiVersionsRequest::V0 {}).unwrap();
     // stream.write(&bytes)?;
 
-    // let (header, resp) = read_resp::<_, ApiVersionsResponse>(&mut stream, 0).unwrap();
+    // let (header, resp) = reader.read_resp::<ApiVersionsResponse>(0).unwrap();
     // // println!("---> {:?}", header);
     // // println!("---> {:?}", resp);
 
@@ -97,19 +87,12 @@ fn wip_requests() -> std::io::Result<()> {
     // let bytes = encode_req(&header, &req).unwrap();
     // stream.write(&bytes)?;
 
-    // let (header, resp) = read_resp::<_, MetadataResponse>(&mut stream, 0).unwrap();
+    // let (header, resp) = reader.read_resp::<MetadataResponse>(0).unwrap();
     // // println!("---> {:?}", header);
     // // println!("---> {:?}", resp);
 
     ///////////////////////////////////////////////////////////////////
 
-    let header = HeaderRequest {
-        api_key: ApiKey::Fetch,
-        api_version: 4,
-        correlation_id: 42,
-        client_id: NullableString::from("me"),
-    };
-
     let req = FetchRequest::V4 {
         replica_id: -1,
         max_wait_time: 5 * 1000,
@@ -126,10 +109,10 @@ fn wip_requests() -> std::io::Result<()> {
         }],
     };
 
-    let bytes = encode_req(&header, &req).unwrap();
+    let bytes = encode_request(&req, 42, NullableString::from("me")).unwrap();
     stream.write(&bytes)?;
 
-    let (header, resp) = read_resp::<_, FetchResponse>(&mut stream, 4).unwrap();
+    let (header, resp) = reader.read_response(&req).unwrap();
     println!("---> {:?}", header);
     println!("---> {:?}", resp);
 
@@ -142,17 +125,24 @@ fn wip_requests() -> std::io::Result<()> {
             .unwrap()
             .record_set
         {
-            use serde::Deserialize;
-
-            let mut deserializer = Deserializer::from_bytes(&bytes, 0);
-
-            while deserializer.len() != 0 {
-                let batch = RecordBatch::deserialize(&mut deserializer).unwrap();
-                println!(">>>>>>>> {:?}", batch);
+            let record_set = RecordSet::new(&bytes);
+            if record_set.first_batch_truncated() {
+                println!("First batch exceeds the fetch size");
+            }
 
-                batch
-                    .iter()
-                    .for_each(|rec| println!("{:?}", String::from_utf8(rec.value.to_vec())));
+            for entry in record_set {
+                match entry.unwrap() {
+                    RecordSetEntry::Batch(batch) => {
+                        println!(">>>>>>>> {:?}", batch);
+
+                        batch.iter().for_each(|rec| {
+                            println!("{:?}", String::from_utf8(rec.value.to_vec()))
+                        });
+                    }
+                    RecordSetEntry::Incomplete { available, .. } => {
+                        println!(">>>>>>>> incomplete, {} bytes", available);
+                    }
+                }
             }
         }
     }
@@ -174,13 +164,6 @@ fn wip_requests() -> std::io::Result<()> {
     rec_batch.serialize(&mut serializer).unwrap();
     let bytes = serializer.bytes();
 
-    let header = HeaderRequest {
-        api_key: ApiKey::Produce,
-        api_version: 3,
-        correlation_id: 42,
-        client_id: NullableString::from("me"),
-    };
-
     let req = ProduceRequest::V3 {
         transactional_id: NullableString(None),
         acks: -1,
@@ -194,10 +177,10 @@ fn wip_requests() -> std::io::Result<()> {
         }],
     };
 
-    let bytes = encode_req(&header, &req).unwrap();
+    let bytes = encode_request(&req, 42, NullableString::from("me")).unwrap();
     stream.write(&bytes)?;
 
-    let (header, resp) = read_resp::<_, ProduceResponse>(&mut stream, 3).unwrap();
+    let (header, resp) = reader.read_response(&req).unwrap();
     println!("---> {:?}", header);
     println!("---> {:?}", resp);
 
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::generator::Error;
+
+/// Key of the types config applying to all requests/responses and their
+/// inner structs.
+pub const ALL_TYPES: &str = "*";
+
+/// Extra derives and attributes of generated types, read from a TOML file:
+///
+/// ```toml
+/// # All requests/responses and their inner structs
+/// [types."*"]
+/// derives = ["Eq"]
+///
+/// [types.ErrorCode]
+/// derives = ["Hash"]
+/// attributes = ["#[non_exhaustive]"]
+///
+/// [types."fetch_response::v4::Responses"]
+/// derives = ["Default"]
+/// ```
+///
+/// Types are named as in generated code: `ErrorCode`, `ApiKey`, `Response`,
+/// requests/responses, e.g. `FetchRequest`, and inner structs by their path,
+/// e.g. `fetch_response::v4::Responses`. Inner structs re-exported from a
+/// previous version are configured by the path of the version defining them.
+/// Generation fails on other types.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub types: HashMap<String, TypeConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeConfig {
+    /// Extra derives, e.g. `Eq` or `Hash`.
+    #[serde(default)]
+    pub derives: Vec<String>,
+    /// Extra attributes, e.g. `#[non_exhaustive]`.
+    #[serde(default)]
+    pub attributes: Vec<String>,
+}
+
+impl Config {
+    /// Reads a config from a TOML file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Config, Error> {
+        Config::from_toml(&fs::read_to_string(path)?)
+    }
+
+    /// Reads a config from TOML content.
+    pub fn from_toml(raw: &str) -> Result<Config, Error> {
+        Ok(toml::from_str(raw)?)
+    }
+
+    /// Returns the extra derives and attributes of a given type, those of
+    /// `ALL_TYPES` included when `all` is set.
+    pub fn type_config(&self, type_name: &str, all: bool) -> TypeConfig {
+        let mut type_config = TypeConfig::default();
+
+        let all_types = if all { self.types.get(ALL_TYPES) } else { None };
+        for config in all_types.into_iter().chain(self.types.get(type_name)) {
+            type_config.derives.extend(config.derives.iter().cloned());
+            type_config
+                .attributes
+                .extend(config.attributes.iter().cloned());
+        }
+
+        type_config
+    }
+}
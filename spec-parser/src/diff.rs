@@ -0,0 +1,400 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::parser::{ParserError, Spec, SpecParser, VersionedSpecs};
+
+/// Vector of (name, id) of API keys or error codes.
+type Rows = Vec<(String, String)>;
+
+/// Differences between two protocol specs, from OLD to NEW.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct SpecDiff {
+    /// (name, key_id) of the API keys only in NEW.
+    pub new_api_keys: Rows,
+    /// (name, key_id) of the API keys only in OLD.
+    pub removed_api_keys: Rows,
+    /// (name, code_id) of the error codes only in NEW.
+    pub new_error_codes: Rows,
+    /// (name, code_id) of the error codes only in OLD.
+    pub removed_error_codes: Rows,
+    /// Requests/responses only in NEW.
+    pub new_apis: Vec<ApiRange>,
+    /// Requests/responses only in OLD.
+    pub removed_apis: Vec<ApiRange>,
+    /// Requests/responses in both specs that changed.
+    pub changed_apis: Vec<ApiDiff>,
+}
+
+/// A request/response and the versions it is defined for.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ApiRange {
+    pub name: String,
+    pub versions: Vec<i16>,
+}
+
+/// Differences of a request/response defined in both specs.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ApiDiff {
+    pub name: String,
+    pub new_versions: Vec<i16>,
+    pub removed_versions: Vec<i16>,
+    /// Field changes per version. A version in both specs is compared to
+    /// itself, a new version to the version preceding it in NEW.
+    pub versions: Vec<VersionDiff>,
+}
+
+/// Field changes of a version of a request/response.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct VersionDiff {
+    pub version: i16,
+    /// Version the fields are compared to.
+    pub compared_to: i16,
+    pub added_fields: Vec<Field>,
+    pub removed_fields: Vec<Field>,
+    pub retyped_fields: Vec<RetypedField>,
+}
+
+/// A field, by its dotted path from the request/response root, e.g.
+/// `topics.partitions.partition`, and its spec type, e.g. `ARRAY(INT32)`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Field {
+    pub path: String,
+    pub spec_type: String,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct RetypedField {
+    pub path: String,
+    pub old_type: String,
+    pub new_type: String,
+}
+
+impl SpecDiff {
+    /// Compares the specs parsed by `old` and `new`.
+    pub(crate) fn new(old: &SpecParser, new: &SpecParser) -> SpecDiff {
+        let (new_api_keys, removed_api_keys) = diff_rows(&old.api_key_rows, &new.api_key_rows);
+        let (new_error_codes, removed_error_codes) = diff_rows(
+            &old.err_code_rows
+                .iter()
+                .map(|(name, code, _)| (name.clone(), code.clone()))
+                .collect::<Vec<_>>(),
+            &new.err_code_rows
+                .iter()
+                .map(|(name, code, _)| (name.clone(), code.clone()))
+                .collect::<Vec<_>>(),
+        );
+
+        let old_apis = old.iter_req_resp().collect::<Vec<_>>();
+        let new_apis = new.iter_req_resp().collect::<Vec<_>>();
+
+        let mut diff = SpecDiff {
+            new_api_keys,
+            removed_api_keys,
+            new_error_codes,
+            removed_error_codes,
+            ..SpecDiff::default()
+        };
+
+        for (name, versions) in &new_apis {
+            match find_api(&old_apis, name) {
+                None => diff.new_apis.push(api_range(name, versions)),
+                Some(old_versions) => {
+                    let api_diff = diff_api(name, old_versions, versions);
+                    if !api_diff.is_empty() {
+                        diff.changed_apis.push(api_diff);
+                    }
+                }
+            }
+        }
+
+        for (name, versions) in &old_apis {
+            if find_api(&new_apis, name).is_none() {
+                diff.removed_apis.push(api_range(name, versions));
+            }
+        }
+
+        diff
+    }
+
+    /// Compares the raw content of two `protocol.html` specs.
+    pub fn from_raw(old: &str, new: &str) -> Result<SpecDiff, ParserError> {
+        Ok(SpecDiff::new(
+            &SpecParser::new(old)?,
+            &SpecParser::new(new)?,
+        ))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == SpecDiff::default()
+    }
+}
+
+impl ApiDiff {
+    pub fn is_empty(&self) -> bool {
+        self.new_versions.is_empty() && self.removed_versions.is_empty() && self.versions.is_empty()
+    }
+}
+
+impl VersionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_fields.is_empty()
+            && self.removed_fields.is_empty()
+            && self.retyped_fields.is_empty()
+    }
+}
+
+impl fmt::Display for SpecDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+
+        if !self.new_api_keys.is_empty() || !self.removed_api_keys.is_empty() {
+            writeln!(f, "API keys:")?;
+            for (name, key) in &self.new_api_keys {
+                writeln!(f, "  + {} = {}", name, key)?;
+            }
+            for (name, key) in &self.removed_api_keys {
+                writeln!(f, "  - {} = {}", name, key)?;
+            }
+        }
+
+        if !self.new_error_codes.is_empty() || !self.removed_error_codes.is_empty() {
+            writeln!(f, "Error codes:")?;
+            for (name, code) in &self.new_error_codes {
+                writeln!(f, "  + {} = {}", name, code)?;
+            }
+            for (name, code) in &self.removed_error_codes {
+                writeln!(f, "  - {} = {}", name, code)?;
+            }
+        }
+
+        if !self.new_apis.is_empty() || !self.removed_apis.is_empty() {
+            writeln!(f, "APIs:")?;
+            for api in &self.new_apis {
+                writeln!(f, "  + {} (versions: {})", api.name, join(&api.versions))?;
+            }
+            for api in &self.removed_apis {
+                writeln!(f, "  - {} (versions: {})", api.name, join(&api.versions))?;
+            }
+        }
+
+        for api in &self.changed_apis {
+            writeln!(f, "{}:", api.name)?;
+            if !api.new_versions.is_empty() {
+                writeln!(f, "  + versions: {}", join(&api.new_versions))?;
+            }
+            if !api.removed_versions.is_empty() {
+                writeln!(f, "  - versions: {}", join(&api.removed_versions))?;
+            }
+            for version in &api.versions {
+                if version.version == version.compared_to {
+                    writeln!(f, "  v{}:", version.version)?;
+                } else {
+                    writeln!(f, "  v{} (from v{}):", version.version, version.compared_to)?;
+                }
+                for field in &version.added_fields {
+                    writeln!(f, "    + {}: {}", field.path, field.spec_type)?;
+                }
+                for field in &version.removed_fields {
+                    writeln!(f, "    - {}: {}", field.path, field.spec_type)?;
+                }
+                for field in &version.retyped_fields {
+                    writeln!(
+                        f,
+                        "    ~ {}: {} -> {}",
+                        field.path, field.old_type, field.new_type
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the rows only in `new`, and the rows only in `old`.
+fn diff_rows(old: &[(String, String)], new: &[(String, String)]) -> (Rows, Rows) {
+    let added = new.iter().filter(|row| !old.contains(row)).cloned();
+    let removed = old.iter().filter(|row| !new.contains(row)).cloned();
+    (added.collect(), removed.collect())
+}
+
+fn find_api<'a, 'b>(
+    apis: &[(&'a String, &'a VersionedSpecs<'b>)],
+    name: &str,
+) -> Option<&'a VersionedSpecs<'b>> {
+    apis.iter()
+        .find(|(api_name, _)| *api_name == name)
+        .map(|(_, versions)| *versions)
+}
+
+fn api_range(name: &str, versions: &VersionedSpecs) -> ApiRange {
+    ApiRange {
+        name: name.to_string(),
+        versions: versions.iter().map(|(version, _, _)| *version).collect(),
+    }
+}
+
+fn diff_api(name: &str, old: &VersionedSpecs, new: &VersionedSpecs) -> ApiDiff {
+    let old_specs = old
+        .iter()
+        .map(|(version, spec, _)| (*version, spec))
+        .collect::<BTreeMap<_, _>>();
+    let new_specs = new
+        .iter()
+        .map(|(version, spec, _)| (*version, spec))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut api_diff = ApiDiff {
+        name: name.to_string(),
+        new_versions: vec![],
+        removed_versions: vec![],
+        versions: vec![],
+    };
+
+    let mut previous: Option<(i16, &Spec)> = None;
+    for (&version, &spec) in &new_specs {
+        let compared = match old_specs.get(&version) {
+            Some(&old_spec) => Some((version, old_spec)),
+            None => {
+                api_diff.new_versions.push(version);
+                previous
+            }
+        };
+
+        if let Some((compared_to, old_spec)) = compared {
+            let version_diff = diff_version(version, compared_to, old_spec, spec);
+            if !version_diff.is_empty() {
+                api_diff.versions.push(version_diff);
+            }
+        }
+
+        previous = Some((version, spec));
+    }
+
+    api_diff.removed_versions = old_specs
+        .keys()
+        .filter(|version| !new_specs.contains_key(version))
+        .cloned()
+        .collect();
+
+    api_diff
+}
+
+fn diff_version(version: i16, compared_to: i16, old: &Spec, new: &Spec) -> VersionDiff {
+    let old_fields = old.flatten();
+    let new_fields = new.flatten();
+
+    let mut version_diff = VersionDiff {
+        version,
+        compared_to,
+        added_fields: vec![],
+        removed_fields: vec![],
+        retyped_fields: vec![],
+    };
+
+    for (path, new_type) in &new_fields {
+        match old_fields.iter().find(|(old_path, _)| old_path == path) {
+            None => version_diff.added_fields.push(Field {
+                path: path.clone(),
+                spec_type: new_type.clone(),
+            }),
+            Some((_, old_type)) if old_type != new_type => {
+                version_diff.retyped_fields.push(RetypedField {
+                    path: path.clone(),
+                    old_type: old_type.clone(),
+                    new_type: new_type.clone(),
+                })
+            }
+            Some(_) => (),
+        }
+    }
+
+    for (path, old_type) in &old_fields {
+        if !new_fields.iter().any(|(new_path, _)| new_path == path) {
+            version_diff.removed_fields.push(Field {
+                path: path.clone(),
+                spec_type: old_type.clone(),
+            });
+        }
+    }
+
+    version_diff
+}
+
+fn join(versions: &[i16]) -> String {
+    versions
+        .iter()
+        .map(|version| version.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Primitive;
+    use std::collections::HashMap;
+
+    #[test]
+    fn diff_versions() {
+        use crate::parser::Spec::*;
+
+        let v0 = Struct(vec![
+            (
+                "topics".into(),
+                Array(Box::new(Struct(vec![
+                    ("name".into(), Value(Primitive::String)),
+                    ("partition".into(), Value(Primitive::Int32)),
+                ]))),
+            ),
+            ("timeout".into(), Value(Primitive::Int32)),
+        ]);
+        let v1 = Struct(vec![
+            (
+                "topics".into(),
+                Array(Box::new(Struct(vec![
+                    ("name".into(), Value(Primitive::String)),
+                    (
+                        "partitions".into(),
+                        Array(Box::new(Value(Primitive::Int32))),
+                    ),
+                ]))),
+            ),
+            ("timeout".into(), Value(Primitive::Int64)),
+        ]);
+
+        let old = vec![(0, v0.clone(), HashMap::new())];
+        let new = vec![(0, v0, HashMap::new()), (1, v1, HashMap::new())];
+
+        let field = |path: &str, spec_type: &str| Field {
+            path: path.to_string(),
+            spec_type: spec_type.to_string(),
+        };
+
+        assert_eq!(
+            ApiDiff {
+                name: "FooRequest".to_string(),
+                new_versions: vec![1],
+                removed_versions: vec![],
+                versions: vec![VersionDiff {
+                    version: 1,
+                    compared_to: 0,
+                    added_fields: vec![field("topics.partitions", "ARRAY(INT32)")],
+                    removed_fields: vec![field("topics.partition", "INT32")],
+                    retyped_fields: vec![RetypedField {
+                        path: "timeout".to_string(),
+                        old_type: "INT32".to_string(),
+                        new_type: "INT64".to_string(),
+                    }],
+                }],
+            },
+            diff_api("FooRequest", &old, &new)
+        );
+
+        assert!(diff_api("FooRequest", &new, &new).is_empty());
+    }
+}
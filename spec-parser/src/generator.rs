@@ -0,0 +1,786 @@
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+
+use heck::SnakeCase;
+use indexmap::IndexMap;
+use similar::TextDiff;
+use thiserror::Error;
+
+use crate::config::{Config, ALL_TYPES};
+use crate::ir::Ir;
+use crate::parser::{ParserError, ReqRespMotif, SpecParser, VersionedSpecs};
+use crate::templates::motif::ApiKeyRows;
+use crate::templates::Templater;
+
+/// Directory of the vendored protocol specs, one `VERSION/protocol.html`
+/// per Kafka release.
+pub const SPECS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/specs");
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{}", .0)]
+    Io(#[from] std::io::Error),
+    #[error("{}", .0)]
+    Parser(#[from] ParserError),
+    #[error("{}", .0)]
+    Template(#[from] tera::Error),
+    #[error("{}", .0)]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid config: {}", .0)]
+    Config(#[from] toml::de::Error),
+    #[error(
+        "No vendored spec for Kafka {}, available: {}, see spec-parser/specs/README.md to vendor one",
+        .0,
+        .1
+    )]
+    UnknownKafkaVersion(String, String),
+    #[error("Unknown API: {}", .0)]
+    UnknownApi(String),
+    #[error("Unknown type in config: {}", .0)]
+    UnknownType(String),
+    #[error("Config of {} doesn't apply to the re-exported struct, set it for {}", .0, .1)]
+    ReexportedType(String, String),
+    #[error("Problem with rustfmt: {}", .0)]
+    Rustfmt(String),
+}
+
+/// Generates the Rust model of a protocol spec.
+///
+/// Usable from a `build.rs`, to generate a model into `OUT_DIR`:
+///
+/// ```no_run
+/// let mut generator = spec_parser::Generator::vendored("2.3").unwrap();
+/// generator.set_apis(&["ApiVersions", "Metadata", "Fetch"]);
+/// generator.set_crate_path("kafka_protocol");
+/// generator.set_derives(&["Eq"]);
+///
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// generator.write_to(format!("{}/model.rs", out_dir)).unwrap();
+/// ```
+pub struct Generator {
+    raw: String,
+    protocol_url: String,
+    apis: Option<Vec<String>>,
+    crate_path: Option<String>,
+    derives: Vec<String>,
+    templates_dir: Option<PathBuf>,
+    config: Config,
+    format: bool,
+}
+
+impl Generator {
+    /// Creates a generator for the raw content of a `protocol.html` spec,
+    /// published at `protocol_url`.
+    pub fn new(raw: String, protocol_url: &str) -> Generator {
+        Generator {
+            raw,
+            protocol_url: protocol_url.to_string(),
+            apis: None,
+            crate_path: None,
+            derives: vec![],
+            templates_dir: None,
+            config: Config::default(),
+            format: true,
+        }
+    }
+
+    /// Creates a generator for a `protocol.html` spec file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Generator, Error> {
+        Ok(Generator::new(fs::read_to_string(path)?, "local file"))
+    }
+
+    /// Creates a generator for the vendored spec of a Kafka release, e.g. `2.3`.
+    pub fn vendored(kafka_version: &str) -> Result<Generator, Error> {
+        Generator::vendored_in(SPECS_DIR, kafka_version)
+    }
+
+    /// Creates a generator for the spec of a Kafka release vendored in `specs_dir`.
+    pub fn vendored_in(specs_dir: &str, kafka_version: &str) -> Result<Generator, Error> {
+        let raw = read_vendored_spec(specs_dir, kafka_version)?;
+        Ok(Generator::new(raw, &spec_url(kafka_version)))
+    }
+
+    /// Restricts the generated requests/responses to the ones of `apis`,
+    /// given by API key names, e.g. `Fetch`. All are generated by default.
+    pub fn set_apis<S: AsRef<str>>(&mut self, apis: &[S]) {
+        self.apis = Some(apis.iter().map(|api| api.as_ref().to_string()).collect());
+    }
+
+    /// Sets the path of the crate providing runtime types, such as
+    /// `kafka_protocol`, when generating outside of `kafka-protocol`. The
+    /// generated code can then be `include!`d, and re-exports the `ApiKey`
+    /// and headers of that crate rather than generating its own.
+    pub fn set_crate_path(&mut self, crate_path: &str) {
+        self.crate_path = Some(crate_path.to_string());
+    }
+
+    /// Adds derives to generated requests/responses and their inner structs.
+    pub fn set_derives<S: AsRef<str>>(&mut self, derives: &[S]) {
+        self.derives = derives.iter().map(|d| d.as_ref().to_string()).collect();
+    }
+
+    /// Overrides templates by the `.tera` files of `dir` named after them,
+    /// e.g. `req_resp_enum.tera`.
+    pub fn set_templates_dir<P: AsRef<Path>>(&mut self, dir: P) {
+        self.templates_dir = Some(dir.as_ref().to_path_buf());
+    }
+
+    /// Sets extra derives and attributes per generated type.
+    pub fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
+    /// Sets whether code is formatted with rustfmt when written or checked,
+    /// the default. Unformatted code doesn't need rustfmt to be installed,
+    /// e.g. when generating from a `build.rs`.
+    pub fn set_format(&mut self, format: bool) {
+        self.format = format;
+    }
+
+    /// Returns the generated Rust code, unformatted.
+    pub fn generate(&self) -> Result<String, Error> {
+        let (mut out, apis) = self.generate_parts()?;
+        for code in apis.values() {
+            out.push_str(code);
+        }
+
+        Ok(out)
+    }
+
+    /// Returns the generated Rust code split into modules, unformatted, as
+    /// (file_name, code): a `mod.rs` of the types common to all APIs, which
+    /// re-exports one module per API, e.g. `api_versions.rs`.
+    pub fn generate_modules(&self) -> Result<Vec<(String, String)>, Error> {
+        let (mut out, apis) = self.generate_parts()?;
+
+        let mut modules = vec![];
+        for (api, code) in apis {
+            let module = api.to_snake_case();
+            out.push_str(&format!(
+                "\nmod {};\npub use self::{}::*;\n",
+                module, module
+            ));
+
+            let code = format!(
+                "//! {} API, generated from: {}\n\nuse super::ApiKey;\n{}",
+                api, self.protocol_url, code
+            );
+            modules.push((format!("{}.rs", module), code));
+        }
+        modules.insert(0, ("mod.rs".to_string(), out));
+
+        Ok(modules)
+    }
+
+    /// Returns the code of the types common to all APIs, and the code of the
+    /// requests/responses of each API, by API name.
+    fn generate_parts(&self) -> Result<(String, IndexMap<String, String>), Error> {
+        let parser = SpecParser::new(&self.raw)?;
+        let mut templ = match self.templates_dir {
+            Some(ref dir) => Templater::with_templates_dir(dir)?,
+            None => Templater::new()?,
+        };
+        if let Some(ref crate_path) = self.crate_path {
+            templ.set_crate_path(crate_path);
+        }
+        templ.set_derives(&self.derives);
+        templ.set_config(self.config.clone());
+
+        let api_key_rows = self.api_key_rows(&parser)?;
+        self.check_config(&parser)?;
+
+        // Code generated for another crate is meant to be `include!`d, which
+        // doesn't allow inner doc comments
+        let mut out = match self.crate_path {
+            None => format!("//! Generated from: {}\n", self.protocol_url),
+            Some(_) => format!("// Generated from: {}\n", self.protocol_url),
+        };
+        // `Request` and the codec are bound to the API keys and headers of
+        // `kafka-protocol`, so code generated for another crate reuses them
+        let in_tree = match self.crate_path {
+            Some(ref path) => path == "crate",
+            None => true,
+        };
+        if in_tree {
+            for header in parser.iter_headers() {
+                out.push_str(&str_versioned(&templ, &parser, header)?);
+            }
+        }
+        out.push_str(&templ.str_err_codes(&parser.err_code_rows)?);
+        match self.crate_path {
+            Some(ref crate_path) if !in_tree => {
+                let mut reused = vec!["ApiKey".to_string()];
+                reused.extend(parser.iter_headers().map(|header| header.enum_name()));
+                out.push_str(&format!(
+                    "\n/// Api keys and headers of `{0}`, which its `Request` and codec use.\n\
+                     pub use {0}::model::{{{1}}};\n",
+                    crate_path,
+                    reused.join(", ")
+                ));
+            }
+            _ => out.push_str(&templ.str_api_keys(&parser.api_key_rows)?),
+        }
+        if in_tree && parser.iter_headers().next().is_some() {
+            out.push_str(&templ.str_header_versions(
+                &parser.header_version_rows(false),
+                &parser.header_version_rows(true),
+            )?);
+            for header in parser.iter_headers() {
+                let vfields = header.enum_vfields();
+                let versions = header.enum_vranges().0.into_iter().zip(vfields.iter());
+                out.push_str(&templ.str_header(&header.enum_name(), &versions.collect())?);
+            }
+        }
+        out.push_str(&templ.str_responses(
+            &api_key_rows,
+            api_key_rows.len() < parser.api_key_rows.len(),
+        )?);
+
+        let mut apis = IndexMap::new();
+        for req_rep in parser.iter_req_resp() {
+            let api = req_rep
+                .0
+                .trim_end_matches("Request")
+                .trim_end_matches("Response");
+            if !api_key_rows.iter().any(|(name, _)| name == api) {
+                continue;
+            }
+
+            let code: &mut String = apis.entry(api.to_string()).or_default();
+            code.push_str(&str_versioned(&templ, &parser, req_rep)?);
+        }
+
+        Ok((out, apis))
+    }
+
+    /// Fails on types of the config which the spec doesn't define, and on
+    /// inner structs re-exported from a previous version, which are
+    /// configured by the path of the version defining them.
+    fn check_config(&self, parser: &SpecParser) -> Result<(), Error> {
+        // Defining path of each type, if re-exported, by type path
+        let mut types: HashMap<String, Option<String>> = ["ErrorCode", "ApiKey", "Response"]
+            .iter()
+            .map(|name| (name.to_string(), None))
+            .collect();
+        for req_rep in parser.iter_headers().chain(parser.iter_req_resp()) {
+            types.insert(req_rep.enum_name(), None);
+            let module = req_rep.mod_name();
+            for (i, structs) in req_rep.mod_vstructs().iter().enumerate() {
+                for (name, _, shared) in structs {
+                    types.insert(
+                        format!("{}::v{}::{}", module, i, name),
+                        shared
+                            .as_ref()
+                            .map(|shared| format!("{}::{}::{}", module, shared, name)),
+                    );
+                }
+            }
+        }
+
+        for type_name in self.config.types.keys() {
+            match types.get(type_name) {
+                _ if type_name == ALL_TYPES => (),
+                Some(None) => (),
+                Some(Some(defining)) => {
+                    return Err(Error::ReexportedType(type_name.clone(), defining.clone()))
+                }
+                None => return Err(Error::UnknownType(type_name.clone())),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the intermediate representation of the parsed spec, to be
+    /// serialized, e.g. as JSON.
+    pub fn ir(&self) -> Result<Ir, Error> {
+        let parser = SpecParser::new(&self.raw)?;
+        let api_key_rows = self.api_key_rows(&parser)?;
+        Ok(Ir::new(&parser, &self.protocol_url, &api_key_rows))
+    }
+
+    /// Returns the rows of the API keys to generate, failing on unknown ones.
+    fn api_key_rows(&self, parser: &SpecParser) -> Result<ApiKeyRows, Error> {
+        match self.apis {
+            None => Ok(parser.api_key_rows.clone()),
+            Some(ref apis) => {
+                if let Some(api) = apis
+                    .iter()
+                    .find(|api| !parser.api_key_rows.iter().any(|(name, _)| name == *api))
+                {
+                    return Err(Error::UnknownApi(api.clone()));
+                }
+                Ok(parser
+                    .api_key_rows
+                    .iter()
+                    .filter(|(name, _)| apis.contains(name))
+                    .cloned()
+                    .collect())
+            }
+        }
+    }
+
+    /// Returns the generated Rust code, formatted with rustfmt.
+    pub fn generate_formatted(&self) -> Result<String, Error> {
+        rustfmt(&self.generate()?)
+    }
+
+    /// Returns `code` formatted with rustfmt, unless formatting is disabled.
+    fn format(&self, code: String) -> Result<String, Error> {
+        if self.format {
+            rustfmt(&code)
+        } else {
+            Ok(code)
+        }
+    }
+
+    /// Generates Rust code to `out_file`, formatted with rustfmt unless
+    /// disabled by `set_format`.
+    pub fn write_to<P: AsRef<Path>>(&self, out_file: P) -> Result<(), Error> {
+        let code = self.format(self.generate()?)?;
+        OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&out_file)?
+            .write_all(code.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Generates Rust code split into modules, formatted as by `write_to`,
+    /// as files of `out_dir`, created if missing. See `generate_modules`.
+    pub fn write_modules_to<P: AsRef<Path>>(&self, out_dir: P) -> Result<(), Error> {
+        fs::create_dir_all(&out_dir)?;
+        for (file_name, code) in self.generate_modules()? {
+            fs::write(out_dir.as_ref().join(file_name), self.format(code)?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `out_file` holds the code `write_to` would generate.
+    /// Returns a unified diff from `out_file` to the generated code when
+    /// stale, `None` when up to date.
+    pub fn check<P: AsRef<Path>>(&self, out_file: P) -> Result<Option<String>, Error> {
+        diff_file(out_file.as_ref(), &self.format(self.generate()?)?)
+    }
+
+    /// Checks that `out_dir` holds the modules `write_modules_to` would
+    /// generate. Returns unified diffs of the stale or missing ones, `None`
+    /// when all are up to date.
+    pub fn check_modules<P: AsRef<Path>>(&self, out_dir: P) -> Result<Option<String>, Error> {
+        let mut diffs = String::new();
+        for (file_name, code) in self.generate_modules()? {
+            if let Some(diff) = diff_file(&out_dir.as_ref().join(file_name), &self.format(code)?)? {
+                diffs.push_str(&diff);
+            }
+        }
+
+        if diffs.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(diffs))
+        }
+    }
+}
+
+/// Returns a unified diff from `out_file` to the generated `code`, `None` when
+/// identical. A missing `out_file` is diffed as empty.
+fn diff_file(out_file: &Path, code: &str) -> Result<Option<String>, Error> {
+    let current = match fs::read_to_string(out_file) {
+        Ok(current) => current,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+
+    if current == code {
+        return Ok(None);
+    }
+
+    let name = out_file.to_string_lossy();
+    let diff = TextDiff::from_lines(current.as_str(), code)
+        .unified_diff()
+        .header(&name, &format!("{} (generated)", name))
+        .to_string();
+    Ok(Some(diff))
+}
+
+/// Generates the versioned enum of a request, response or header, along with
+/// the versioned modules of its inner structs.
+fn str_versioned(
+    templ: &Templater,
+    parser: &SpecParser,
+    req_rep: (&String, &VersionedSpecs),
+) -> Result<String, Error> {
+    let mut out = String::new();
+
+    let vfields = req_rep.enum_vfields();
+    let vranges = req_rep.enum_vranges();
+    out.push_str(&templ.str_req_resp_enum(&req_rep.enum_name(), &vfields)?);
+    out.push_str(&templ.str_req_resp_versions(
+        &req_rep.enum_name(),
+        &vranges,
+        parser.deprecated_versions(req_rep.0),
+    )?);
+    if req_rep.0.ends_with("Request") {
+        out.push_str(&templ.str_request(req_rep.0.trim_end_matches("Request"))?);
+    }
+    if req_rep.0 == "ApiVersionsResponse" {
+        out.push_str(&templ.str_api_key_versions(&req_rep.enum_name(), &vfields)?);
+    }
+    let vcodec = vranges.0.iter().cloned().zip(vfields.iter()).collect();
+    out.push_str(&templ.str_req_resp_codec(&req_rep.enum_name(), &vcodec)?);
+
+    let vstructs = req_rep.mod_vstructs();
+    if !vstructs.is_empty() {
+        out.push_str(&templ.str_req_resp_mod(&req_rep.mod_name(), &vstructs)?);
+        out.push_str(&templ.str_req_resp_mod_codec(&req_rep.mod_name(), &vstructs)?);
+    }
+
+    Ok(out)
+}
+
+/// Formats Rust code with rustfmt.
+fn rustfmt(code: &str) -> Result<String, Error> {
+    let mut child = Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2018")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| Error::Rustfmt(e.to_string()))?;
+
+    // Writes from another thread, not to block on a full stdout pipe
+    let mut stdin = child.stdin.take().expect("rustfmt stdin is piped");
+    let input = code.to_string();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| Error::Rustfmt(e.to_string()))?;
+    writer
+        .join()
+        .expect("rustfmt writer thread panicked")
+        .map_err(|e| Error::Rustfmt(e.to_string()))?;
+    if !output.status.success() {
+        return Err(Error::Rustfmt(output.status.to_string()));
+    }
+
+    String::from_utf8(output.stdout).map_err(|e| Error::Rustfmt(e.to_string()))
+}
+
+/// Reads the vendored spec of a Kafka release, e.g. `2.3`.
+pub fn read_vendored_spec(specs_dir: &str, kafka_version: &str) -> Result<String, Error> {
+    let path = Path::new(specs_dir)
+        .join(kafka_version)
+        .join("protocol.html");
+    if !path.is_file() {
+        let available = vendored_versions(specs_dir);
+        return Err(Error::UnknownKafkaVersion(
+            kafka_version.to_string(),
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            },
+        ));
+    }
+    Ok(fs::read_to_string(path)?)
+}
+
+/// Returns the Kafka releases whose spec is vendored in `specs_dir`.
+pub fn vendored_versions(specs_dir: &str) -> Vec<String> {
+    let mut versions = fs::read_dir(specs_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().join("protocol.html").is_file())
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    versions.sort();
+    versions
+}
+
+/// Returns the upstream URL of the spec of a Kafka release.
+pub fn spec_url(kafka_version: &str) -> String {
+    format!(
+        "https://kafka.apache.org/{}/protocol.html",
+        kafka_version.replace('.', "")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    /// A spec with a re-exported inner struct, `pong_request::v1::Topics`.
+    const SPEC: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head><title>Kafka protocol</title></head>
+<body>
+<h4>Protocol</h4>
+<h5><a id="protocol_error_codes" href="#protocol_error_codes">Error Codes</a></h5>
+<p>We use numeric codes.</p>
+<table class="data-table"><tbody>
+<tr><th>Error</th><th>Code</th><th>Retriable</th><th>Description</th></tr>
+<tr><td>UNKNOWN_SERVER_ERROR</td><td>-1</td><td>False</td><td>The server experienced an unexpected error.</td></tr>
+<tr><td>NONE</td><td>0</td><td>False</td><td></td></tr>
+<tr><td>CORRUPT_MESSAGE</td><td>2</td><td>True</td><td>Corrupt.</td></tr>
+</table>
+<h5><a id="protocol_api_keys" href="#protocol_api_keys">Api Keys</a></h5>
+<p>The following are the numeric codes.</p>
+<table class="data-table"><tbody>
+<tr><th>Name</th><th>Key</th></tr>
+<tr><td><a href="#The_Messages_Ping">Ping</a></td><td>0</td></tr>
+<tr><td><a href="#The_Messages_Pong">Pong</a></td><td>1</td></tr>
+</table>
+<h5><a id="protocol_messages" href="#protocol_messages">The Messages</a></h5>
+<p>This section gives details.</p>
+<h5>Headers:</h5>
+<pre>Request Header => api_key api_version correlation_id client_id 
+  api_key => INT16
+  api_version => INT16
+  correlation_id => INT32
+  client_id => NULLABLE_STRING
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th>
+<th>Description</th>
+</tr><tr>
+<td>api_key</td><td>The id of the request type.</td></tr>
+<tr>
+<td>api_version</td><td>The version of the API.</td></tr>
+<tr>
+<td>correlation_id</td><td>A user-supplied integer value that will be passed back with the response</td></tr>
+<tr>
+<td>client_id</td><td>A user specified identifier for the client making the request.</td></tr>
+</table>
+<pre>Response Header => correlation_id 
+  correlation_id => INT32
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th>
+<th>Description</th>
+</tr><tr>
+<td>correlation_id</td><td>The user-supplied value passed in with the request</td></tr>
+</table>
+<h5><a name="The_Messages_Ping">Ping API (Key: 0):</a></h5>
+
+<b>Ping Request</b>
+<p><pre>Ping Request (Version: 0) (deprecated) => timeout
+  timeout => INT32
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th><th>Description</th></tr>
+<tr><td>timeout</td><td>The timeout.</td></tr>
+</table>
+<p><pre>Ping Request (Version: 1) => timeout name
+  timeout => INT32
+  name => STRING
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th><th>Description</th></tr>
+<tr><td>timeout</td><td>The timeout.</td></tr>
+<tr><td>name</td><td>The name.</td></tr>
+</table>
+<b>Ping Response</b>
+<p><pre>Ping Response (Version: 0) => error_code
+  error_code => INT16
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th><th>Description</th></tr>
+<tr><td>error_code</td><td>The error.</td></tr>
+</table>
+<p><pre>Ping Response (Version: 1) => error_code
+  error_code => INT16
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th><th>Description</th></tr>
+<tr><td>error_code</td><td>The error.</td></tr>
+</table>
+<h5><a name="The_Messages_Pong">Pong API (Key: 1):</a></h5>
+
+<b>Pong Request</b>
+<p><pre>Pong Request (Version: 0) => [topics]
+  topics => name partition
+    name => STRING
+    partition => INT32
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th><th>Description</th></tr>
+<tr><td>topics</td><td>Topics.</td></tr>
+<tr><td>name</td><td>Name.</td></tr>
+<tr><td>partition</td><td>Partition.</td></tr>
+</table>
+<p><pre>Pong Request (Version: 1) => [topics] timeout
+  topics => name partition
+    name => STRING
+    partition => INT32
+  timeout => INT32
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th><th>Description</th></tr>
+<tr><td>topics</td><td>Topics.</td></tr>
+<tr><td>name</td><td>Name v1.</td></tr>
+<tr><td>partition</td><td>Partition.</td></tr>
+<tr><td>timeout</td><td>Timeout.</td></tr>
+</table>
+<p><pre>Pong Request (Version: 2) => [topics]
+  topics => name [partitions]
+    name => STRING
+    partitions => INT32
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th><th>Description</th></tr>
+<tr><td>topics</td><td>Topics.</td></tr>
+<tr><td>name</td><td>Name.</td></tr>
+<tr><td>partitions</td><td>Partitions.</td></tr>
+</table>
+<b>Pong Response</b>
+<p><pre>Pong Response (Version: 0) => [topics]
+  topics => name
+    name => STRING
+</pre>
+<table class="data-table"><tbody>
+<tr><th>Field</th><th>Description</th></tr>
+<tr><td>topics</td><td>Topics.</td></tr>
+<tr><td>name</td><td>Name.</td></tr>
+</table>
+<h4><a id="protocol_philosophy" href="#protocol_philosophy">Some Common Philosophical Questions</a></h4>
+</body>
+</html>
+"##;
+
+    #[test]
+    fn config_types() {
+        let mut generator = Generator::new(SPEC.to_string(), "local file");
+        generator.set_format(false);
+
+        let config = r#"
+            [types."*"]
+            derives = ["Eq"]
+            [types.PingRequest]
+            derives = ["Hash"]
+            [types."pong_request::v0::Topics"]
+            derives = ["Default"]
+        "#;
+        generator.set_config(Config::from_toml(config).unwrap());
+        assert!(generator.generate().is_ok());
+
+        generator.set_config(Config::from_toml("[types.PangRequest]").unwrap());
+        match generator.generate() {
+            Err(Error::UnknownType(type_name)) => assert_eq!("PangRequest", type_name),
+            res => panic!("{:?}", res.map(|_| ())),
+        }
+
+        let config = r#"[types."pong_request::v1::Topics"]"#;
+        generator.set_config(Config::from_toml(config).unwrap());
+        match generator.generate() {
+            Err(Error::ReexportedType(type_name, defining)) => {
+                assert_eq!("pong_request::v1::Topics", type_name);
+                assert_eq!("pong_request::v0::Topics", defining);
+            }
+            res => panic!("{:?}", res.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn generate_check_modules() {
+        let mut generator = Generator::new(SPEC.to_string(), "local file");
+        generator.set_format(false);
+        let modules = generator.generate_modules().unwrap();
+
+        let file_names: Vec<_> = modules
+            .iter()
+            .map(|(file_name, _)| file_name.as_str())
+            .collect();
+        assert_eq!(vec!["mod.rs", "ping.rs", "pong.rs"], file_names);
+        assert!(modules[0].1.contains("pub enum RequestHeader {"));
+        assert!(modules[0].1.contains("api_key: crate::types::AnyApiKey,"));
+        assert!(modules[0]
+            .1
+            .contains("\nmod ping;\npub use self::ping::*;\n"));
+        assert!(modules[1]
+            .1
+            .starts_with("//! Ping API, generated from: local file\n"));
+        assert!(modules[1].1.contains("pub enum PingRequest {"));
+        assert!(modules[1]
+            .1
+            .contains("const DEPRECATED_VERSIONS: &'static [i16] = &[0];"));
+        assert!(modules[1]
+            .1
+            .contains("const DEPRECATED_VERSIONS: &'static [i16] = &[];"));
+        assert!(modules[2].1.contains("pub mod pong_request {"));
+
+        let out_dir = env::temp_dir().join(format!("spec-parser-modules-{}", process::id()));
+        let _ = fs::remove_dir_all(&out_dir);
+        assert!(generator.check_modules(&out_dir).unwrap().is_some());
+
+        generator.write_modules_to(&out_dir).unwrap();
+        assert_eq!(None, generator.check_modules(&out_dir).unwrap());
+
+        // Stale and missing modules are both reported
+        fs::write(out_dir.join("ping.rs"), "").unwrap();
+        fs::remove_file(out_dir.join("pong.rs")).unwrap();
+        let diff = generator.check_modules(&out_dir).unwrap().unwrap();
+        assert!(diff.contains("ping.rs (generated)"));
+        assert!(diff.contains("pong.rs (generated)"));
+        assert!(!diff.contains("mod.rs (generated)"));
+
+        fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn generate_out_of_tree() {
+        let mut generator = Generator::new(SPEC.to_string(), "local file");
+        generator.set_crate_path("kafka_protocol");
+        generator.set_format(false);
+        let code = generator.generate().unwrap();
+
+        assert!(code.starts_with("// Generated from: local file\n"));
+        assert!(code.contains(
+            "pub use kafka_protocol::model::{ApiKey, RequestHeader, ResponseHeader};\n"
+        ));
+        assert!(!code.contains("pub enum ApiKey {"));
+        assert!(!code.contains("pub enum RequestHeader {"));
+        assert!(code.contains("impl kafka_protocol::request::Request for PingRequest {"));
+    }
+
+    #[test]
+    fn generate_modules() {
+        let generator = Generator::vendored("2.3").unwrap();
+        let code = generator.generate().unwrap();
+        let modules = generator.generate_modules().unwrap();
+
+        assert_eq!("mod.rs", modules[0].0);
+        assert!(modules[0]
+            .1
+            .contains("\nmod api_versions;\npub use self::api_versions::*;\n"));
+
+        let (_, api_versions) = modules
+            .iter()
+            .find(|(file_name, _)| file_name == "api_versions.rs")
+            .unwrap();
+        assert!(api_versions.starts_with("//! ApiVersions API, generated from: "));
+        assert!(api_versions.contains("pub enum ApiVersionsRequest {"));
+        assert!(api_versions.contains("pub enum ApiVersionsResponse {"));
+
+        // Each type is generated once, in a single module
+        for line in code.lines().filter(|line| line.starts_with("pub enum ")) {
+            let defined = modules
+                .iter()
+                .filter(|(_, module)| module.contains(&format!("\n{}\n", line)))
+                .count();
+            assert_eq!(1, defined, "{}", line);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Language agnostic intermediate representation (IR) of a parsed protocol
+//! spec, serialized to JSON as described by `ir.schema.json`.
+
+use serde::Serialize;
+
+use crate::parser::{Spec, SpecParser, VersionedSpecs};
+
+/// Version of the IR format, bumped on breaking changes.
+pub const IR_FORMAT_VERSION: u32 = 1;
+
+/// A parsed protocol spec.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Ir {
+    pub format_version: u32,
+    /// Where the spec was read from, e.g. its upstream URL.
+    pub source: String,
+    pub api_keys: Vec<ApiKey>,
+    pub error_codes: Vec<ErrorCode>,
+    /// Request/response headers, when specs define them.
+    pub headers: Vec<Api>,
+    /// Requests/responses, in spec order.
+    pub apis: Vec<Api>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ApiKey {
+    /// Name of the API, e.g. `Fetch`.
+    pub name: String,
+    pub key: i16,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ErrorCode {
+    /// Name of the error as in specs, e.g. `UNKNOWN_TOPIC_OR_PARTITION`.
+    pub name: String,
+    pub code: i16,
+    pub retriable: bool,
+    pub description: String,
+}
+
+/// A request, a response or a header, e.g. `FetchRequest`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Api {
+    pub name: String,
+    pub versions: Vec<Version>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Version {
+    pub version: i16,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Field {
+    pub name: String,
+    /// Description of the field, when specs have one.
+    pub doc: Option<String>,
+    #[serde(rename = "type")]
+    pub field_type: Type,
+}
+
+/// Type of a field, tagged by `kind`.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Type {
+    /// A primitive, by its name in specs, e.g. `INT32` or `NULLABLE_STRING`.
+    Primitive {
+        name: String,
+    },
+    Array {
+        items: Box<Type>,
+    },
+    /// An array whose length is encoded as an `UNSIGNED_VARINT`.
+    CompactArray {
+        items: Box<Type>,
+    },
+    Struct {
+        fields: Vec<Field>,
+    },
+}
+
+impl Ir {
+    /// Builds the IR of the spec parsed by `parser`, restricted to the
+    /// requests/responses of `api_keys`.
+    pub(crate) fn new(parser: &SpecParser, source: &str, api_keys: &[(String, String)]) -> Ir {
+        Ir {
+            format_version: IR_FORMAT_VERSION,
+            source: source.to_string(),
+            api_keys: parser
+                .api_key_rows
+                .iter()
+                .map(|(name, key)| ApiKey {
+                    name: name.clone(),
+                    key: key.parse().expect("API keys are parsed as i16"),
+                })
+                .collect(),
+            error_codes: parser
+                .err_code_specs
+                .iter()
+                .map(|(name, code, retriable, description)| ErrorCode {
+                    name: name.clone(),
+                    code: code.parse().expect("Error codes are parsed as i16"),
+                    retriable: *retriable,
+                    description: description.clone(),
+                })
+                .collect(),
+            headers: parser
+                .iter_headers()
+                .map(|(name, versions)| api(name, versions))
+                .collect(),
+            apis: parser
+                .iter_req_resp()
+                .filter(|(name, _)| {
+                    let api = name
+                        .trim_end_matches("Request")
+                        .trim_end_matches("Response");
+                    api_keys.iter().any(|(key_name, _)| key_name == api)
+                })
+                .map(|(name, versions)| api(name, versions))
+                .collect(),
+        }
+    }
+}
+
+fn api(name: &str, versions: &VersionedSpecs) -> Api {
+    Api {
+        name: name.to_string(),
+        versions: versions
+            .iter()
+            .map(|(version, spec, docs)| Version {
+                version: *version,
+                fields: match spec_type(spec, &|field| docs.get(field).cloned()) {
+                    Type::Struct { fields } => fields,
+                    _ => unreachable!("All specs are Spec::Struct(_)"),
+                },
+            })
+            .collect(),
+    }
+}
+
+fn spec_type(spec: &Spec, doc: &dyn Fn(&str) -> Option<String>) -> Type {
+    match spec {
+        Spec::Value(primitive) => Type::Primitive {
+            name: primitive.spec_name().to_string(),
+        },
+        Spec::Array(inner) => Type::Array {
+            items: Box::new(spec_type(inner, doc)),
+        },
+        Spec::CompactArray(inner) => Type::CompactArray {
+            items: Box::new(spec_type(inner, doc)),
+        },
+        Spec::Struct(fields) => Type::Struct {
+            fields: fields
+                .iter()
+                .map(|(name, field_spec)| Field {
+                    name: name.to_string(),
+                    doc: doc(name),
+                    field_type: spec_type(field_spec, doc),
+                })
+                .collect(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Primitive;
+    use std::collections::HashMap;
+
+    #[test]
+    fn ir_json() {
+        use crate::parser::Spec::*;
+
+        let spec = Struct(vec![(
+            "topics".into(),
+            Array(Box::new(Struct(vec![(
+                "name".into(),
+                Value(Primitive::String),
+            )]))),
+        )]);
+        let mut docs = HashMap::new();
+        docs.insert("name".into(), "The topic name.".to_string());
+
+        let api = api("FooRequest", &vec![(0, spec, docs)]);
+
+        assert_eq!(
+            serde_json::json!({
+                "name": "FooRequest",
+                "versions": [{
+                    "version": 0,
+                    "fields": [{
+                        "name": "topics",
+                        "doc": null,
+                        "type": {
+                            "kind": "array",
+                            "items": {
+                                "kind": "struct",
+                                "fields": [{
+                                    "name": "name",
+                                    "doc": "The topic name.",
+                                    "type": {"kind": "primitive", "name": "STRING"}
+                                }]
+                            }
+                        }
+                    }]
+                }]
+            }),
+            serde_json::to_value(&api).unwrap()
+        );
+    }
+}
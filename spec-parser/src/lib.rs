@@ -0,0 +1,44 @@
+//! Generates the Rust model of the Kafka protocol, as found in
+//! `kafka-protocol`, by parsing Kafka protocol specifications.
+//!
+//! `Generator` is the entry point to generate a whole model, as a single
+//! file or as one module per API. The components it relies on are exposed
+//! too, to generate other code from a spec:
+//!
+//! - `SpecParser` parses a `protocol.html` spec into versioned specs of
+//!   headers and requests/responses, API keys and error codes.
+//! - `ReqRespMotif` turns a versioned spec into the motifs templates need.
+//! - `Templater` renders motifs into Rust code.
+//!
+//! ```no_run
+//! use spec_parser::{ReqRespMotif, SpecParser, Templater};
+//!
+//! let raw = spec_parser::read_vendored_spec(spec_parser::SPECS_DIR, "2.3").unwrap();
+//! let parser = SpecParser::new(&raw).unwrap();
+//! let templ = Templater::new().unwrap();
+//!
+//! for req_rep in parser.iter_req_resp() {
+//!     let code = templ
+//!         .str_req_resp_enum(&req_rep.enum_name(), &req_rep.enum_vfields())
+//!         .unwrap();
+//!     println!("{}", code);
+//! }
+//! ```
+
+mod config;
+mod diff;
+mod generator;
+pub mod ir;
+mod parser;
+mod templates;
+
+pub use crate::config::{Config, TypeConfig, ALL_TYPES};
+pub use crate::diff::SpecDiff;
+pub use crate::generator::{
+    read_vendored_spec, spec_url, vendored_versions, Error, Generator, SPECS_DIR,
+};
+pub use crate::ir::Ir;
+pub use crate::parser::{
+    ErrorCodeSpecs, ParserError, Primitive, ReqRespMotif, Spec, SpecParser, VersionedSpecs,
+};
+pub use crate::templates::{motif, Templater, TEMPLATE_NAMES};
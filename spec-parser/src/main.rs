@@ -1,52 +1,78 @@
-mod parser;
-mod templates;
-
-use std::fs::{File, OpenOptions};
-use std::io::{prelude::*, stdout, Write};
-use std::process::{self, Command};
+use std::fs;
+use std::io::{stdout, Write};
+use std::path::Path;
+use std::process;
 
 use docopt::Docopt;
-use reqwest;
 use serde::Deserialize;
-use thiserror::Error;
 
-use crate::parser::{ParserError, ReqRespMotif, SpecParser};
-use crate::templates::Templater;
+use spec_parser::{read_vendored_spec, Config, Error, Generator, SpecDiff, SPECS_DIR};
 
 const VERSION: &'static str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_OUT: &'static str = "../kafka-protocol/src/model.rs";
+
+const DEFAULT_OUT_DIR: &'static str = "../kafka-protocol/src/model";
+
 const USAGE: &'static str = "
 Usage:
-  spec-parser [options]
+  spec-parser [generate] [options]
+  spec-parser check [options]
+  spec-parser emit-ir [options]
+  spec-parser diff [--format=FMT] [--specs-dir=DIR] <old> <new>
   spec-parser (-h | --help)
   spec-parser (-V | --version)
 
+Commands:
+  generate  Generate the Rust model, the default.
+  check     Check that the generated Rust model is up to date instead of
+            writing it, printing a diff and failing if not.
+  emit-ir   Export the parsed spec as ir JSON, to stdout by default.
+  diff      Compare the specs <old> and <new>, each either a protocol.html
+            file or a vendored Kafka release, e.g. spec-parser diff 2.2 2.3
+
 Options:
-  -o, --out=OUT    Specify output, - for stdout.
-  -s, --spec=FILE  Specify input protocol.html spec file.
-  -V, --version    Show version.
-  -h, --help       Show this screen.
+  -k, --kafka-version=VER  Kafka release(s) to generate against, comma separated,
+                           among vendored specs [default: 2.3].
+  -o, --out=OUT            Specify output, - for stdout. Must be a directory when
+                           several Kafka releases are given.
+  -m, --modules            Generate one module file per API, and a mod.rs of
+                           common types, into the OUT directory.
+  -s, --spec=FILE          Specify input protocol.html spec file, instead of a
+                           vendored one.
+  -a, --apis=APIS          Only generate the given APIs, comma separated, e.g.
+                           Metadata,Fetch.
+  -t, --templates=DIR      Override templates by the .tera files of DIR named
+                           after them, e.g. req_resp_enum.tera.
+  -c, --config=FILE        Specify a TOML file of extra derives and attributes
+                           per generated type.
+  --no-format              Don't format generated code with rustfmt.
+  --specs-dir=DIR          Specify the directory of vendored specs.
+  -f, --format=FMT         Diff output format, text or json [default: text].
+  -V, --version            Show version.
+  -h, --help               Show this screen.
 ";
 
 #[derive(Debug, Deserialize)]
 struct CmdArgs {
+    cmd_check: bool,
+    cmd_diff: bool,
+    cmd_emit_ir: bool,
+    arg_old: String,
+    arg_new: String,
+    flag_apis: Option<String>,
+    flag_config: Option<String>,
+    flag_format: String,
+    flag_kafka_version: String,
+    flag_modules: bool,
+    flag_no_format: bool,
     flag_out: Option<String>,
     flag_spec: Option<String>,
+    flag_specs_dir: Option<String>,
+    flag_templates: Option<String>,
     flag_version: bool,
 }
 
-#[derive(Error, Debug)]
-enum Error {
-    #[error("{}", .0)]
-    Io(#[from] std::io::Error),
-    #[error("{}", .0)]
-    Http(#[from] reqwest::Error),
-    #[error("{}", .0)]
-    Parser(#[from] ParserError),
-    #[error("{}", .0)]
-    Template(#[from] tera::Error),
-}
-
 fn main() -> Result<(), Error> {
     let args: CmdArgs = Docopt::new(USAGE)
         .and_then(|d| d.deserialize())
@@ -57,77 +83,196 @@ fn main() -> Result<(), Error> {
         process::exit(0);
     }
 
-    let out_file = match args.flag_out {
-        Some(ref out_file) => out_file,
-        None => "../kafka-protocol/src/model.rs",
-    };
+    if args.cmd_diff {
+        let specs_dir = args.flag_specs_dir.as_ref().map_or(SPECS_DIR, |dir| dir);
+        let old = read_spec(specs_dir, &args.arg_old)?;
+        let new = read_spec(specs_dir, &args.arg_new)?;
+        return diff(&old, &new, &args.flag_format);
+    }
 
-    let mut out: Box<dyn Write> = match out_file {
-        "-" => Box::new(stdout()),
-        _ => Box::new(
-            OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(out_file)
-                .unwrap_or_else(|e| {
-                    eprintln!("Output file error: {}", e);
-                    process::exit(1);
-                }),
-        ),
+    let apis = args.flag_apis.as_ref().map(|apis| split_list(apis));
+    let config = match args.flag_config {
+        Some(ref file) => Some(Config::from_file(file)?),
+        None => None,
     };
-
-    let protocol_url;
-    let raw = match args.flag_spec {
-        Some(file) => {
-            protocol_url = "local file";
-            let mut raw = String::new();
-            File::open(&file)?.read_to_string(&mut raw)?;
-            raw
+    let configure = |mut generator: Generator| {
+        if let Some(ref apis) = apis {
+            generator.set_apis(apis);
         }
-        None => {
-            protocol_url = "https://kafka.apache.org/23/protocol.html";
-            reqwest::get(protocol_url)?.text()?
+        if let Some(ref dir) = args.flag_templates {
+            generator.set_templates_dir(dir);
         }
+        if let Some(ref config) = config {
+            generator.set_config(config.clone());
+        }
+        generator.set_format(!args.flag_no_format);
+        generator
+    };
+
+    let cmd = if args.cmd_check {
+        Cmd::Check
+    } else if args.cmd_emit_ir {
+        Cmd::EmitIr
+    } else {
+        Cmd::Generate
+    };
+    let modules = args.flag_modules;
+    let default_out = match cmd {
+        Cmd::EmitIr => "-",
+        _ if modules => DEFAULT_OUT_DIR,
+        _ => DEFAULT_OUT,
+    };
+
+    if cmd == Cmd::EmitIr && modules {
+        eprintln!("Only Rust code can be generated as modules");
+        process::exit(1);
+    }
+    if (cmd == Cmd::Check || modules) && args.flag_out.as_deref() == Some("-") {
+        eprintln!("Only Rust code written to files can be checked or split into modules");
+        process::exit(1);
+    }
+    let run = |generator: Generator, out: &str| match cmd {
+        Cmd::Generate => generate(generator, modules, out).map(|_| true),
+        Cmd::Check => check(generator, modules, out),
+        Cmd::EmitIr => emit_ir(generator, out).map(|_| true),
     };
 
-    println!("Generating Rust code to: {}", out_file);
-
-    let parser = SpecParser::new(&raw)?;
-    let templ = Templater::new()?;
-
-    out.write_all(format!("//! Generated from: {}\n", protocol_url).as_bytes())?;
-    out.write_all(templ.str_headers().as_bytes())?;
-    out.write_all(templ.str_err_codes(&parser.err_code_rows)?.as_bytes())?;
-    out.write_all(templ.str_api_keys(&parser.api_key_rows)?.as_bytes())?;
-
-    for req_rep in parser.iter_req_resp() {
-        out.write_all(
-            templ
-                .str_req_resp_enum(&req_rep.enum_name(), &req_rep.enum_vfields())?
-                .as_bytes(),
-        )?;
-
-        let vstructs = req_rep.mod_vstructs();
-        if vstructs.len() > 0 {
-            out.write_all(
-                templ
-                    .str_req_resp_mod(&req_rep.mod_name(), &vstructs)?
-                    .as_bytes(),
-            )?;
+    if let Some(ref file) = args.flag_spec {
+        let out = args.flag_out.as_ref().map_or(default_out, |out| out);
+        let up_to_date = run(configure(Generator::from_file(file)?), out)?;
+        return exit_stale(up_to_date);
+    }
+
+    let specs_dir = args.flag_specs_dir.as_ref().map_or(SPECS_DIR, |dir| dir);
+    let kafka_versions = split_list(&args.flag_kafka_version);
+
+    if let [kafka_version] = kafka_versions[..] {
+        let generator = configure(Generator::vendored_in(specs_dir, kafka_version)?);
+        let out = args.flag_out.as_ref().map_or(default_out, |out| out);
+        return exit_stale(run(generator, out)?);
+    }
+
+    let out_dir = match args.flag_out {
+        Some(ref out_dir) if Path::new(out_dir).is_dir() => Path::new(out_dir),
+        _ => {
+            eprintln!("Output must be an existing directory for several Kafka releases");
+            process::exit(1);
         }
+    };
+
+    let mut up_to_date = true;
+    for kafka_version in kafka_versions {
+        let generator = configure(Generator::vendored_in(specs_dir, kafka_version)?);
+        let version = kafka_version.replace('.', "_");
+        let out = out_dir.join(match cmd {
+            Cmd::EmitIr => format!("ir_{}.json", version),
+            _ if modules => format!("model_{}", version),
+            _ => format!("model_{}.rs", version),
+        });
+        up_to_date &= run(generator, &out.to_string_lossy())?;
     }
 
-    if out_file != "-" {
-        Command::new("rustfmt")
-            .arg(out_file)
-            .status()
-            .unwrap_or_else(|e| {
-                eprintln!("Problem with rustfmt: {}", e);
-                process::exit(1);
-            });
+    exit_stale(up_to_date)
+}
+
+/// Subcommands generating from a protocol spec.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cmd {
+    Generate,
+    Check,
+    EmitIr,
+}
+
+/// Generates the Rust model of a protocol spec to `out`, a file or - for
+/// stdout, or a directory of `modules`.
+fn generate(generator: Generator, modules: bool, out: &str) -> Result<(), Error> {
+    println!("Generating Rust code to: {}", out);
+
+    if modules {
+        generator.write_modules_to(out)?;
+    } else if out == "-" {
+        stdout().write_all(generator.generate()?.as_bytes())?;
+    } else {
+        generator.write_to(out)?;
     }
 
     println!("Done");
     Ok(())
 }
+
+/// Checks that `out`, a file or a directory of `modules`, holds the Rust
+/// code that would be generated, printing a diff if not. Returns whether it
+/// is up to date.
+fn check(generator: Generator, modules: bool, out: &str) -> Result<bool, Error> {
+    let diff = if modules {
+        generator.check_modules(out)?
+    } else {
+        generator.check(out)?
+    };
+
+    match diff {
+        None => {
+            println!("{} is up to date", out);
+            Ok(true)
+        }
+        Some(diff) => {
+            print!("{}", diff);
+            eprintln!("{} is stale, regenerate it with spec-parser", out);
+            Ok(false)
+        }
+    }
+}
+
+/// Exports the IR of a protocol spec as JSON to `out_file`, - for stdout.
+fn emit_ir(generator: Generator, out_file: &str) -> Result<(), Error> {
+    let ir = serde_json::to_string_pretty(&generator.ir()?)?;
+    if out_file == "-" {
+        println!("{}", ir);
+    } else {
+        fs::write(out_file, ir + "\n")?;
+    }
+
+    Ok(())
+}
+
+/// Exits with a failure when generated code is stale.
+fn exit_stale(up_to_date: bool) -> Result<(), Error> {
+    if !up_to_date {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Prints the differences between two protocol specs, as text or json.
+fn diff(old: &str, new: &str, format: &str) -> Result<(), Error> {
+    let diff = SpecDiff::from_raw(old, new)?;
+
+    match format {
+        "text" => print!("{}", diff),
+        "json" => println!("{}", serde_json::to_string_pretty(&diff)?),
+        _ => {
+            eprintln!("Unknown diff format: {}, expected text or json", format);
+            process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads a spec given either as a protocol.html file or as a vendored
+/// Kafka release.
+fn read_spec(specs_dir: &str, spec: &str) -> Result<String, Error> {
+    if Path::new(spec).is_file() {
+        Ok(fs::read_to_string(spec)?)
+    } else {
+        read_vendored_spec(specs_dir, spec)
+    }
+}
+
+/// Splits a comma separated list.
+fn split_list(list: &str) -> Vec<&str> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
@@ -1,9 +1,11 @@
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
 
 use heck::{CamelCase, SnakeCase};
 use indexmap::IndexMap;
 use lazy_static::*;
+use pest::iterators::Pair;
 use pest::Parser as _;
 use pest_derive::*;
 use regex::Regex;
@@ -18,6 +20,10 @@ pub enum ParserError {
     Invalid(String),
     #[error("Grammar error: {}", .0)]
     Grammar(pest::error::Error<Rule>),
+    #[error("Ambiguous struct name: {}", .0)]
+    NameCollision(String),
+    #[error("Unknown primitive: {}", .0)]
+    UnknownPrimitive(String),
 }
 
 impl From<std::num::ParseIntError> for ParserError {
@@ -39,22 +45,189 @@ pub struct ProtocolParser;
 /// Holds the result of parsing the Kafka protocol html file.
 /// Exposes it in an easily templatable form (template::motif types).
 pub struct SpecParser<'a> {
+    pub err_code_specs: ErrorCodeSpecs,
     pub err_code_rows: motif::ErrorCodeRows,
     pub api_key_rows: motif::ApiKeyRows,
+    header_specs: IndexMap<String, VersionedSpecs<'a>>,
     req_resp_specs: IndexMap<String, VersionedSpecs<'a>>,
+    deprecated_versions: HashMap<String, Vec<i16>>,
 }
 
+/// Vector of (name, code_id, retriable, description) of error codes, as in specs.
+pub type ErrorCodeSpecs = Vec<(String, String, bool, String)>;
+
 /// Vector of (version, spec, fields_doc = {f_name -> doc_string})
-type VersionedSpecs<'a> = Vec<(i16, Spec<'a>, HashMap<Cow<'a, str>, String>)>;
+pub type VersionedSpecs<'a> = Vec<(i16, Spec<'a>, HashMap<Cow<'a, str>, String>)>;
 
 /// Represents a req/resp spec, reflects the form of its recusive BNF definition.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Spec<'a> {
     Value(Primitive),
     Array(Box<Spec<'a>>),
+    CompactArray(Box<Spec<'a>>),
     Struct(Vec<(Cow<'a, str>, Spec<'a>)>),
 }
 
+impl<'a> Spec<'a> {
+    /// Returns (dotted_path, spec_type) of all fields of a spec, inner struct
+    /// fields included, in spec order.
+    pub fn flatten(&self) -> Vec<(String, String)> {
+        fn walk(prefix: &str, spec: &Spec, fields: &mut Vec<(String, String)>) {
+            match spec {
+                Spec::Struct(inner_fields) => {
+                    for (name, field_spec) in inner_fields {
+                        let path = if prefix.is_empty() {
+                            name.to_string()
+                        } else {
+                            format!("{}.{}", prefix, name)
+                        };
+                        fields.push((path.clone(), field_spec.spec_type()));
+                        walk(&path, field_spec, fields);
+                    }
+                }
+                Spec::Array(inner) | Spec::CompactArray(inner) => walk(prefix, inner, fields),
+                Spec::Value(_) => (),
+            }
+        }
+
+        let mut fields = vec![];
+        walk("", self, &mut fields);
+        fields
+    }
+
+    /// Returns the type of a spec as written in protocol specs, e.g.
+    /// `ARRAY(INT32)`, inner structs being `STRUCT`.
+    pub fn spec_type(&self) -> String {
+        match self {
+            Spec::Value(primitive) => primitive.spec_name().to_string(),
+            Spec::Array(inner) => format!("ARRAY({})", inner.spec_type()),
+            Spec::CompactArray(inner) => format!("COMPACT_ARRAY({})", inner.spec_type()),
+            Spec::Struct(_) => "STRUCT".to_string(),
+        }
+    }
+
+    /// Tells whether a spec is of a flexible version, whose root ends with
+    /// tagged fields.
+    pub fn is_flexible(&self) -> bool {
+        match self {
+            Spec::Struct(fields) => fields
+                .iter()
+                .any(|(_, spec)| *spec == Spec::Value(Primitive::TagBuffer)),
+            _ => false,
+        }
+    }
+
+    /// Returns (field_path, struct_spec) of the inner structs of a spec,
+    /// breadth first, field_path being the names of the fields leading to
+    /// a struct from the spec root.
+    pub fn inner_structs(&self) -> Vec<(Vec<&str>, &Spec<'a>)> {
+        let mut structs = Vec::new();
+        let mut q = VecDeque::new();
+
+        // Initializes the specs exploration queue
+        if let Spec::Struct(fields) = self {
+            for (f_name, f_spec) in fields {
+                match f_spec {
+                    Spec::Value(_) => (),
+                    Spec::Array(inner) | Spec::CompactArray(inner) => {
+                        q.push_back((vec![f_name.as_ref()], &**inner))
+                    }
+                    Spec::Struct(_) => q.push_back((vec![f_name.as_ref()], f_spec)),
+                }
+            }
+        } else {
+            unreachable!("All specs are Spec::Struct(_)");
+        }
+
+        // Builds the specs dependencies stack
+        while let Some((path, spec)) = q.pop_front() {
+            match spec {
+                Spec::Value(_) => (),
+                Spec::Array(inner) | Spec::CompactArray(inner) => q.push_back((path, &**inner)),
+                Spec::Struct(fields) => {
+                    for (f_name, f_spec) in fields {
+                        let mut f_path = path.clone();
+                        f_path.push(f_name.as_ref());
+                        match f_spec {
+                            Spec::Value(_) => (),
+                            Spec::Array(inner) | Spec::CompactArray(inner) => {
+                                q.push_back((f_path, &**inner))
+                            }
+                            Spec::Struct(_) => q.push_back((f_path, f_spec)),
+                        }
+                    }
+                    structs.push((path, spec));
+                }
+            }
+        }
+
+        structs
+    }
+
+    /// Returns the Rust names of the inner structs of a spec, by dotted
+    /// field path.
+    ///
+    /// A struct is named after its field, camel cased. Structs of the same
+    /// name but of different specs are disambiguated by prefixing the names of
+    /// their parent fields, e.g. `TopicsPartitions` and `ResponsesPartitions`.
+    /// Fails when a name still designates different structs with all parent
+    /// fields prefixed.
+    pub fn struct_names(&self) -> Result<HashMap<String, String>, ParserError> {
+        let structs = self.inner_structs();
+        // Number of trailing fields of its path a struct is named after
+        let mut depths = vec![1; structs.len()];
+
+        loop {
+            let names = structs
+                .iter()
+                .zip(&depths)
+                .map(|((path, _), depth)| path[path.len() - depth..].join("_").to_camel_case())
+                .collect::<Vec<_>>();
+
+            let mut clashes: IndexMap<&str, Vec<usize>> = IndexMap::new();
+            for (i, (_, spec)) in structs.iter().enumerate() {
+                if structs
+                    .iter()
+                    .zip(&names)
+                    .any(|((_, other), name)| *name == names[i] && other != spec)
+                {
+                    clashes.entry(&names[i]).or_default().push(i);
+                }
+            }
+
+            if clashes.is_empty() {
+                return Ok(structs
+                    .iter()
+                    .zip(names)
+                    .map(|((path, _), name)| (path.join("."), name))
+                    .collect());
+            }
+
+            for (name, clashing) in clashes {
+                let growing = clashing
+                    .iter()
+                    .filter(|i| depths[**i] < structs[**i].0.len())
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if growing.is_empty() {
+                    let paths = clashing
+                        .iter()
+                        .map(|i| structs[*i].0.join("."))
+                        .collect::<Vec<_>>();
+                    return Err(ParserError::NameCollision(format!(
+                        "{} for fields {}",
+                        name,
+                        paths.join(", ")
+                    )));
+                }
+                for i in growing {
+                    depths[i] += 1;
+                }
+            }
+        }
+    }
+}
+
 impl<'a> SpecParser<'a> {
     /// Parses raw file content and return an initialized SpecParser.
     pub fn new(raw: &'a str) -> Result<Self, ParserError> {
@@ -62,15 +235,18 @@ impl<'a> SpecParser<'a> {
             .next() // there is exactly one { file }
             .expect("Unreachable file rule");
 
+        let mut err_code_specs = vec![];
         let mut err_code_rows = vec![];
         let mut api_key_rows = vec![];
+        let mut header_specs = IndexMap::new();
         let mut req_resp_specs = IndexMap::new();
+        let mut deprecated_versions = HashMap::new();
 
         for target in parsed_file.into_inner() {
             match target.as_rule() {
                 // Parses the error codes table into a templatable enum motif
                 Rule::error_codes => {
-                    err_code_rows = target
+                    err_code_specs = target
                         .into_inner() // inner { table }
                         .next() // there is exactly one { table }
                         .expect("Unreachable error_codes table rule")
@@ -82,14 +258,29 @@ impl<'a> SpecParser<'a> {
                                 .into_iter()
                                 .map(|td| td.into_inner().as_str()) // inner { content }
                                 .collect::<Vec<_>>();
+                            (
+                                String::from(row[0]),
+                                String::from(row[1]),
+                                yes_no(row[2]) == "Yes",
+                                String::from(row[3]),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    err_code_rows = err_code_specs
+                        .iter()
+                        .map(|(name, code, retriable, description)| {
                             (
                                 // Enum variant name of the error code
-                                String::from(row[0]).to_camel_case(),
+                                name.to_camel_case(),
                                 // Enum value (i16) of the error code
-                                String::from(row[1]),
+                                code.clone(),
                                 // Error's description
                                 capped_comment(
-                                    &format!("{} Retriable: {}.", row[3], yes_no(row[2])),
+                                    &format!(
+                                        "{} Retriable: {}.",
+                                        description,
+                                        if *retriable { "Yes" } else { "No" }
+                                    ),
                                     4,
                                 ),
                             )
@@ -127,6 +318,24 @@ impl<'a> SpecParser<'a> {
                         .collect::<Vec<_>>();
                 }
 
+                // Parses a request/response header BNF definition and description table
+                Rule::header => {
+                    let mut sections = target.into_inner(); // inner { content, table }
+                    let (name, version, spec) = parse_struct_spec(
+                        sections
+                            .next()
+                            .expect("Unreachable header content rule")
+                            .as_str(),
+                    )?;
+                    let fields_doc =
+                        fields_doc(sections.next().expect("Unreachable header table rule"));
+
+                    header_specs
+                        .entry(name)
+                        .or_insert_with(Vec::new)
+                        .push((version, spec, fields_doc));
+                }
+
                 // Parses all req/resp BNF definitions and description tables
                 // into templatable versioned enum/mod/strut motifs
                 Rule::req_resp => {
@@ -142,23 +351,19 @@ impl<'a> SpecParser<'a> {
                         match section.as_rule() {
                             Rule::content => {
                                 let (name, version, spec) = parse_struct_spec(section.as_str())?;
+                                if is_deprecated(section.as_str()) {
+                                    deprecated_versions
+                                        .entry(name.clone())
+                                        .or_insert_with(Vec::new)
+                                        .push(version);
+                                }
                                 curr_name = Some(name);
                                 curr_version = Some(version);
                                 curr_spec = Some(spec);
                             }
 
                             Rule::table => {
-                                let fields_doc = section
-                                    .into_inner() // inner { td }
-                                    .map(|tr| {
-                                        let row = tr
-                                            .into_inner() // inner { td }
-                                            .into_iter()
-                                            .map(|td| td.into_inner().as_str()) // inner { content }
-                                            .collect::<Vec<_>>();
-                                        (clean_name(row[0]), String::from(row[1]))
-                                    })
-                                    .collect::<HashMap<_, _>>();
+                                let fields_doc = fields_doc(section);
 
                                 let name = curr_name.take().expect("unreachable no name parsed");
                                 let version = (
@@ -186,13 +391,102 @@ impl<'a> SpecParser<'a> {
             }
         }
 
+        // Fails early on inner struct names that can't be disambiguated
+        for (name, versions) in header_specs.iter().chain(&req_resp_specs) {
+            for (version, spec, _) in versions {
+                spec.struct_names().map_err(|e| match e {
+                    ParserError::NameCollision(msg) => {
+                        ParserError::NameCollision(format!("{} v{}: {}", name, version, msg))
+                    }
+                    e => e,
+                })?;
+            }
+        }
+
         Ok(SpecParser {
+            err_code_specs,
             err_code_rows,
             api_key_rows,
+            header_specs,
             req_resp_specs,
+            deprecated_versions,
         })
     }
 
+    /// Returns the version of the request header, or of the response header
+    /// when `response`, messages of each API version are sent with.
+    ///
+    /// Follows Kafka: flexible versions use request header v2 and response
+    /// header v1, others request header v1 and response header v0. Except
+    /// `ControlledShutdown` v0 requests, using request header v0, and
+    /// `ApiVersions` responses, always using response header v0 so that
+    /// clients can read them whatever versions brokers support. A header
+    /// version missing from specs falls back to the previous one they define,
+    /// e.g. to the single unversioned header of specs before Kafka 2.4.
+    pub fn header_version_rows(&self, response: bool) -> motif::HeaderVersionRows {
+        let (header, genre) = if response {
+            ("ResponseHeader", "Response")
+        } else {
+            ("RequestHeader", "Request")
+        };
+        let defined = self
+            .header_specs
+            .get(header)
+            .map(|versions| versions.iter().map(|(v, _, _)| *v).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let header_version = |kafka_version: i16| {
+            defined
+                .iter()
+                .rev()
+                .find(|v| **v <= kafka_version)
+                .or_else(|| defined.first())
+                .cloned()
+                .unwrap_or(0)
+        };
+
+        self.api_key_rows
+            .iter()
+            .map(|(name, _)| {
+                let kafka_version = |api_version: i16, flexible: bool| match (response, flexible) {
+                    (true, _) if name == "ApiVersions" => 0,
+                    (true, flexible) => flexible as i16,
+                    (false, true) => 2,
+                    (false, false) if name == "ControlledShutdown" && api_version == 0 => 0,
+                    (false, false) => 1,
+                };
+
+                // (api_version, header_version) of each API version, in increasing order
+                let versions = match self.req_resp_specs.get(&format!("{}{}", name, genre)) {
+                    Some(versions) => versions
+                        .iter()
+                        .map(|(v, spec, _)| {
+                            (*v, header_version(kafka_version(*v, spec.is_flexible())))
+                        })
+                        .collect::<Vec<_>>(),
+                    None => vec![(0, header_version(kafka_version(0, false)))],
+                };
+
+                let default = versions[0].1;
+                let mut ranges = versions
+                    .windows(2)
+                    .filter(|w| w[0].1 != w[1].1)
+                    .map(|w| w[1])
+                    .collect::<Vec<_>>();
+                ranges.reverse();
+
+                (name.clone(), ranges, default)
+            })
+            .collect()
+    }
+
+    /// Iterates over the request and response headers, `RequestHeader` and
+    /// `ResponseHeader`, when specs define them.
+    pub fn iter_headers(&self) -> impl Iterator<Item = (&String, &VersionedSpecs)> {
+        self.header_specs.iter()
+    }
+
+    /// Iterates over the requests and responses, e.g. `FetchRequest`, in
+    /// the order of specs.
     pub fn iter_req_resp(&self) -> impl Iterator<Item = (&String, &VersionedSpecs)> {
         let mut i = 0;
 
@@ -202,13 +496,30 @@ impl<'a> SpecParser<'a> {
             req_resp
         })
     }
+
+    /// Returns the versions of the request/response `name`, e.g.
+    /// `FetchRequest`, the spec marks as deprecated, with `(deprecated)`
+    /// after the version of their BNF definition.
+    pub fn deprecated_versions(&self, name: &str) -> &[i16] {
+        self.deprecated_versions
+            .get(name)
+            .map_or(&[], |versions| versions.as_slice())
+    }
 }
 
 /// Defines methods required to template a versioned req/resp's enum/mod/strucs.
+///
+/// Implemented by the (name, versioned specs) items of `SpecParser` iterators.
 pub trait ReqRespMotif {
+    /// Name of the versioned enum, e.g. `FetchRequest`.
     fn enum_name(&self) -> String;
+    /// Fields of each version of the enum.
     fn enum_vfields(&self) -> motif::EnumVfields;
+    /// Versions of the enum, and the version ranges of its fields.
+    fn enum_vranges(&self) -> motif::VersionRanges;
+    /// Name of the module of the inner structs, e.g. `fetch_request`.
     fn mod_name(&self) -> String;
+    /// Inner structs of each version.
     fn mod_vstructs(&self) -> motif::ModVstructs;
 }
 
@@ -224,18 +535,23 @@ impl<'a> ReqRespMotif for (&'a String, &'a VersionedSpecs<'a>) {
             field_spec: &Spec,
             enum_name: &str,
             version: &i16,
+            struct_names: &HashMap<String, String>,
         ) -> String {
             match field_spec {
-                Spec::Value(primitive) => primitive.rust_type(),
+                Spec::Value(primitive) => primitive.field_rust_type(field_name),
                 Spec::Array(inner) => format!(
                     "Vec<{}>",
-                    rust_type_for(field_name, &*inner, enum_name, version)
+                    rust_type_for(field_name, &*inner, enum_name, version, struct_names)
+                ),
+                Spec::CompactArray(inner) => format!(
+                    "crate::types::CompactArray<{}>",
+                    rust_type_for(field_name, inner, enum_name, version, struct_names)
                 ),
                 Spec::Struct(_) => format!(
                     "{}::v{}::{}",
                     enum_name.to_snake_case(),
                     version,
-                    field_name.to_camel_case()
+                    struct_names[field_name]
                 ),
             }
         }
@@ -243,13 +559,22 @@ impl<'a> ReqRespMotif for (&'a String, &'a VersionedSpecs<'a>) {
         self.1
             .iter()
             .map(|(version, spec, docs)| {
+                let struct_names = spec
+                    .struct_names()
+                    .expect("Struct names checked by SpecParser::new");
                 let fields: motif::Fields = if let Spec::Struct(fields) = spec {
                     fields
                         .iter()
                         .map(|(field_name, field_spec)| {
                             (
                                 field_name.to_string(),
-                                rust_type_for(field_name, field_spec, self.0, version),
+                                rust_type_for(
+                                    field_name,
+                                    field_spec,
+                                    self.0,
+                                    version,
+                                    &struct_names,
+                                ),
                                 docs.get(field_name).map_or_else(
                                     || String::default(),
                                     |doc| capped_comment(doc, 8),
@@ -265,68 +590,111 @@ impl<'a> ReqRespMotif for (&'a String, &'a VersionedSpecs<'a>) {
             .collect::<Vec<_>>()
     }
 
+    fn enum_vranges(&self) -> motif::VersionRanges {
+        let versions = self.1.iter().map(|(version, _, _)| *version).collect();
+
+        // Ranges of contiguous versions of each field, by field path
+        let mut ranges: IndexMap<String, Vec<(i16, i16)>> = IndexMap::new();
+        let mut previous = None;
+        for (version, spec, _) in self.1 {
+            for (path, _) in spec.flatten() {
+                let field_ranges = ranges.entry(path).or_insert_with(Vec::new);
+                match field_ranges.last_mut() {
+                    Some((_, last)) if Some(*last) == previous => *last = *version,
+                    _ => field_ranges.push((*version, *version)),
+                }
+            }
+            previous = Some(*version);
+        }
+
+        let fields = ranges
+            .into_iter()
+            .flat_map(|(path, field_ranges)| {
+                field_ranges
+                    .into_iter()
+                    .map(move |(first, last)| (path.clone(), first, last))
+            })
+            .collect();
+
+        (versions, fields)
+    }
+
     fn mod_name(&self) -> String {
         self.0.to_snake_case()
     }
 
     fn mod_vstructs(&self) -> motif::ModVstructs {
         /// Returns Rust type (as a String) for a given struct field
-        fn rust_type_for(field_name: &str, field_spec: &Spec) -> String {
+        fn rust_type_for(
+            field_path: &str,
+            field_spec: &Spec,
+            struct_names: &HashMap<String, String>,
+        ) -> String {
             match field_spec {
-                Spec::Value(primitive) => primitive.rust_type(),
-                Spec::Array(inner) => format!("Vec<{}>", rust_type_for(field_name, &*inner)),
-                Spec::Struct(_) => field_name.to_camel_case(),
-            }
-        }
-
-        /// Returns a stack of the inner structs of a given spec.
-        fn spec_deps<'a>(spec: &'a Spec<'_>) -> Vec<(String, &'a Spec<'a>)> {
-            let mut deps = Vec::new();
-            let mut q = VecDeque::new();
-
-            // Initializes the specs exploration queue
-            if let Spec::Struct(fields) = spec {
-                for (f_name, f_spec) in fields {
-                    match f_spec {
-                        Spec::Value(_) => (),
-                        Spec::Array(inner) => q.push_back((f_name.to_camel_case(), &**inner)),
-                        Spec::Struct(_) => q.push_back((f_name.to_camel_case(), f_spec)),
-                    }
+                Spec::Value(primitive) => {
+                    let field_name = field_path.rsplit('.').next().unwrap_or(field_path);
+                    primitive.field_rust_type(field_name)
                 }
-            } else {
-                unreachable!("All specs are Spec::Struct(_)");
+                Spec::Array(inner) => {
+                    format!("Vec<{}>", rust_type_for(field_path, &*inner, struct_names))
+                }
+                Spec::CompactArray(inner) => format!(
+                    "crate::types::CompactArray<{}>",
+                    rust_type_for(field_path, inner, struct_names)
+                ),
+                Spec::Struct(_) => struct_names[field_path].clone(),
             }
+        }
 
-            // Builds the specs dependencies stack
-            while let Some((ref f_name, ref f_spec)) = q.pop_front() {
-                match (f_name, f_spec) {
-                    (_, Spec::Value(_)) => (),
-                    (_, Spec::Array(inner)) => q.push_back((f_name.to_camel_case(), &**inner)),
-                    (_, Spec::Struct(fields)) => {
-                        for (f_name, f_spec) in fields {
-                            match f_spec {
-                                Spec::Value(_) => (),
-                                Spec::Array(inner) => {
-                                    q.push_back((f_name.to_camel_case(), &**inner))
-                                }
-                                Spec::Struct(_) => q.push_back((f_name.to_camel_case(), f_spec)),
-                            }
-                        }
-                        deps.push((f_name.to_camel_case(), &**f_spec));
-                    }
+        /// Returns (struct_name, field_path, struct_spec) of the inner structs
+        /// of a given spec, a struct found at several paths being listed once.
+        fn spec_deps<'a, 'b>(
+            spec: &'a Spec<'b>,
+            struct_names: &HashMap<String, String>,
+        ) -> Vec<(String, String, &'a Spec<'b>)> {
+            let mut deps: Vec<(String, String, &Spec)> = Vec::new();
+            for (path, struct_spec) in spec.inner_structs() {
+                let path = path.join(".");
+                let name = &struct_names[&path];
+                if !deps.iter().any(|(dep_name, _, _)| dep_name == name) {
+                    deps.push((name.clone(), path, struct_spec));
                 }
             }
-
             deps
         }
 
-        self.1
+        let vdeps = self
+            .1
             .iter()
             .map(|(_version, spec, docs)| {
-                let structs = spec_deps(spec);
+                let struct_names = spec
+                    .struct_names()
+                    .expect("Struct names checked by SpecParser::new");
+                (spec_deps(spec, &struct_names), struct_names, docs)
+            })
+            .filter(|(structs, _struct_names, _docs)| !structs.is_empty())
+            .collect::<Vec<_>>();
+
+        vdeps
+            .iter()
+            .enumerate()
+            .map(|(i, (structs, struct_names, docs))| {
                 structs
                     .iter()
-                    .map(|(struct_name, struct_spec)| {
+                    .map(|(struct_name, struct_path, struct_spec)| {
+                        // Re-exports an identical struct of a previous version, if any
+                        let shared = vdeps[..i]
+                            .iter()
+                            .position(|(prev_structs, _struct_names, _docs)| {
+                                prev_structs
+                                    .iter()
+                                    .any(|prev| prev.0 == *struct_name && prev.2 == *struct_spec)
+                            })
+                            .map(|j| format!("v{}", j));
+                        if shared.is_some() {
+                            return (struct_name.clone(), vec![], shared);
+                        }
+
                         let struct_fields: motif::Fields = if let Spec::Struct(fields) = struct_spec
                         {
                             fields
@@ -334,7 +702,11 @@ impl<'a> ReqRespMotif for (&'a String, &'a VersionedSpecs<'a>) {
                                 .map(|(field_name, field_spec)| {
                                     (
                                         field_name.to_string(),
-                                        rust_type_for(field_name, field_spec),
+                                        rust_type_for(
+                                            &format!("{}.{}", struct_path, field_name),
+                                            field_spec,
+                                            struct_names,
+                                        ),
                                         docs.get(field_name).map_or_else(
                                             || String::default(),
                                             |doc| capped_comment(doc, 12),
@@ -345,11 +717,10 @@ impl<'a> ReqRespMotif for (&'a String, &'a VersionedSpecs<'a>) {
                         } else {
                             unreachable!("All specs are Spec::Struct(_)");
                         };
-                        (struct_name.clone(), struct_fields)
+                        (struct_name.clone(), struct_fields, None)
                     })
                     .collect::<Vec<_>>()
             })
-            .filter(|versions| versions.len() > 0)
             .collect::<Vec<_>>()
     }
 }
@@ -370,6 +741,20 @@ fn clean_name(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Returns the descriptions of a table of fields, by field name.
+fn fields_doc<'a>(table: Pair<'a, Rule>) -> HashMap<Cow<'a, str>, String> {
+    table
+        .into_inner() // inner { tr }
+        .map(|tr| {
+            let row = tr
+                .into_inner() // inner { td }
+                .map(|td| td.into_inner().as_str()) // inner { content }
+                .collect::<Vec<_>>();
+            (clean_name(row[0]), String::from(row[1]))
+        })
+        .collect()
+}
+
 fn capped_comment(text: &str, nb_indent: usize) -> String {
     lazy_static! {
         static ref RE: Regex = Regex::new(r"\b.{1,70}\b\W?").expect("Invalid regex");
@@ -388,16 +773,30 @@ fn capped_comment(text: &str, nb_indent: usize) -> String {
         .join("\n")
 }
 
+/// Tells whether the BNF definition `raw` of a request/response version is
+/// marked as deprecated, e.g. `Foo Request (Version: 0) (deprecated) => id`.
+fn is_deprecated(raw: &str) -> bool {
+    lazy_static! {
+        static ref DEPRECATED: Regex =
+            Regex::new(r"^\w+ \w+ \(Version: \d+\) \((?i:deprecated)\) =>").expect("Invalid regex");
+    }
+
+    DEPRECATED.is_match(raw)
+}
+
 fn parse_struct_spec<'a>(raw: &'a str) -> Result<(String, i16, Spec<'a>), ParserError> {
     #[derive(Debug, Clone)]
     enum Field<'a> {
         Simple(Cow<'a, str>),
         Array(Cow<'a, str>),
+        TagBuffer,
     }
 
     impl<'a> Field<'a> {
         fn new(name: &str) -> Field {
-            if name.chars().nth(0).expect("no first char") == '['
+            if name == Primitive::TagBuffer.spec_name() {
+                Field::TagBuffer
+            } else if name.chars().nth(0).expect("no first char") == '['
                 && name.chars().last().expect("no last char") == ']'
             {
                 Field::Array(clean_name(&name[1..name.len() - 1]))
@@ -411,6 +810,7 @@ fn parse_struct_spec<'a>(raw: &'a str) -> Result<(String, i16, Spec<'a>), Parser
     enum Kind<'a> {
         Value(Primitive),
         Array(Primitive),
+        CompactArray(Primitive),
         Struct(Vec<Field<'a>>),
     }
 
@@ -426,38 +826,44 @@ fn parse_struct_spec<'a>(raw: &'a str) -> Result<(String, i16, Spec<'a>), Parser
             Kind::Struct(fields)
         }
 
-        fn for_field(raw: &str) -> Kind {
+        fn for_field(raw: &str) -> Result<Kind, ParserError> {
             lazy_static! {
-                static ref ARRAY: Regex = Regex::new(r"ARRAY\((.+?)\)").expect("Invalid regex");
+                static ref ARRAY: Regex =
+                    Regex::new(r"^(COMPACT_)?ARRAY\((.+?)\)$").expect("Invalid regex");
             }
 
             let kind = raw.split(' ').filter(|s| *s != "").collect::<Vec<_>>();
             if kind.len() == 1 {
                 let field = kind[0];
-                if Primitive::is_valid(field) {
-                    Kind::Value(Primitive::from(field))
-                } else if ARRAY.is_match(field) {
-                    let inner = ARRAY
-                        .captures(field)
-                        .expect("unreachable field kind parsing")
-                        .get(1)
+                if let Some(array) = ARRAY.captures(field) {
+                    let inner = array
+                        .get(2)
                         .expect("unreachable field kind capture")
                         .as_str();
-                    if Primitive::is_valid(inner) {
-                        Kind::Array(Primitive::from(inner))
+                    if !Primitive::is_name(inner) {
+                        Ok(Kind::Struct(vec![Field::new(inner)]))
+                    } else if array.get(1).is_some() {
+                        Ok(Kind::CompactArray(inner.parse()?))
                     } else {
-                        Kind::Struct(vec![Field::new(inner)])
+                        Ok(Kind::Array(inner.parse()?))
                     }
+                } else if Primitive::is_name(field) {
+                    Ok(Kind::Value(field.parse()?))
                 } else {
-                    Kind::Struct(vec![Field::new(field)])
+                    Ok(Kind::Struct(vec![Field::new(field)]))
                 }
             } else {
                 let fields = kind.iter().map(|name| Field::new(name)).collect::<Vec<_>>();
-                Kind::Struct(fields)
+                Ok(Kind::Struct(fields))
             }
         }
     }
 
+    /// Returns the field a `TAG_BUFFER` in a list of fields stands for.
+    fn tagged_fields<'a>() -> (Cow<'a, str>, Spec<'a>) {
+        ("tagged_fields".into(), Spec::Value(Primitive::TagBuffer))
+    }
+
     #[derive(Debug, Clone)]
     struct Line<'a> {
         name: Cow<'a, str>,
@@ -485,6 +891,17 @@ fn parse_struct_spec<'a>(raw: &'a str) -> Result<(String, i16, Spec<'a>), Parser
                 specs.insert(name.into(), Spec::Array(Box::new(Spec::Value(primitive))));
             }
 
+            Line {
+                kind: Kind::CompactArray(primitive),
+                name,
+                ..
+            } => {
+                specs.insert(
+                    name.into(),
+                    Spec::CompactArray(Box::new(Spec::Value(primitive))),
+                );
+            }
+
             Line {
                 kind: Kind::Struct(fields),
                 name,
@@ -505,6 +922,7 @@ fn parse_struct_spec<'a>(raw: &'a str) -> Result<(String, i16, Spec<'a>), Parser
                             })?;
                             inner_specs.push((name.clone(), Spec::Array(Box::new(spec.clone()))));
                         }
+                        Field::TagBuffer => inner_specs.push(tagged_fields()),
                     }
                 }
                 specs.insert(name.into(), Spec::Struct(inner_specs));
@@ -516,19 +934,26 @@ fn parse_struct_spec<'a>(raw: &'a str) -> Result<(String, i16, Spec<'a>), Parser
 
     lazy_static! {
         static ref HEADER: Regex =
-            Regex::new(r"(\w+) (\w+) \(Version: (\d+)\) =>(.*)").expect("Invalid regex");
+            Regex::new(r"(\w+) (\w+) \(Version: (\d+)\)(?: \((?i:deprecated)\))? =>(.*)")
+                .expect("Invalid regex");
+        // Request/response headers, unversioned before Kafka 2.4
+        static ref MSG_HEADER: Regex =
+            Regex::new(r"^(Request|Response) (Header)(?: v(\d+))? =>(.*)").expect("Invalid regex");
     }
 
     let raw_lines = raw.split('\n').collect::<Vec<_>>();
     let (first, rest) = raw_lines.split_first().expect("Unreachable split fail");
 
-    let header = HEADER.captures(first).ok_or_else(|| {
-        ParserError::Invalid(format!("First line didn't match: {:?} {}", *HEADER, first))
-    })?;
+    let header = HEADER
+        .captures(first)
+        .or_else(|| MSG_HEADER.captures(first))
+        .ok_or_else(|| {
+            ParserError::Invalid(format!("First line didn't match: {:?} {}", *HEADER, first))
+        })?;
 
     let (name, version) = match (header.get(1), header.get(2), header.get(3)) {
-        (Some(name), Some(genre), Some(version)) => {
-            let version: i16 = version.as_str().parse()?;
+        (Some(name), Some(genre), version) => {
+            let version: i16 = version.map_or(Ok(0), |version| version.as_str().parse())?;
             let name = format!("{}{}", name.as_str(), genre.as_str());
             (name, version)
         }
@@ -545,11 +970,11 @@ fn parse_struct_spec<'a>(raw: &'a str) -> Result<(String, i16, Spec<'a>), Parser
             let parts = s.split(" =>").collect::<Vec<_>>();
 
             let name = clean_name(parts.get(0).expect(&format!("Invalid line: {}", s)).trim());
-            let kind = Kind::for_field(parts.get(1).expect(&format!("Invalid line: {}", s)));
+            let kind = Kind::for_field(parts.get(1).expect(&format!("Invalid line: {}", s)))?;
 
-            Line { name, kind }
+            Ok(Line { name, kind })
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, ParserError>>()?;
 
     let mut fields_spec = HashMap::new();
     lines.reverse();
@@ -573,6 +998,7 @@ fn parse_struct_spec<'a>(raw: &'a str) -> Result<(String, i16, Spec<'a>), Parser
                     })?;
                     specs.push((name.clone(), Spec::Array(Box::new(field_spec.clone()))));
                 }
+                Field::TagBuffer => specs.push(tagged_fields()),
             }
         }
     }
@@ -624,53 +1050,108 @@ pub enum Primitive {
     NullableBytes,
     /// Represents a sequence of Kafka records as NULLABLE_BYTES.
     Records,
+    /// Represents a type 4 immutable universally unique identifier (Uuid).
+    /// The values are encoded using sixteen bytes in network byte order (big-endian).
+    Uuid,
+    /// Represents a double-precision 64-bit format IEEE 754 value.
+    /// The values are encoded using eight bytes in network byte order (big-endian).
+    Float64,
+    /// Represents an integer between 0 and 2^32-1 inclusive.
+    /// Encoding follows the variable-length encoding from Google Protocol Buffers.
+    UnsignedVarint,
+    /// Represents a sequence of characters. First the length N + 1 is given as an
+    /// UNSIGNED_VARINT. Then N bytes follow which are the UTF-8 encoding of the
+    /// character sequence.
+    CompactString,
+    /// Represents a sequence of characters or null. For non-null strings,
+    /// first the length N + 1 is given as an UNSIGNED_VARINT. Then N bytes follow
+    /// which are the UTF-8 encoding of the character sequence. A null string is
+    /// represented with a length of 0.
+    CompactNullableString,
+    /// Represents a raw sequence of bytes. First the length N + 1 is given as an
+    /// UNSIGNED_VARINT. Then N bytes follow.
+    CompactBytes,
+    /// Represents a raw sequence of bytes or null. For non-null values,
+    /// first the length N + 1 is given as an UNSIGNED_VARINT. Then N bytes follow.
+    /// A null object is represented with a length of 0.
+    CompactNullableBytes,
+    /// Represents a sequence of Kafka records as COMPACT_NULLABLE_BYTES.
+    CompactRecords,
+    /// Represents a sequence of tagged fields. First the number of fields is
+    /// given as an UNSIGNED_VARINT. Then each field follows as its tag and the
+    /// size N of its data, both UNSIGNED_VARINTs, followed by N bytes of data.
+    TagBuffer,
 }
 
-impl Primitive {
-    fn from(raw: &str) -> Primitive {
+impl FromStr for Primitive {
+    type Err = ParserError;
+
+    fn from_str(raw: &str) -> Result<Primitive, ParserError> {
         match raw {
-            "BOOLEAN" => Primitive::Boolean,
-            "INT8" => Primitive::Int8,
-            "INT16" => Primitive::Int16,
-            "INT32" => Primitive::Int32,
-            "INT64" => Primitive::Int64,
-            "UINT32" => Primitive::Uint32,
-            "VARINT" => Primitive::Varint,
-            "VARLONG" => Primitive::Varlong,
-            "STRING" => Primitive::String,
-            "NULLABLE_STRING" => Primitive::NullableString,
-            "BYTES" => Primitive::Bytes,
-            "NULLABLE_BYTES" => Primitive::NullableBytes,
-            "RECORDS" => Primitive::Records,
-            _ => unreachable!("Unknown primitive: {}", raw),
+            "BOOLEAN" => Ok(Primitive::Boolean),
+            "INT8" => Ok(Primitive::Int8),
+            "INT16" => Ok(Primitive::Int16),
+            "INT32" => Ok(Primitive::Int32),
+            "INT64" => Ok(Primitive::Int64),
+            "UINT32" => Ok(Primitive::Uint32),
+            "VARINT" => Ok(Primitive::Varint),
+            "VARLONG" => Ok(Primitive::Varlong),
+            "STRING" => Ok(Primitive::String),
+            "NULLABLE_STRING" => Ok(Primitive::NullableString),
+            "BYTES" => Ok(Primitive::Bytes),
+            "NULLABLE_BYTES" => Ok(Primitive::NullableBytes),
+            "RECORDS" => Ok(Primitive::Records),
+            "UUID" => Ok(Primitive::Uuid),
+            "FLOAT64" => Ok(Primitive::Float64),
+            "UNSIGNED_VARINT" => Ok(Primitive::UnsignedVarint),
+            "COMPACT_STRING" => Ok(Primitive::CompactString),
+            "COMPACT_NULLABLE_STRING" => Ok(Primitive::CompactNullableString),
+            "COMPACT_BYTES" => Ok(Primitive::CompactBytes),
+            "COMPACT_NULLABLE_BYTES" => Ok(Primitive::CompactNullableBytes),
+            "COMPACT_RECORDS" => Ok(Primitive::CompactRecords),
+            "TAG_BUFFER" => Ok(Primitive::TagBuffer),
+            _ => Err(ParserError::UnknownPrimitive(raw.to_string())),
         }
     }
+}
 
-    fn is_valid(raw: &str) -> bool {
+impl Primitive {
+    /// Returns the name of the primitive in protocol specs.
+    pub fn spec_name(&self) -> &'static str {
+        match *self {
+            Primitive::Boolean => "BOOLEAN",
+            Primitive::Int8 => "INT8",
+            Primitive::Int16 => "INT16",
+            Primitive::Int32 => "INT32",
+            Primitive::Int64 => "INT64",
+            Primitive::Uint32 => "UINT32",
+            Primitive::Varint => "VARINT",
+            Primitive::Varlong => "VARLONG",
+            Primitive::String => "STRING",
+            Primitive::NullableString => "NULLABLE_STRING",
+            Primitive::Bytes => "BYTES",
+            Primitive::NullableBytes => "NULLABLE_BYTES",
+            Primitive::Records => "RECORDS",
+            Primitive::Uuid => "UUID",
+            Primitive::Float64 => "FLOAT64",
+            Primitive::UnsignedVarint => "UNSIGNED_VARINT",
+            Primitive::CompactString => "COMPACT_STRING",
+            Primitive::CompactNullableString => "COMPACT_NULLABLE_STRING",
+            Primitive::CompactBytes => "COMPACT_BYTES",
+            Primitive::CompactNullableBytes => "COMPACT_NULLABLE_BYTES",
+            Primitive::CompactRecords => "COMPACT_RECORDS",
+            Primitive::TagBuffer => "TAG_BUFFER",
+        }
+    }
+
+    /// Tells whether a type in specs names a primitive, known or not, rather
+    /// than fields: primitives are upper case, e.g. `INT32`, while fields are
+    /// snake case.
+    fn is_name(raw: &str) -> bool {
         lazy_static! {
-            static ref VALIDS: HashSet<String> = {
-                let s: HashSet<_> = vec![
-                    "BOOLEAN",
-                    "INT8",
-                    "INT16",
-                    "INT32",
-                    "INT64",
-                    "UINT32",
-                    "VARINT",
-                    "VARLONG",
-                    "STRING",
-                    "NULLABLE_STRING",
-                    "BYTES",
-                    "NULLABLE_BYTES",
-                    "RECORDS",
-                ]
-                .iter()
-                .map(|s| s.to_string())
-                .collect();
-                s
-            };
+            static ref NAME: Regex = Regex::new(r"^[A-Z][A-Z0-9_]*$").expect("Invalid regex");
         }
-        VALIDS.contains(raw)
+        NAME.is_match(raw)
     }
 
     fn rust_type(&self) -> String {
@@ -688,6 +1169,25 @@ impl Primitive {
             Primitive::Bytes => "crate::types::Bytes".to_string(),
             Primitive::NullableBytes => "crate::types::NullableBytes".to_string(),
             Primitive::Records => "crate::types::NullableBytes".to_string(),
+            Primitive::Uuid => "crate::types::Uuid".to_string(),
+            Primitive::Float64 => "f64".to_string(),
+            Primitive::UnsignedVarint => "crate::types::UnsignedVarint".to_string(),
+            Primitive::CompactString => "crate::types::CompactString".to_string(),
+            Primitive::CompactNullableString => "crate::types::CompactNullableString".to_string(),
+            Primitive::CompactBytes => "crate::types::CompactBytes".to_string(),
+            Primitive::CompactNullableBytes => "crate::types::CompactNullableBytes".to_string(),
+            Primitive::CompactRecords => "crate::types::CompactNullableBytes".to_string(),
+            Primitive::TagBuffer => "crate::types::TaggedFields".to_string(),
+        }
+    }
+
+    /// Returns the Rust type of a field of this primitive: api keys, `INT16`
+    /// fields named `api_key`, are `AnyApiKey`s, not to fail on the ones
+    /// unknown to the crate.
+    fn field_rust_type(&self, field_name: &str) -> String {
+        match *self {
+            Primitive::Int16 if field_name == "api_key" => "crate::types::AnyApiKey".to_string(),
+            _ => self.rust_type(),
         }
     }
 }
@@ -696,31 +1196,33 @@ impl Primitive {
 mod tests {
     use super::*;
 
+    /// Reads the vendored Kafka 2.3 spec, generated by default.
+    fn vendored_spec() -> String {
+        crate::generator::read_vendored_spec(crate::generator::SPECS_DIR, "2.3").unwrap()
+    }
+
     #[test]
-    #[ignore]
     fn parse_error_codes() {
-        let raw = include_str!("protocol.html");
-        let parser = SpecParser::new(raw).unwrap();
+        let raw = vendored_spec();
+        let parser = SpecParser::new(&raw).unwrap();
         for row in parser.err_code_rows {
             println!("{:?}", row);
         }
     }
 
     #[test]
-    #[ignore]
     fn parse_api_keys() {
-        let raw = include_str!("protocol.html");
-        let parser = SpecParser::new(raw).unwrap();
+        let raw = vendored_spec();
+        let parser = SpecParser::new(&raw).unwrap();
         for row in parser.api_key_rows {
             println!("{:?}", row);
         }
     }
 
     #[test]
-    #[ignore]
     fn parse_req_resp() {
-        let raw = include_str!("protocol.html");
-        let parser = SpecParser::new(raw).unwrap();
+        let raw = vendored_spec();
+        let parser = SpecParser::new(&raw).unwrap();
         println!("{:?}", parser.req_resp_specs.get_index(0));
         println!(
             "{:?}",
@@ -731,10 +1233,9 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn parse_enum_vfields() {
-        let raw = include_str!("protocol.html");
-        let parser = SpecParser::new(raw).unwrap();
+        let raw = vendored_spec();
+        let parser = SpecParser::new(&raw).unwrap();
         let mut it = parser.iter_req_resp();
         let req_resp = it.next().unwrap();
         let vfields = req_resp.enum_vfields();
@@ -744,10 +1245,9 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn parse_mod_vstructs() {
-        let raw = include_str!("protocol.html");
-        let parser = SpecParser::new(raw).unwrap();
+        let raw = vendored_spec();
+        let parser = SpecParser::new(&raw).unwrap();
         let mut it = parser.iter_req_resp();
         let req_resp = it.next().unwrap();
         let vstructs = req_resp.mod_vstructs();
@@ -806,4 +1306,207 @@ mod tests {
             spec
         );
     }
+
+    #[test]
+    fn parse_new_primitives() {
+        use super::Spec::*;
+
+        let raw = "Foo Response (Version: 0) => topic_id ratio leader_ids name tag
+  topic_id => UUID
+  ratio => FLOAT64
+  leader_ids => COMPACT_ARRAY(INT32)
+  name => COMPACT_NULLABLE_STRING
+  tag => UNSIGNED_VARINT";
+
+        let (_, _, spec) = parse_struct_spec(raw).unwrap();
+        assert_eq!(
+            Struct(vec![
+                ("topic_id".into(), Value(Primitive::Uuid)),
+                ("ratio".into(), Value(Primitive::Float64)),
+                (
+                    "leader_ids".into(),
+                    CompactArray(Box::new(Value(Primitive::Int32)))
+                ),
+                ("name".into(), Value(Primitive::CompactNullableString)),
+                ("tag".into(), Value(Primitive::UnsignedVarint)),
+            ]),
+            spec
+        );
+        assert_eq!(
+            "crate::types::CompactArray<i32>",
+            (&"FooResponse".to_string(), &vec![(0, spec, HashMap::new())]).enum_vfields()[0][2].1
+        );
+
+        let raw = "Foo Response (Version: 0) => value
+  value => FLOAT128";
+        match parse_struct_spec(raw) {
+            Err(ParserError::UnknownPrimitive(name)) => assert_eq!("FLOAT128", name),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_headers() {
+        use super::Spec::*;
+
+        let raw = "Request Header v2 => request_api_key correlation_id TAG_BUFFER 
+  request_api_key => INT16
+  correlation_id => INT32";
+        let (name, version, spec) = parse_struct_spec(raw).unwrap();
+        assert_eq!(("RequestHeader", 2), (name.as_str(), version));
+        assert_eq!(
+            Struct(vec![
+                ("request_api_key".into(), Value(Primitive::Int16)),
+                ("correlation_id".into(), Value(Primitive::Int32)),
+                ("tagged_fields".into(), Value(Primitive::TagBuffer)),
+            ]),
+            spec
+        );
+        assert!(spec.is_flexible());
+
+        // Unversioned before Kafka 2.4
+        let raw = "Response Header => correlation_id 
+  correlation_id => INT32";
+        let (name, version, spec) = parse_struct_spec(raw).unwrap();
+        assert_eq!(("ResponseHeader", 0), (name.as_str(), version));
+        assert!(!spec.is_flexible());
+    }
+
+    #[test]
+    fn parse_deprecated_version() {
+        let raw = "Foo Request (Version: 0) (Deprecated) => id
+  id => INT32";
+        let (name, version, _) = parse_struct_spec(raw).unwrap();
+        assert_eq!(("FooRequest", 0), (name.as_str(), version));
+        assert!(is_deprecated(raw));
+
+        assert!(!is_deprecated("Foo Request (Version: 1) => id\n  id => INT32"));
+        assert!(!is_deprecated("Foo Request (Version: 1) => deprecated\n  deprecated => INT32"));
+    }
+
+    #[test]
+    fn header_versions() {
+        let spec = |raw| parse_struct_spec(raw).unwrap().2;
+        let versions = |specs: Vec<Spec<'static>>| {
+            specs
+                .into_iter()
+                .enumerate()
+                .map(|(v, spec)| (v as i16, spec, HashMap::new()))
+                .collect::<VersionedSpecs>()
+        };
+        let fixed = spec("Foo Request (Version: 0) => id\n  id => INT32");
+        let flexible = spec("Foo Request (Version: 0) => id TAG_BUFFER\n  id => INT32");
+
+        let mut header_specs = IndexMap::new();
+        header_specs.insert(
+            "RequestHeader".to_string(),
+            versions(vec![fixed.clone(), fixed.clone(), flexible.clone()]),
+        );
+        header_specs.insert(
+            "ResponseHeader".to_string(),
+            versions(vec![fixed.clone(), flexible.clone()]),
+        );
+        let mut req_resp_specs = IndexMap::new();
+        for name in &["Foo", "ControlledShutdown", "ApiVersions"] {
+            for genre in &["Request", "Response"] {
+                req_resp_specs.insert(
+                    format!("{}{}", name, genre),
+                    versions(vec![fixed.clone(), fixed.clone(), flexible.clone()]),
+                );
+            }
+        }
+        let mut parser = SpecParser {
+            err_code_specs: vec![],
+            err_code_rows: vec![],
+            api_key_rows: vec![
+                ("Foo".to_string(), "0".to_string()),
+                ("ControlledShutdown".to_string(), "7".to_string()),
+                ("ApiVersions".to_string(), "18".to_string()),
+                ("Bar".to_string(), "19".to_string()),
+            ],
+            header_specs,
+            req_resp_specs,
+            deprecated_versions: HashMap::new(),
+        };
+
+        assert_eq!(
+            vec![
+                ("Foo".to_string(), vec![(2, 2)], 1),
+                ("ControlledShutdown".to_string(), vec![(2, 2), (1, 1)], 0),
+                ("ApiVersions".to_string(), vec![(2, 2)], 1),
+                ("Bar".to_string(), vec![], 1),
+            ],
+            parser.header_version_rows(false)
+        );
+        assert_eq!(
+            vec![
+                ("Foo".to_string(), vec![(2, 1)], 0),
+                ("ControlledShutdown".to_string(), vec![(2, 1)], 0),
+                ("ApiVersions".to_string(), vec![], 0),
+                ("Bar".to_string(), vec![], 0),
+            ],
+            parser.header_version_rows(true)
+        );
+
+        // A single unversioned header is used by all versions
+        parser.header_specs["RequestHeader"].truncate(1);
+        assert!(parser
+            .header_version_rows(false)
+            .iter()
+            .all(|(_, ranges, default)| ranges.is_empty() && *default == 0));
+    }
+
+    #[test]
+    fn struct_name_collisions() {
+        let raw = "Foo Request (Version: 0) => [topics] [groups]
+  topics => name [partitions]
+    name => STRING
+    partitions => index
+      index => INT32
+  groups => name [partitions]
+    name => STRING
+    partitions => index [offsets]
+      index => INT32
+      offsets => offset
+        offset => INT64";
+
+        let (name, version, spec) = parse_struct_spec(raw).unwrap();
+        let names = spec.struct_names().unwrap();
+        assert_eq!("TopicsPartitions", names["topics.partitions"]);
+        assert_eq!("GroupsPartitions", names["groups.partitions"]);
+        assert_eq!("Offsets", names["groups.partitions.offsets"]);
+
+        let versions = vec![(version, spec, HashMap::new())];
+        let vstructs = (&name, &versions).mod_vstructs();
+        let struct_names = vstructs[0]
+            .iter()
+            .map(|(name, _, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "Topics",
+                "Groups",
+                "TopicsPartitions",
+                "GroupsPartitions",
+                "Offsets"
+            ],
+            struct_names
+        );
+        assert_eq!("Vec<GroupsPartitions>", vstructs[0][1].1[1].1);
+
+        let raw = "Foo Request (Version: 0) => [partitions] [topics] [topics_partitions]
+  partitions => index
+    index => INT32
+  topics => [partitions]
+    partitions => offset
+      offset => INT64
+  topics_partitions => name
+    name => STRING";
+
+        let (_, _, spec) = parse_struct_spec(raw).unwrap();
+        assert!(matches!(
+            spec.struct_names(),
+            Err(ParserError::NameCollision(_))
+        ));
+    }
 }
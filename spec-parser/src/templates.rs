@@ -1,5 +1,12 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use lazy_static::*;
+use regex::Regex;
 use tera::{Context, Error, Tera};
 
+use crate::config::{Config, TypeConfig};
+
 /// Types used by templatings methods
 pub mod motif {
     /// Vector of (name, code_id, doc)
@@ -8,31 +15,31 @@ pub mod motif {
     /// Vector of (name, key_id)
     pub type ApiKeyRows = Vec<(String, String)>;
 
+    /// Vector of (name, ranges, default_version) of the header versions of
+    /// api keys. Ranges are (first_version, header_version) by decreasing
+    /// version, the default version being used below all ranges.
+    pub type HeaderVersionRows = Vec<(String, Vec<(i16, i16)>, i16)>;
+
     /// Vector of (name, rust_type, doc)
     pub type Fields = Vec<(String, String, String)>;
 
     /// A req/resp enum's versioned fields. Each element is a version.
     pub type EnumVfields = Vec<Fields>;
 
-    /// A req/resp module's versioned structs. Each element is a version.
-    /// Each version is a vector of (struct_name, struct_fields)
-    pub type ModVstructs = Vec<Vec<(String, Fields)>>;
-}
+    /// A req/resp's versions, and a vector of (field_path, first_version,
+    /// last_version) with an element per contiguous range of versions a
+    /// field exists in.
+    pub type VersionRanges = (Vec<i16>, Vec<(String, i16, i16)>);
 
-const HEADERS: &str = r#"
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-pub struct HeaderRequest {
-    pub api_key: crate::model::ApiKey,
-    pub api_version: i16,
-    pub correlation_id: i32,
-    pub client_id: crate::types::NullableString,
-}
+    /// A req/resp module's versioned structs. Each element is a version.
+    /// Each version is a vector of (struct_name, struct_fields, shared_from),
+    /// where shared_from is the module of a previous version defining an
+    /// identical struct, re-exported instead of being defined again.
+    pub type ModVstructs = Vec<Vec<(String, Fields, Option<String>)>>;
 
-#[derive(Debug, serde::Deserialize)]
-pub struct HeaderResponse {
-    pub correlation: i32,
+    /// A req/resp enum's versioned fields, with the version of each variant.
+    pub type EnumCodec<'a> = Vec<(i16, &'a Fields)>;
 }
-"#;
 
 const ERROR_CODES_TERA: &str = "error_codes.tera";
 const ERROR_CODES_TEMPLATE: &str = r#"
@@ -48,7 +55,13 @@ const ERROR_CODES_TEMPLATE: &str = r#"
     serde_repr::Deserialize_repr,
     num_enum::IntoPrimitive,
     num_enum::TryFromPrimitive,
+{%- for d in derives %}
+    {{ d }},
+{%- endfor %}
 )]
+{%- for a in attributes %}
+{{ a }}
+{%- endfor %}
 #[repr(i16)]
 pub enum ErrorCode {
     {%- for e in err_codes %}
@@ -73,7 +86,13 @@ const API_KEYS_TEMPLATE: &str = r#"
     num_enum::IntoPrimitive,
     num_enum::TryFromPrimitive,
     strum_macros::EnumIter,
+{%- for d in derives %}
+    {{ d }},
+{%- endfor %}
 )]
+{%- for a in attributes %}
+{{ a }}
+{%- endfor %}
 #[repr(i16)]
 pub enum ApiKey {
     {%- for k in api_keys %}
@@ -82,9 +101,133 @@ pub enum ApiKey {
 }
 "#;
 
+const HEADER_VERSIONS_TERA: &str = "header_versions.tera";
+const HEADER_VERSIONS_TEMPLATE: &str = r#"
+impl ApiKey {
+    /// Returns the version of the header requests of `api_version` are sent with.
+    pub fn request_header_version(self, {% if request_fixed %}_{% endif %}api_version: i16) -> i16 {
+        match self {
+            {%- for k in request %}
+            {%- for r in k.1 %}
+            ApiKey::{{ k.0 }} if api_version >= {{ r.0 }} => {{ r.1 }},
+            {%- endfor %}
+            ApiKey::{{ k.0 }} => {{ k.2 }},
+            {%- endfor %}
+        }
+    }
+
+    /// Returns the version of the header responses to requests of `api_version`
+    /// are sent with.
+    pub fn response_header_version(self, {% if response_fixed %}_{% endif %}api_version: i16) -> i16 {
+        match self {
+            {%- for k in response %}
+            {%- for r in k.1 %}
+            ApiKey::{{ k.0 }} if api_version >= {{ r.0 }} => {{ r.1 }},
+            {%- endfor %}
+            ApiKey::{{ k.0 }} => {{ k.2 }},
+            {%- endfor %}
+        }
+    }
+}
+"#;
+
+const HEADER_TERA: &str = "header.tera";
+const HEADER_TEMPLATE: &str = r#"
+impl {{ name }} {
+    /// Returns the header to send {% if request %}a request{% else %}the response to a request{% endif %} of `api_key` and
+    /// `api_version` with, of the version `ApiKey::{{ genre }}_header_version`
+    /// gives.
+    pub fn new(
+        api_key: ApiKey,
+        api_version: i16,
+        correlation_id: i32,
+        {%- if request %}
+        client_id: {{ crate_path }}::types::NullableString,
+        {%- endif %}
+    ) -> {{ name }} {
+        {%- set single = versions | length == 1 %}
+        {%- if single %}
+        debug_assert_eq!(api_key.{{ genre }}_header_version(api_version), {{ versions.0.0 }});
+        {%- else %}
+        match api_key.{{ genre }}_header_version(api_version) {
+        {%- endif %}
+            {%- for v in versions %}
+            {% if not single %}{% if loop.last %}_{% else %}{{ v.0 }}{% endif %} => {% endif %}{{ name }}::V{{ loop.index0 }} {
+                {%- for f in v.1 %}
+                {{ f.0 }}
+                {%- if f.0 == "api_key" %}: api_key.into()
+                {%- elif f.0 == "client_id" and request %}
+                {%- elif f.0 == "api_version" or f.0 == "correlation_id" %}
+                {%- else %}: Default::default()
+                {%- endif %},
+                {%- endfor %}
+            }{% if not single %},{% endif %}
+            {%- endfor %}
+        {%- if not single %}
+        }
+        {%- endif %}
+    }
+    {%- for a in accessors %}
+
+{{ a.2 }}
+    pub fn {{ a.0 }}(&self) -> {{ a.1 }} {
+        match self {
+            {%- for v in versions %}
+            {{ name }}::V{{ loop.index0 }} { {{ a.0 }}, .. } => *{{ a.0 }},
+            {%- endfor %}
+        }
+    }
+    {%- endfor %}
+}
+"#;
+
+const RESPONSES_TERA: &str = "responses.tera";
+const RESPONSES_TEMPLATE: &str = r#"
+/// A response of any type, as identified by the `ApiKey` of its request.
+#[derive(Debug, Clone, PartialEq, serde::Serialize{% for d in derives %}, {{ d }}{% endfor %})]
+{%- for a in attributes %}
+{{ a }}
+{%- endfor %}
+pub enum Response {
+    {%- for k in api_keys %}
+    {{ k.0 }}({{ k.0 }}Response),
+    {%- endfor %}
+}
+
+impl Response {
+    /// Deserializes the response to a request of type `api_key`.
+    pub fn deserialize_for<'de, D>(api_key: ApiKey, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+
+        match api_key {
+            {%- for k in api_keys %}
+            ApiKey::{{ k.0 }} => {{ k.0 }}Response::deserialize(deserializer).map(Response::{{ k.0 }}),
+            {%- endfor %}
+            {%- if partial %}
+            _ => Err(serde::de::Error::custom(format!("Unsupported api key: {:?}", api_key))),
+            {%- endif %}
+        }
+    }
+
+    pub fn api_key(&self) -> ApiKey {
+        match self {
+            {%- for k in api_keys %}
+            Response::{{ k.0 }}(_) => ApiKey::{{ k.0 }},
+            {%- endfor %}
+        }
+    }
+}
+"#;
+
 const REQ_RESP_ENUM_TERA: &str = "req_resp_enum.tera";
 const REQ_RESP_ENUM_TEMPLATE: &str = r#"
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum_macros::EnumCount{% for d in derives %}, {{ d }}{% endfor %})]
+{%- for a in attributes %}
+{{ a }}
+{%- endfor %}
 pub enum {{ name }} {
     {%- for fields in versions %}
     V{{ loop.index0 }} {
@@ -97,29 +240,163 @@ pub enum {{ name }} {
 }
 "#;
 
+const REQ_RESP_VERSIONS_TERA: &str = "req_resp_versions.tera";
+const REQ_RESP_VERSIONS_TEMPLATE: &str = r#"
+impl {{ crate_path }}::versions::ApiVersions for {{ name }} {
+    const NAME: &'static str = "{{ name }}";
+    const FIRST_VERSION: i16 = {{ versions | first }};
+    const LAST_VERSION: i16 = {{ versions | last }};
+    const DEPRECATED_VERSIONS: &'static [i16] = &[
+        {%- for v in deprecated %}{{ v }}{% if not loop.last %}, {% endif %}{% endfor -%}
+    ];
+    const FIELD_VERSIONS: &'static [(&'static str, std::ops::RangeInclusive<i16>)] = &[
+        {%- for f in fields %}
+        ("{{ f.0 }}", {{ f.1 }}..={{ f.2 }}),
+        {%- endfor %}
+    ];
+
+    fn version(&self) -> i16 {
+        match self {
+            {%- for v in versions %}
+            {{ name }}::V{{ loop.index0 }} { .. } => {{ v }},
+            {%- endfor %}
+        }
+    }
+}
+"#;
+
+const REQUEST_TERA: &str = "request.tera";
+const REQUEST_TEMPLATE: &str = r#"
+impl {{ crate_path }}::request::Request for {{ name }}Request {
+    const API_KEY: ApiKey = ApiKey::{{ name }};
+    type Response = {{ name }}Response;
+}
+"#;
+
+const API_KEY_VERSIONS_TERA: &str = "api_key_versions.tera";
+const API_KEY_VERSIONS_TEMPLATE: &str = r#"
+impl {{ name }} {
+    /// Returns the (api_key, min_version, max_version) of the APIs supported
+    /// by the broker. Api keys unknown to this crate, e.g. of APIs added in a
+    /// more recent broker, are kept as `AnyApiKey::Unknown`.
+    pub fn api_key_versions(&self) -> Vec<({{ crate_path }}::types::AnyApiKey, i16, i16)> {
+        match self {
+            {%- for f in fields %}
+            {{ name }}::V{{ loop.index0 }} { {{ f }}, .. } => {{ f }}
+                .iter()
+                .map(|v| (v.api_key, v.min_version, v.max_version))
+                .collect(),
+            {%- endfor %}
+        }
+    }
+}
+"#;
+
+const REQ_RESP_CODEC_TERA: &str = "req_resp_codec.tera";
+const REQ_RESP_CODEC_TEMPLATE: &str = r#"
+impl {{ crate_path }}::codec::Encode for {{ name }} {
+    fn encode(&self, {% if empty %}_{% endif %}buf: &mut Vec<u8>) {
+        match self {
+            {%- for v in versions %}
+            {{ name }}::V{{ loop.index0 }} { {% for f in v.1 %}{{ f.0 }}, {% endfor %}} => {
+                {%- for f in v.1 %}
+                {{ crate_path }}::codec::Encode::encode({{ f.0 }}, buf);
+                {%- endfor %}
+            }
+            {%- endfor %}
+        }
+    }
+}
+
+impl {{ crate_path }}::codec::Decode for {{ name }} {
+    fn decode({% if empty %}_{% endif %}buf: &mut &[u8], version: i16) -> {{ crate_path }}::codec::Result<Self> {
+        match version {
+            {%- for v in versions %}
+            {{ v.0 }} => Ok({{ name }}::V{{ loop.index0 }} {
+                {%- for f in v.1 %}
+                {{ f.0 }}: {{ crate_path }}::codec::Decode::decode(buf, version)?,
+                {%- endfor %}
+            }),
+            {%- endfor %}
+            _ => Err(serde::de::Error::custom(format!("{} has no version {}", "{{ name }}", version))),
+        }
+    }
+}
+"#;
+
+const REQ_RESP_MOD_CODEC_TERA: &str = "req_resp_mod_codec.tera";
+const REQ_RESP_MOD_CODEC_TEMPLATE: &str = r#"
+{%- for ver in versions %}{% set module = "v" ~ loop.index0 %}
+{%- for struct in ver %}{% if not struct.2 %}
+impl {{ crate_path }}::codec::Encode for {{ name }}::{{ module }}::{{ struct.0 }} {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        {%- for f in struct.1 %}
+        {{ crate_path }}::codec::Encode::encode(&self.{{ f.0 }}, buf);
+        {%- endfor %}
+    }
+}
+
+impl {{ crate_path }}::codec::Decode for {{ name }}::{{ module }}::{{ struct.0 }} {
+    fn decode(buf: &mut &[u8], version: i16) -> {{ crate_path }}::codec::Result<Self> {
+        Ok({{ name }}::{{ module }}::{{ struct.0 }} {
+            {%- for f in struct.1 %}
+            {{ f.0 }}: {{ crate_path }}::codec::Decode::decode(buf, version)?,
+            {%- endfor %}
+        })
+    }
+}
+{% endif %}{% endfor %}
+{%- endfor %}
+"#;
+
 pub const REQ_RESP_MOD_TERA: &str = "req_resp_mod.tera";
 pub const REQ_RESP_MOD_TEMPLATE: &str = r#"
 pub mod {{ name }} {
-    {%- for ver in versions %}
-    pub mod v{{ loop.index0 }} {
-        {%- for struct in ver %}
-        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    {%- for ver in versions %}{% set module = "v" ~ loop.index0 %}
+    pub mod {{ module }} {
+        {%- for struct in ver %}{% if struct.2 %}
+        pub use super::{{ struct.2 }}::{{ struct.0 }};
+        {%- endif %}{% endfor %}
+        {%- for struct in ver %}{% if not struct.2 %}{% set key = module ~ "::" ~ struct.0 %}
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize{% for d in structs_config[key].derives %}, {{ d }}{% endfor %})]
+        {%- for a in structs_config[key].attributes %}
+        {{ a }}
+        {%- endfor %}
         pub struct {{ struct.0 }} {
             {%- for f in struct.1 %}
 {{ f.2 }}
             pub {{ f.0 }}: {{ f.1 }},
             {%- endfor %}
         }
-        {%- endfor %}
+        {%- endif %}{% endfor %}
     }
     {%- endfor %}
 }
 "#;
 
+/// Names of the templates, which can be overridden by files of the same name.
+pub const TEMPLATE_NAMES: &[&str] = &[
+    ERROR_CODES_TERA,
+    API_KEYS_TERA,
+    HEADER_VERSIONS_TERA,
+    HEADER_TERA,
+    RESPONSES_TERA,
+    REQ_RESP_ENUM_TERA,
+    REQ_RESP_VERSIONS_TERA,
+    REQUEST_TERA,
+    API_KEY_VERSIONS_TERA,
+    REQ_RESP_MOD_TERA,
+    REQ_RESP_CODEC_TERA,
+    REQ_RESP_MOD_CODEC_TERA,
+];
+
 /// The main, stateless, component for templating. Current implementation uses Tera.
 /// It generates String of Rust code/types corresponding to Kafka protocol.
 pub struct Templater {
     tera: Tera,
+    crate_path: String,
+    derives: Vec<String>,
+    config: Config,
 }
 
 impl Templater {
@@ -128,53 +405,334 @@ impl Templater {
         let mut tera = Tera::new("/dev/null/*")?;
         tera.add_raw_template(ERROR_CODES_TERA, ERROR_CODES_TEMPLATE)?;
         tera.add_raw_template(API_KEYS_TERA, API_KEYS_TEMPLATE)?;
+        tera.add_raw_template(HEADER_VERSIONS_TERA, HEADER_VERSIONS_TEMPLATE)?;
+        tera.add_raw_template(HEADER_TERA, HEADER_TEMPLATE)?;
+        tera.add_raw_template(RESPONSES_TERA, RESPONSES_TEMPLATE)?;
         tera.add_raw_template(REQ_RESP_ENUM_TERA, REQ_RESP_ENUM_TEMPLATE)?;
+        tera.add_raw_template(REQ_RESP_VERSIONS_TERA, REQ_RESP_VERSIONS_TEMPLATE)?;
+        tera.add_raw_template(REQUEST_TERA, REQUEST_TEMPLATE)?;
+        tera.add_raw_template(API_KEY_VERSIONS_TERA, API_KEY_VERSIONS_TEMPLATE)?;
         tera.add_raw_template(REQ_RESP_MOD_TERA, REQ_RESP_MOD_TEMPLATE)?;
-        Ok(Templater { tera })
+        tera.add_raw_template(REQ_RESP_CODEC_TERA, REQ_RESP_CODEC_TEMPLATE)?;
+        tera.add_raw_template(REQ_RESP_MOD_CODEC_TERA, REQ_RESP_MOD_CODEC_TEMPLATE)?;
+        Ok(Templater {
+            tera,
+            crate_path: "crate".to_string(),
+            derives: vec![],
+            config: Config::default(),
+        })
+    }
+
+    /// Creates a new `Templater`, with templates overridden by the `.tera`
+    /// files of `dir` named after them, e.g. `req_resp_enum.tera`.
+    pub fn with_templates_dir<P: AsRef<Path>>(dir: P) -> Result<Templater, Error> {
+        let mut templater = Templater::new()?;
+
+        let entries = std::fs::read_dir(dir.as_ref())
+            .map_err(|e| Error::chain(format!("Can't read templates dir {:?}", dir.as_ref()), e))?;
+        for entry in entries {
+            let path = entry
+                .map_err(|e| Error::chain("Can't read templates dir entry", e))?
+                .path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("tera") {
+                continue;
+            }
+
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            match TEMPLATE_NAMES.iter().find(|name| **name == file_name) {
+                Some(name) => templater.tera.add_template_file(&path, Some(name))?,
+                None => {
+                    return Err(Error::msg(format!(
+                        "Unknown template {:?}, expected one of: {}",
+                        path,
+                        TEMPLATE_NAMES.join(", ")
+                    )))
+                }
+            }
+        }
+
+        Ok(templater)
+    }
+
+    /// Sets the path of the crate providing runtime types, `crate` by
+    /// default. Templates refer to it as `crate_path`.
+    pub fn set_crate_path(&mut self, crate_path: &str) {
+        self.crate_path = crate_path.to_string();
+    }
+
+    /// Sets extra derives of req/resp enums and of their inner structs.
+    pub fn set_derives(&mut self, derives: &[String]) {
+        self.derives = derives.to_vec();
     }
 
-    /// Generates structs for request and response headers
-    pub fn str_headers(&self) -> &'static str {
-        HEADERS
+    /// Sets extra derives and attributes per type.
+    pub fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
+    /// Returns extra derives and attributes of a type, `req_resp` for
+    /// req/resp enums and their inner structs.
+    fn type_config(&self, type_name: &str, req_resp: bool) -> TypeConfig {
+        let mut type_config = self.config.type_config(type_name, req_resp);
+        if req_resp {
+            type_config
+                .derives
+                .splice(0..0, self.derives.iter().cloned());
+        }
+        type_config
+    }
+
+    /// Returns a templating context holding the variables of all templates.
+    fn context(&self) -> Context {
+        let mut ctx = Context::new();
+        ctx.insert("crate_path", &self.crate_path);
+        ctx
+    }
+
+    /// Returns fields with their runtime types, `crate::types::...` in
+    /// motifs, under the crate path.
+    fn crate_fields(&self, fields: &motif::Fields) -> motif::Fields {
+        lazy_static! {
+            static ref CRATE: Regex = Regex::new(r"\bcrate::").expect("Invalid regex");
+        }
+        let crate_path = format!("{}::", self.crate_path);
+        fields
+            .iter()
+            .map(|(name, rust_type, doc)| {
+                let rust_type = CRATE.replace_all(rust_type, crate_path.as_str());
+                (name.clone(), rust_type.into_owned(), doc.clone())
+            })
+            .collect()
+    }
+
+    /// Inserts extra derives and attributes of a type into a templating context.
+    fn insert_type_config(&self, ctx: &mut Context, type_name: &str, req_resp: bool) {
+        let type_config = self.type_config(type_name, req_resp);
+        ctx.insert("derives", &type_config.derives);
+        ctx.insert("attributes", &type_config.attributes);
     }
 
     /// Generates an enum with all Kafka error codes.
     pub fn str_err_codes(&self, err_codes: &motif::ErrorCodeRows) -> Result<String, Error> {
-        let mut ctx = Context::new();
+        let mut ctx = self.context();
         ctx.insert("err_codes", err_codes);
+        self.insert_type_config(&mut ctx, "ErrorCode", false);
         Ok(self.tera.render(ERROR_CODES_TERA, &ctx)?)
     }
 
     /// Generates an enum with all Kafka api keys.
     pub fn str_api_keys(&self, api_keys: &motif::ApiKeyRows) -> Result<String, Error> {
-        let mut ctx = Context::new();
+        let mut ctx = self.context();
         ctx.insert("api_keys", api_keys);
+        self.insert_type_config(&mut ctx, "ApiKey", false);
         Ok(self.tera.render(API_KEYS_TERA, &ctx)?)
     }
 
+    /// Generates the mapping of api keys and versions to the versions of the
+    /// request and response headers.
+    pub fn str_header_versions(
+        &self,
+        request: &motif::HeaderVersionRows,
+        response: &motif::HeaderVersionRows,
+    ) -> Result<String, Error> {
+        let fixed =
+            |rows: &motif::HeaderVersionRows| rows.iter().all(|(_, ranges, _)| ranges.is_empty());
+
+        let mut ctx = self.context();
+        ctx.insert("request", request);
+        ctx.insert("request_fixed", &fixed(request));
+        ctx.insert("response", response);
+        ctx.insert("response_fixed", &fixed(response));
+        Ok(self.tera.render(HEADER_VERSIONS_TERA, &ctx)?)
+    }
+
+    /// Generates the constructor of the request or response header enum
+    /// `name`, picking its version by api key and version, and accessors
+    /// to the fields of a `Copy` type all its versions have.
+    pub fn str_header(&self, name: &str, versions: &motif::EnumCodec) -> Result<String, Error> {
+        let versions: Vec<(i16, motif::Fields)> = versions
+            .iter()
+            .map(|(version, fields)| (*version, self.crate_fields(fields)))
+            .collect();
+        let accessors: motif::Fields = match versions.first() {
+            Some((_, fields)) => fields
+                .iter()
+                .filter(|(field, rust_type, _)| {
+                    is_copy(rust_type)
+                        && versions
+                            .iter()
+                            .all(|(_, fields)| fields.iter().any(|(f, _, _)| f == field))
+                })
+                .cloned()
+                .collect(),
+            None => vec![],
+        };
+        let request = name.starts_with("Request");
+
+        let mut ctx = self.context();
+        ctx.insert("name", name);
+        ctx.insert("genre", if request { "request" } else { "response" });
+        ctx.insert("request", &request);
+        ctx.insert("versions", &versions);
+        ctx.insert("accessors", &accessors);
+        Ok(self.tera.render(HEADER_TERA, &ctx)?)
+    }
+
+    /// Generates an enum wrapping the responses of Kafka api keys, `partial`
+    /// when some api keys are left out.
+    pub fn str_responses(
+        &self,
+        api_keys: &motif::ApiKeyRows,
+        partial: bool,
+    ) -> Result<String, Error> {
+        let mut ctx = self.context();
+        ctx.insert("api_keys", api_keys);
+        ctx.insert("partial", &partial);
+        self.insert_type_config(&mut ctx, "Response", false);
+        Ok(self.tera.render(RESPONSES_TERA, &ctx)?)
+    }
+
     /// Generates a versioned enum for a given request/response of the Kafka protocol
     pub fn str_req_resp_enum(
         &self,
         enum_name: &str,
         versions: &motif::EnumVfields,
     ) -> Result<String, Error> {
-        let mut ctx = Context::new();
+        let versions = versions
+            .iter()
+            .map(|fields| self.crate_fields(fields))
+            .collect::<motif::EnumVfields>();
+
+        let mut ctx = self.context();
         ctx.insert("name", enum_name);
-        ctx.insert("versions", versions);
+        ctx.insert("versions", &versions);
+        self.insert_type_config(&mut ctx, enum_name, true);
         Ok(self.tera.render(REQ_RESP_ENUM_TERA, &ctx)?)
     }
 
+    /// Generates the versions metadata of a given request/response, and of its
+    /// fields, along with its `deprecated` versions.
+    pub fn str_req_resp_versions(
+        &self,
+        enum_name: &str,
+        versions: &motif::VersionRanges,
+        deprecated: &[i16],
+    ) -> Result<String, Error> {
+        let mut ctx = self.context();
+        ctx.insert("name", enum_name);
+        ctx.insert("versions", &versions.0);
+        ctx.insert("deprecated", deprecated);
+        ctx.insert("fields", &versions.1);
+        Ok(self.tera.render(REQ_RESP_VERSIONS_TERA, &ctx)?)
+    }
+
+    /// Generates the link of a request to its api key and response type
+    pub fn str_request(&self, api_name: &str) -> Result<String, Error> {
+        let mut ctx = self.context();
+        ctx.insert("name", api_name);
+        Ok(self.tera.render(REQUEST_TERA, &ctx)?)
+    }
+
     /// Generates versioned modules for the inner structs of versioned req_resp enums
     pub fn str_req_resp_mod(
         &self,
         module_name: &str,
         versions: &motif::ModVstructs,
     ) -> Result<String, Error> {
-        let mut ctx = Context::new();
+        // Extra derives and attributes of inner structs, by `vN::StructName`
+        let structs_config = versions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, structs)| {
+                structs.iter().map(move |(struct_name, _, _)| {
+                    let key = format!("v{}::{}", i, struct_name);
+                    let path = format!("{}::{}", module_name, key);
+                    (key, self.type_config(&path, true))
+                })
+            })
+            .collect::<HashMap<_, _>>();
+
+        let versions = versions
+            .iter()
+            .map(|structs| {
+                structs
+                    .iter()
+                    .map(|(name, fields, shared)| {
+                        (name.clone(), self.crate_fields(fields), shared.clone())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<motif::ModVstructs>();
+
+        let mut ctx = self.context();
         ctx.insert("name", module_name);
-        ctx.insert("versions", versions);
+        ctx.insert("versions", &versions);
+        ctx.insert("structs_config", &structs_config);
         Ok(self.tera.render(REQ_RESP_MOD_TERA, &ctx)?)
     }
+
+    /// Generates the accessor to the api keys and versions an ApiVersions
+    /// response lists, in the array field of each of its versions.
+    pub fn str_api_key_versions(
+        &self,
+        enum_name: &str,
+        versions: &motif::EnumVfields,
+    ) -> Result<String, Error> {
+        let fields = versions
+            .iter()
+            .enumerate()
+            .map(|(i, fields)| {
+                fields
+                    .iter()
+                    .find(|(_, rust_type, _)| {
+                        rust_type.starts_with("Vec<") || rust_type.contains("CompactArray<")
+                    })
+                    .map(|(name, _, _)| name.clone())
+                    .ok_or_else(|| Error::msg(format!("{}::V{} lists no api keys", enum_name, i)))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut ctx = self.context();
+        ctx.insert("name", enum_name);
+        ctx.insert("fields", &fields);
+        Ok(self.tera.render(API_KEY_VERSIONS_TERA, &ctx)?)
+    }
+
+    /// Generates the direct encoding/decoding of a given request/response
+    pub fn str_req_resp_codec(
+        &self,
+        enum_name: &str,
+        versions: &motif::EnumCodec,
+    ) -> Result<String, Error> {
+        let mut ctx = self.context();
+        ctx.insert("name", enum_name);
+        ctx.insert("versions", versions);
+        ctx.insert(
+            "empty",
+            &versions.iter().all(|(_, fields)| fields.is_empty()),
+        );
+        Ok(self.tera.render(REQ_RESP_CODEC_TERA, &ctx)?)
+    }
+
+    /// Generates the direct encoding/decoding of the inner structs of a req_resp module
+    pub fn str_req_resp_mod_codec(
+        &self,
+        module_name: &str,
+        versions: &motif::ModVstructs,
+    ) -> Result<String, Error> {
+        let mut ctx = self.context();
+        ctx.insert("name", module_name);
+        ctx.insert("versions", versions);
+        Ok(self.tera.render(REQ_RESP_MOD_CODEC_TERA, &ctx)?)
+    }
+}
+
+/// Whether a runtime type is `Copy`, to be returned by value.
+fn is_copy(rust_type: &str) -> bool {
+    match rust_type {
+        "bool" | "i8" | "i16" | "i32" | "i64" | "u32" | "f64" => true,
+        _ => rust_type.ends_with("types::AnyApiKey") || rust_type.ends_with("types::Uuid"),
+    }
 }
 
 #[cfg(test)]
@@ -223,31 +781,51 @@ pub enum CreateTopicsRequest {
 
         let mod_name = "create_topics_request";
 
-        let versions = vec![vec![
-            (
-                "CreateTopicsRequests".to_owned(),
-                vec![
-                    (
+        let versions = vec![
+            vec![
+                (
+                    "CreateTopicsRequests".to_owned(),
+                    vec![
+                        (
+                            "topic".to_owned(),
+                            "String".to_owned(),
+                            "            /// I am a comment.".to_owned(),
+                        ),
+                        (
+                            "num_partitions".to_owned(),
+                            "i32".to_owned(),
+                            "            /// I am another comment.".to_owned(),
+                        ),
+                    ],
+                    None,
+                ),
+                (
+                    "ReplicaAssignment".to_owned(),
+                    vec![(
+                        "partition".to_owned(),
+                        "i32".to_owned(),
+                        "            /// I am a comment.".to_owned(),
+                    )],
+                    None,
+                ),
+            ],
+            vec![
+                (
+                    "CreateTopicsRequests".to_owned(),
+                    vec![(
                         "topic".to_owned(),
                         "String".to_owned(),
                         "            /// I am a comment.".to_owned(),
-                    ),
-                    (
-                        "num_partitions".to_owned(),
-                        "i32".to_owned(),
-                        "            /// I am another comment.".to_owned(),
-                    ),
-                ],
-            ),
-            (
-                "ReplicaAssignment".to_owned(),
-                vec![(
-                    "partition".to_owned(),
-                    "i32".to_owned(),
-                    "            /// I am a comment.".to_owned(),
-                )],
-            ),
-        ]];
+                    )],
+                    None,
+                ),
+                (
+                    "ReplicaAssignment".to_owned(),
+                    vec![],
+                    Some("v0".to_owned()),
+                ),
+            ],
+        ];
 
         let res = templater.str_req_resp_mod(mod_name, &versions).unwrap();
 
@@ -267,9 +845,244 @@ pub mod create_topics_request {
             pub partition: i32,
         }
     }
+    pub mod v1 {
+        pub use super::v0::ReplicaAssignment;
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        pub struct CreateTopicsRequests {
+            /// I am a comment.
+            pub topic: String,
+        }
+    }
+}
+";
+
+        assert_eq!(expected, res);
+    }
+
+    #[test]
+    fn template_req_resp_codec() {
+        let templater = Templater::new().unwrap();
+
+        let v1 = vec![];
+        let v2 = vec![(
+            "timeout_ms".to_owned(),
+            "i32".to_owned(),
+            "        /// I am a comment.".to_owned(),
+        )];
+        let versions = vec![(1, &v1), (2, &v2)];
+
+        let res = templater
+            .str_req_resp_codec("PingRequest", &versions)
+            .unwrap();
+
+        let expected = r#"
+impl crate::codec::Encode for PingRequest {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            PingRequest::V0 { } => {
+            }
+            PingRequest::V1 { timeout_ms, } => {
+                crate::codec::Encode::encode(timeout_ms, buf);
+            }
+        }
+    }
+}
+
+impl crate::codec::Decode for PingRequest {
+    fn decode(buf: &mut &[u8], version: i16) -> crate::codec::Result<Self> {
+        match version {
+            1 => Ok(PingRequest::V0 {
+            }),
+            2 => Ok(PingRequest::V1 {
+                timeout_ms: crate::codec::Decode::decode(buf, version)?,
+            }),
+            _ => Err(serde::de::Error::custom(format!("{} has no version {}", "PingRequest", version))),
+        }
+    }
+}
+"#;
+
+        assert_eq!(expected, res);
+    }
+
+    #[test]
+    fn template_crate_path() {
+        let mut templater = Templater::new().unwrap();
+        templater.set_crate_path("kafka_protocol");
+
+        let versions = vec![vec![(
+            "topics".to_owned(),
+            "Vec<crate::types::NullableString>".to_owned(),
+            "".to_owned(),
+        )]];
+        let res = templater
+            .str_req_resp_enum("PingRequest", &versions)
+            .unwrap();
+        assert!(res.contains("topics: Vec<kafka_protocol::types::NullableString>,"));
+
+        let res = templater.str_request("Ping").unwrap();
+        assert!(res.starts_with("\nimpl kafka_protocol::request::Request for PingRequest {"));
+    }
+
+    #[test]
+    fn template_type_config() {
+        let mut templater = Templater::new().unwrap();
+        let config = Config::from_toml(
+            r##"
+[types."*"]
+derives = ["Default"]
+
+[types.ErrorCode]
+derives = ["PartialOrd"]
+attributes = ["#[non_exhaustive]"]
+"##,
+        )
+        .unwrap();
+        templater.set_config(config);
+
+        let err_codes = vec![(
+            "None".to_owned(),
+            "0".to_owned(),
+            "    /// No error.".to_owned(),
+        )];
+
+        let res = templater.str_err_codes(&err_codes).unwrap();
+
+        let expected = "
+///  Numeric codes to indicate what problem occurred on the Kafka server.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde_repr::Serialize_repr,
+    serde_repr::Deserialize_repr,
+    num_enum::IntoPrimitive,
+    num_enum::TryFromPrimitive,
+    PartialOrd,
+)]
+#[non_exhaustive]
+#[repr(i16)]
+pub enum ErrorCode {
+    /// No error.
+    None = 0,
+}
+";
+
+        assert_eq!(expected, res);
+    }
+
+    #[test]
+    fn template_header_versions() {
+        let templater = Templater::new().unwrap();
+
+        let request = vec![
+            ("Produce".to_owned(), vec![(9, 2)], 1),
+            ("ControlledShutdown".to_owned(), vec![(3, 2), (1, 1)], 0),
+        ];
+        let response = vec![
+            ("Produce".to_owned(), vec![], 0),
+            ("ControlledShutdown".to_owned(), vec![], 0),
+        ];
+
+        let res = templater.str_header_versions(&request, &response).unwrap();
+
+        let expected = "
+impl ApiKey {
+    /// Returns the version of the header requests of `api_version` are sent with.
+    pub fn request_header_version(self, api_version: i16) -> i16 {
+        match self {
+            ApiKey::Produce if api_version >= 9 => 2,
+            ApiKey::Produce => 1,
+            ApiKey::ControlledShutdown if api_version >= 3 => 2,
+            ApiKey::ControlledShutdown if api_version >= 1 => 1,
+            ApiKey::ControlledShutdown => 0,
+        }
+    }
+
+    /// Returns the version of the header responses to requests of `api_version`
+    /// are sent with.
+    pub fn response_header_version(self, _api_version: i16) -> i16 {
+        match self {
+            ApiKey::Produce => 0,
+            ApiKey::ControlledShutdown => 0,
+        }
+    }
 }
 ";
 
         assert_eq!(expected, res);
     }
+
+    #[test]
+    fn template_header() {
+        let templater = Templater::new().unwrap();
+
+        let field = |name: &str, rust_type: &str| {
+            let doc = format!("        /// The {}.", name);
+            (name.to_owned(), rust_type.to_owned(), doc)
+        };
+        let v0 = vec![
+            field("api_key", "i16"),
+            field("api_version", "i16"),
+            field("correlation_id", "i32"),
+        ];
+        let mut v1 = v0.clone();
+        v1.push(field("client_id", "crate::types::NullableString"));
+        v1.push(field("tagged_fields", "crate::types::TaggedFields"));
+
+        let res = templater
+            .str_header("RequestHeader", &vec![(0, &v0), (2, &v1)])
+            .unwrap();
+        assert!(res.contains("match api_key.request_header_version(api_version) {"));
+        assert!(res.contains("0 => RequestHeader::V0 {"));
+        assert!(res.contains("_ => RequestHeader::V1 {"));
+        assert!(res.contains("tagged_fields: Default::default(),"));
+        assert!(res.contains("pub fn api_key(&self) -> i16 {"));
+        assert!(res.contains("RequestHeader::V1 { correlation_id, .. } => *correlation_id,"));
+        assert!(!res.contains("pub fn client_id("));
+
+        let res = templater
+            .str_header("ResponseHeader", &vec![(0, &v0[2..].to_vec())])
+            .unwrap();
+        assert!(res.contains("debug_assert_eq!(api_key.response_header_version(api_version), 0);"));
+        assert!(res.contains("pub fn correlation_id(&self) -> i32 {"));
+    }
+
+    #[test]
+    fn template_api_key_versions() {
+        let templater = Templater::new().unwrap();
+
+        let field =
+            |name: &str, rust_type: &str| (name.to_owned(), rust_type.to_owned(), String::new());
+        let versions = vec![
+            vec![
+                field("error_code", "i16"),
+                field(
+                    "api_versions",
+                    "Vec<api_versions_response::v0::ApiVersions>",
+                ),
+            ],
+            vec![
+                field("error_code", "i16"),
+                field(
+                    "api_keys",
+                    "crate::types::CompactArray<api_versions_response::v1::ApiKeys>",
+                ),
+            ],
+        ];
+
+        let res = templater
+            .str_api_key_versions("ApiVersionsResponse", &versions)
+            .unwrap();
+        assert!(res.contains("ApiVersionsResponse::V0 { api_versions, .. } => api_versions"));
+        assert!(res.contains("ApiVersionsResponse::V1 { api_keys, .. } => api_keys"));
+
+        let versions = vec![vec![field("error_code", "i16")]];
+        assert!(templater
+            .str_api_key_versions("ApiVersionsResponse", &versions)
+            .is_err());
+    }
 }